@@ -0,0 +1,130 @@
+//! Pick the answer as late as possible, for games where the word dodges the guesses
+//!
+//! Instead of a fixed word the game keeps every answer that's still possible, each guess
+//! gets the feedback that keeps the most of them so the word is only known once it's forced
+
+use crate::engine::pattern;
+use crate::solver::Constraints;
+use crate::word_list::answers_for_size;
+use crate::LetterSlot;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// Answers for `word_size` that are still possible after `rows`
+pub fn remaining(word_size: usize, rows: &[Vec<LetterSlot>]) -> Vec<&'static str> {
+    answers_for_size(word_size)
+        .map(|answers| Constraints::from_rows(word_size, rows).filter(answers))
+        .unwrap_or_default()
+}
+
+/// Splits `words` by the feedback `guess` would get and returns the largest group
+///
+/// Ties go to the group with the fewest green letters, then the fewest yellow letters,
+/// so the word is only guessed once it's the last one left
+/// Words stay in the same order
+pub fn largest_group<'a>(guess: &str, words: &[&'a str]) -> Vec<&'a str> {
+    let mut groups: HashMap<u32, Vec<&'a str>> = HashMap::new();
+    //words that can't be scored against the guess are left out
+    for word in words {
+        if let Some(code) = pattern(guess, word) {
+            groups.entry(code).or_default().push(word);
+        }
+    }
+    groups
+        .into_iter()
+        .max_by_key(|(code, group)| {
+            let (greens, yellows) = count_marks(*code);
+            (
+                group.len(),
+                Reverse(greens),
+                Reverse(yellows),
+                Reverse(*code),
+            )
+        })
+        .map(|(_, group)| group)
+        .unwrap_or_default()
+}
+
+/// Answer that `guess` should be scored against so the most answers stay possible after `rows`
+///
+/// Returns `None` if no answers are left
+pub fn adversarial_answer(
+    word_size: usize,
+    rows: &[Vec<LetterSlot>],
+    guess: &str,
+) -> Option<&'static str> {
+    largest_group(guess, &remaining(word_size, rows))
+        .first()
+        .copied()
+}
+
+/// Number of green and yellow letters in a [pattern]
+fn count_marks(mut code: u32) -> (u32, u32) {
+    let mut greens = 0;
+    let mut yellows = 0;
+    while code > 0 {
+        match code % 3 {
+            2 => greens += 1,
+            1 => yellows += 1,
+            _ => {}
+        }
+        code /= 3;
+    }
+    (greens, yellows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Difficulty, EngineState, SlotState, WordleEngine};
+
+    #[test]
+    fn ties_avoid_the_answer() {
+        let words = ["BAKED", "BAKER"];
+        assert_eq!(largest_group("BAKER", &words), vec!["BAKED"]);
+        assert_eq!(largest_group("BAKER", &["BAKER"]), vec!["BAKER"]);
+        //CAKES and BAKES give the same feedback so beat BAKED on its own
+        let words = ["BAKED", "CAKES", "BAKES"];
+        assert_eq!(largest_group("FAKES", &words), vec!["CAKES", "BAKES"]);
+        assert!(largest_group("FAKES", &[]).is_empty());
+    }
+
+    #[test]
+    fn picks_largest_group() {
+        let words = remaining(5, &[]);
+        let mut sizes: HashMap<u32, usize> = HashMap::new();
+        for word in &words {
+            *sizes.entry(pattern("CRANE", word).unwrap()).or_default() += 1;
+        }
+        let answer = adversarial_answer(5, &[], "CRANE").unwrap();
+        let chosen = sizes[&pattern("CRANE", answer).unwrap()];
+        assert_eq!(Some(&chosen), sizes.values().max());
+        assert_eq!(largest_group("CRANE", &words).len(), chosen);
+    }
+
+    #[test]
+    fn game_ends_when_forced() {
+        let mut engine = WordleEngine::new_adversarial(5, Difficulty::Normal).unwrap();
+        let mut left = remaining(5, &[]).len();
+        while engine.state == EngineState::Guessing {
+            let guess = remaining(5, &engine.guesses)[0];
+            engine.guess(guess).unwrap();
+            let now = remaining(5, &engine.guesses).len();
+            if engine.state == EngineState::Guessing {
+                //the guess is always ruled out until it's the only word left
+                assert!(now < left);
+                assert!(engine
+                    .guesses
+                    .last()
+                    .unwrap()
+                    .iter()
+                    .any(|slot| slot.state != SlotState::Match));
+            } else {
+                assert_eq!(now, 1);
+                assert_eq!(engine.word, guess);
+            }
+            left = now;
+        }
+        assert_eq!(engine.state, EngineState::Found);
+    }
+}
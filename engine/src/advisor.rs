@@ -0,0 +1,192 @@
+//! Rank possible next guesses by how much they're expected to reveal
+//!
+//! Every guess is scored against every remaining answer with [pattern], the guesses
+//! that split the answers into the most even groups give the most information
+
+use crate::engine::pattern;
+use crate::solver::{candidates, Constraints};
+use crate::word_list::{answers_for_size, guesses_for_size};
+use crate::{EngineState, LetterSlot, WordleEngine};
+use std::cmp::Ordering;
+use std::thread;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedGuess<'a> {
+    pub word: &'a str,
+    /// Expected information from the feedback, in bits
+    pub entropy: f64,
+    /// Average number of answers that will still be possible after guessing this word
+    pub expected_remaining: f64,
+    /// This word could be the answer
+    pub possible_answer: bool,
+}
+
+/// Answers that are still possible after `rows`
+///
+/// These are taken from the answer list, or the whole dictionary if none of the
+/// answers match (for games where the word isn't a normal answer)
+pub fn remaining_answers(word_size: usize, rows: &[Vec<LetterSlot>]) -> Vec<&'static str> {
+    let Some(answers) = answers_for_size(word_size) else {
+        return vec![];
+    };
+    let remaining = Constraints::from_rows(word_size, rows).filter(answers);
+    if remaining.is_empty() {
+        candidates(word_size, rows)
+    } else {
+        remaining
+    }
+}
+
+/// Ranks every dictionary word that can be guessed next in `engine`, best first
+///
+/// Guesses that break the hard mode rules for the engine's difficulty are skipped,
+/// see [remaining_answers] for which answers are considered
+/// Returns an empty list if the game has finished
+pub fn rank_guesses(engine: &WordleEngine) -> Vec<RankedGuess<'static>> {
+    if engine.state != EngineState::Guessing {
+        return vec![];
+    }
+    let Some(guesses) = guesses_for_size(engine.word_size) else {
+        return vec![];
+    };
+    let remaining = remaining_answers(engine.word_size, &engine.guesses);
+    let guesses: Vec<&str> = guesses
+        .iter()
+        .filter(|word| {
+            let chars: Vec<char> = word.chars().collect();
+            engine.check_hard_mode(&chars).is_ok()
+        })
+        .copied()
+        .collect();
+    rank(&guesses, &remaining)
+}
+
+/// Ranks `guesses` against the possible `answers`, best first
+///
+/// Ties are broken by preferring guesses that could be the answer
+pub fn rank<'a>(guesses: &[&'a str], answers: &[&str]) -> Vec<RankedGuess<'a>> {
+    if answers.is_empty() {
+        return vec![];
+    }
+    let threads = thread::available_parallelism()
+        .map(|count| count.get())
+        .unwrap_or(1);
+    let chunk_size = guesses.len().div_ceil(threads).max(1);
+    let mut ranked: Vec<RankedGuess> = thread::scope(|scope| {
+        let jobs: Vec<_> = guesses
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || rank_chunk(chunk, answers)))
+            .collect();
+        jobs.into_iter()
+            .flat_map(|job| job.join().expect("ranking thread panicked"))
+            .collect()
+    });
+    ranked.sort_by(|lhs, rhs| {
+        rhs.entropy
+            .partial_cmp(&lhs.entropy)
+            .unwrap_or(Ordering::Equal)
+            .then(rhs.possible_answer.cmp(&lhs.possible_answer))
+            .then(lhs.word.cmp(rhs.word))
+    });
+    ranked
+}
+
+fn rank_chunk<'a>(guesses: &[&'a str], answers: &[&str]) -> Vec<RankedGuess<'a>> {
+    let total = answers.len() as f64;
+    let mut counts: Vec<u32> = vec![];
+    let mut patterns = vec![0; answers.len()];
+    guesses
+        .iter()
+        .map(|guess| {
+            for (code, answer) in patterns.iter_mut().zip(answers) {
+                //guesses and answers come from the lists for the same word size
+                *code = pattern(guess, answer).unwrap_or_default() as usize;
+                if *code >= counts.len() {
+                    counts.resize(*code + 1, 0);
+                }
+                counts[*code] += 1;
+            }
+            let mut entropy = 0.0;
+            let mut expected_remaining = 0.0;
+            for code in &patterns {
+                let count = counts[*code];
+                if count > 0 {
+                    let count = count as f64;
+                    let probability = count / total;
+                    entropy -= probability * probability.log2();
+                    expected_remaining += count * probability;
+                    counts[*code] = 0;
+                }
+            }
+            RankedGuess {
+                word: guess,
+                entropy,
+                expected_remaining,
+                possible_answer: answers.contains(guess),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{score, Difficulty};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn splits_evenly() {
+        let answers = ["BAKED", "BAKER", "BAKES", "CAKES"];
+        let ranked = rank(&["BIKER", "BAKES", "CRASH", "BAKER"], &answers);
+        //CRASH gives different feedback for every answer
+        assert_eq!(ranked[0].word, "CRASH");
+        assert_eq!(ranked[0].entropy, 2.0);
+        assert_eq!(ranked[0].expected_remaining, 1.0);
+        assert!(!ranked[0].possible_answer);
+        //the rest split the answers 2/1/1 so possible answers go first
+        let words: Vec<&str> = ranked[1..].iter().map(|guess| guess.word).collect();
+        assert_eq!(words, vec!["BAKER", "BAKES", "BIKER"]);
+        assert!(ranked[1..].iter().all(|guess| guess.entropy == 1.5));
+        assert!(ranked[1..]
+            .iter()
+            .all(|guess| guess.expected_remaining == 1.5));
+    }
+
+    #[test]
+    fn single_answer() {
+        let ranked = rank(&["BAKER", "CAKES"], &["CAKES"]);
+        assert_eq!(ranked[0].word, "CAKES");
+        assert_eq!(ranked[0].entropy, 0.0);
+        assert_eq!(ranked[0].expected_remaining, 1.0);
+        assert!(rank(&["BAKER"], &[]).is_empty());
+    }
+
+    #[test]
+    fn matches_scoring() {
+        let answers = answers_for_size(5).unwrap();
+        let mut groups: BTreeMap<String, usize> = BTreeMap::new();
+        for answer in answers {
+            *groups
+                .entry(format!("{:?}", score("SOUTH", answer)))
+                .or_default() += 1;
+        }
+        let expected: usize = groups.values().map(|count| count * count).sum();
+        let ranked = rank(&["SOUTH"], answers);
+        let diff = ranked[0].expected_remaining - expected as f64 / answers.len() as f64;
+        assert!(diff.abs() < 1e-9);
+    }
+
+    #[test]
+    fn engine_advice() {
+        let mut engine = WordleEngine::new(5, 0, Difficulty::Hard).unwrap();
+        engine.word = "GHOST".to_string();
+        engine.guess("BOOST").unwrap();
+        let ranked = rank_guesses(&engine);
+        assert!(!ranked.is_empty());
+        assert!(ranked.iter().any(|guess| guess.word == "GHOST"));
+        //hard mode needs OST at the end
+        assert!(ranked.iter().all(|guess| guess.word.ends_with("OST")));
+        engine.guess("GHOST").unwrap();
+        assert!(rank_guesses(&engine).is_empty());
+    }
+}
@@ -0,0 +1,155 @@
+//! Rate each guess of a game
+//!
+//! Everything is worked out from the scored rows, word size and difficulty so a saved
+//! [WordleEngine] always gives the same analysis (as long as the word lists haven't changed)
+
+use crate::advisor::{rank, rank_guesses, remaining_answers};
+use crate::{EngineState, WordleEngine};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuessAnalysis {
+    pub guess: String,
+    /// Number of possible answers before this guess
+    pub candidates_before: usize,
+    /// Number of possible answers after this guess
+    pub candidates_after: usize,
+    /// Information this guess was expected to give, in bits
+    pub expected_bits: f64,
+    /// Information this guess actually gave, in bits
+    pub actual_bits: f64,
+    /// Highest ranked guess that was allowed, see [rank_guesses]
+    pub best_guess: String,
+    /// Information the best guess was expected to give, in bits
+    pub best_bits: f64,
+    /// This guess could have been the answer
+    pub possible_answer: bool,
+}
+
+impl GuessAnalysis {
+    /// How good the guess was compared to the best guess, from 0 to 100
+    ///
+    /// When there was nothing left to learn the only good guess is a possible answer
+    pub fn skill(&self) -> u8 {
+        if self.best_bits > 0.0 {
+            (self.expected_bits / self.best_bits * 100.0)
+                .round()
+                .clamp(0.0, 100.0) as u8
+        } else if self.possible_answer {
+            100
+        } else {
+            0
+        }
+    }
+
+    /// Bits gained compared to the expected amount, positive if the feedback
+    /// ruled out more answers than average
+    pub fn luck(&self) -> f64 {
+        self.actual_bits - self.expected_bits
+    }
+}
+
+/// Analysis for every guess in `engine`
+///
+/// Each row takes as long as [rank_guesses], use [analyse_row] to show results as they're ready
+pub fn analyse(engine: &WordleEngine) -> Vec<GuessAnalysis> {
+    (0..engine.guesses.len())
+        .filter_map(|row| analyse_row(engine, row))
+        .collect()
+}
+
+/// Analysis for the guess in `row` of `engine`, returns `None` if there's no such row
+pub fn analyse_row(engine: &WordleEngine, row: usize) -> Option<GuessAnalysis> {
+    let scored = engine.guesses.get(row)?;
+    let guess: String = scored.iter().map(|slot| slot.chr).collect();
+    let mut board = engine.clone();
+    board.guesses.truncate(row);
+    board.state = EngineState::Guessing;
+    board.current_guess.clear();
+
+    let before = remaining_answers(engine.word_size, &board.guesses);
+    let after = remaining_answers(engine.word_size, &engine.guesses[..=row]);
+    let expected = rank(&[guess.as_str()], &before);
+    let best = rank_guesses(&board);
+    let (expected_bits, possible_answer) = expected
+        .first()
+        .map(|ranked| (ranked.entropy, ranked.possible_answer))
+        .unwrap_or_default();
+    let (best_guess, best_bits) = best
+        .first()
+        .map(|ranked| (ranked.word.to_string(), ranked.entropy))
+        .unwrap_or_else(|| (guess.clone(), expected_bits));
+
+    Some(GuessAnalysis {
+        candidates_before: before.len(),
+        candidates_after: after.len(),
+        expected_bits,
+        actual_bits: (before.len().max(1) as f64 / after.len().max(1) as f64).log2(),
+        best_guess,
+        best_bits,
+        possible_answer,
+        guess,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::engine::played_game;
+    use crate::Difficulty;
+
+    #[test]
+    fn rows_are_analysed() {
+        let engine = played_game("GHOST", &["BOOST", "GHOST"], Difficulty::Normal);
+        let analysis = analyse(&engine);
+        assert_eq!(analysis.len(), 2);
+
+        let first = &analysis[0];
+        assert_eq!(first.guess, "BOOST");
+        assert_eq!(first.candidates_before, 2312);
+        assert_eq!(
+            first.candidates_after,
+            remaining_answers(5, &engine.guesses[..1]).len()
+        );
+        assert!(first.candidates_after < 20);
+        assert!(first.expected_bits > 0.0);
+        assert!(first.best_bits >= first.expected_bits);
+        assert!(first.skill() <= 100);
+        assert!(first.luck() > 0.0);
+
+        let last = &analysis[1];
+        assert_eq!(last.guess, "GHOST");
+        assert_eq!(last.candidates_before, first.candidates_after);
+        assert_eq!(last.candidates_after, 1);
+        assert!(last.possible_answer);
+    }
+
+    #[test]
+    fn reproducible_from_save() {
+        let engine = played_game("CRANE", &["SLATE", "CRONE"], Difficulty::Normal);
+        let json = serde_json::to_string(&engine).unwrap();
+        let restored: WordleEngine = serde_json::from_str(&json).unwrap();
+        //skips the first row as ranking the opener is slow
+        assert_eq!(analyse_row(&restored, 1), analyse_row(&engine, 1));
+        assert!(analyse_row(&engine, 2).is_none());
+    }
+
+    #[test]
+    fn skill_with_one_answer_left() {
+        let mut analysis = GuessAnalysis {
+            guess: String::from("CRANE"),
+            candidates_before: 1,
+            candidates_after: 1,
+            expected_bits: 0.0,
+            actual_bits: 0.0,
+            best_guess: String::from("CRANE"),
+            best_bits: 0.0,
+            possible_answer: true,
+        };
+        assert_eq!(analysis.skill(), 100);
+        analysis.possible_answer = false;
+        assert_eq!(analysis.skill(), 0);
+        analysis.best_bits = 2.0;
+        analysis.expected_bits = 1.0;
+        assert_eq!(analysis.skill(), 50);
+    }
+}
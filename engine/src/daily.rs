@@ -0,0 +1,115 @@
+//! Daily puzzles
+//!
+//! Every player gets the same word on the same date, the word is picked from the date and
+//! the answer list only so it doesn't depend on the install or any saved data
+
+use crate::rng::mix;
+use crate::word_list::answers_for_size;
+
+/// Day (since 1970-01-01) of the first daily puzzle
+pub const FIRST_DAILY: i64 = 20_744;
+
+/// Number of days since 1970-01-01 for a date in the Gregorian calendar
+///
+/// `month` and `day` start at 1
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Puzzle number shown to players for `day`, the first daily is #1
+pub fn puzzle_number(day: i64) -> i64 {
+    day - FIRST_DAILY + 1
+}
+
+/// Index of the answer for the daily puzzle on `day` (days since 1970-01-01)
+///
+/// Returns `None` if `word_size` is unsupported
+pub fn daily_answer_idx(word_size: usize, day: i64) -> Option<usize> {
+    answers_for_size(word_size).map(|answers| pick(answers, day))
+}
+
+/// Every word is scored against the day and the highest wins, so adding words to
+/// the end of a list only changes the days where a new word is picked
+fn pick(answers: &[&str], day: i64) -> usize {
+    answers
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, word)| hash(word, day))
+        .map(|(idx, _)| idx)
+        .unwrap_or_default()
+}
+
+/// FNV-1a followed by the splitmix64 finalizer
+fn hash(word: &str, day: i64) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in word.bytes().chain(day.to_le_bytes()) {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    mix(hash)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::word_list::WORD_SIZES;
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(
+            days_from_civil(2024, 2, 29) + 1,
+            days_from_civil(2024, 3, 1)
+        );
+        assert_eq!(days_from_civil(2026, 10, 18), FIRST_DAILY);
+        assert_eq!(puzzle_number(FIRST_DAILY), 1);
+    }
+
+    #[test]
+    fn daily_is_stable() {
+        for size in WORD_SIZES {
+            let answers = answers_for_size(size).unwrap();
+            let first = daily_answer_idx(size, FIRST_DAILY).unwrap();
+            assert_eq!(daily_answer_idx(size, FIRST_DAILY), Some(first));
+            assert!(first < answers.len());
+            let days: Vec<usize> = (FIRST_DAILY..FIRST_DAILY + 10)
+                .map(|day| daily_answer_idx(size, day).unwrap())
+                .collect();
+            assert!(days.iter().any(|idx| *idx != first));
+        }
+        assert_eq!(daily_answer_idx(3, FIRST_DAILY), None);
+    }
+
+    #[test]
+    fn first_daily_words() {
+        let word =
+            |size| answers_for_size(size).unwrap()[daily_answer_idx(size, FIRST_DAILY).unwrap()];
+        assert_eq!(word(4), "SKIM");
+        assert_eq!(word(5), "SHARK");
+        assert_eq!(word(6), "SUPPLY");
+        assert_eq!(word(7), "VAMPIRE");
+    }
+
+    #[test]
+    fn adding_words_keeps_old_days() {
+        let answers = answers_for_size(5).unwrap();
+        let (old, added) = answers.split_at(answers.len() - 20);
+        for day in FIRST_DAILY..FIRST_DAILY + 100 {
+            let idx = pick(answers, day);
+            if idx < old.len() {
+                assert_eq!(pick(old, day), idx);
+            } else {
+                assert!(added.contains(&answers[idx]));
+            }
+        }
+    }
+}
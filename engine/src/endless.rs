@@ -0,0 +1,124 @@
+//! Games played back to back, the run carries on until the first loss
+
+use crate::{EngineState, WordleEngine};
+use serde::{Deserialize, Serialize};
+
+/// Points for each guess left over when the word is found
+pub const POINTS_PER_GUESS_LEFT: usize = 10;
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Run {
+    /// Number of words found in a row
+    pub streak: usize,
+    /// Total points for the words found, see [points]
+    pub score: usize,
+}
+
+impl Run {
+    /// Adds a finished game to the run
+    ///
+    /// Returns the points scored, or `None` if the game wasn't won and so the run is over
+    pub fn add_game(&mut self, engine: &WordleEngine) -> Option<usize> {
+        if engine.state != EngineState::Found {
+            return None;
+        }
+        let points = points(engine);
+        self.streak += 1;
+        self.score += points;
+        Some(points)
+    }
+
+    /// Whether this run is better than `other`, longer streaks win and ties go to the higher score
+    pub fn beats(&self, other: &Run) -> bool {
+        (self.streak, self.score) > (other.streak, other.score)
+    }
+}
+
+/// Points for a game, one lot of [POINTS_PER_GUESS_LEFT] for the winning guess
+/// and each guess left over
+///
+/// Games that weren't won, or where hints were used, score nothing
+pub fn points(engine: &WordleEngine) -> usize {
+    if engine.state != EngineState::Found || engine.hints > 0 {
+        return 0;
+    }
+    let left = engine.max_guess_count.saturating_sub(engine.guesses.len());
+    (left + 1) * POINTS_PER_GUESS_LEFT
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::engine::played_game;
+    use crate::Difficulty;
+
+    #[test]
+    fn points_for_guesses_left() {
+        assert_eq!(
+            points(&played_game("GHOST", &["GHOST"], Difficulty::Normal)),
+            60
+        );
+        assert_eq!(
+            points(&played_game(
+                "GHOST",
+                &["BOOST", "GHOST"],
+                Difficulty::Normal
+            )),
+            50
+        );
+        let last_guess = played_game(
+            "GHOST",
+            &["CRANE", "CRANE", "CRANE", "CRANE", "CRANE", "GHOST"],
+            Difficulty::Normal,
+        );
+        assert_eq!(points(&last_guess), 10);
+        assert_eq!(
+            points(&played_game("GHOST", &["CRANE"], Difficulty::Normal)),
+            0
+        );
+
+        let mut hinted = played_game("GHOST", &["GHOST"], Difficulty::Normal);
+        hinted.hints = 1;
+        assert_eq!(points(&hinted), 0);
+    }
+
+    #[test]
+    fn run_ends_on_loss() {
+        let mut run = Run::default();
+        assert_eq!(
+            run.add_game(&played_game("GHOST", &["GHOST"], Difficulty::Normal)),
+            Some(60)
+        );
+        assert_eq!(
+            run.add_game(&played_game(
+                "PIANO",
+                &["CRANE", "PIANO"],
+                Difficulty::Normal
+            )),
+            Some(50)
+        );
+        assert_eq!(
+            run,
+            Run {
+                streak: 2,
+                score: 110
+            }
+        );
+        let lost = played_game("GHOST", &["CRANE"; 6], Difficulty::Normal);
+        assert_eq!(run.add_game(&lost), None);
+        assert_eq!(run.streak, 2);
+
+        assert!(run.beats(&Run::default()));
+        assert!(!run.beats(&run.clone()));
+        assert!(Run {
+            streak: 3,
+            score: 10
+        }
+        .beats(&run));
+        assert!(Run {
+            streak: 2,
+            score: 120
+        }
+        .beats(&run));
+    }
+}
@@ -0,0 +1,753 @@
+use crate::adversary::adversarial_answer;
+use crate::error::{EngineError, GuessError, HardModeViolation};
+use crate::word_list::{answers_for_size, is_word};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum SlotState {
+    Match,
+    WrongPos,
+    NoMatch,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum EngineState {
+    Found,
+    OutOfGuesses,
+    Guessing,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    #[default]
+    Normal,
+    /// Green letters must stay in place and yellow letters must be reused
+    Hard,
+    /// As hard, but letters also can't be placed where they've been ruled out
+    /// and grey letters can't be used at all
+    UltraHard,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+pub struct LetterSlot {
+    pub chr: char,
+    pub state: SlotState,
+}
+
+#[derive(Debug, Default)]
+pub struct SubmittedGuessInfo {
+    pub word: String,
+    pub matches: Vec<char>,
+    pub mismatches: Vec<char>,
+    pub no_matches: Vec<char>,
+}
+
+impl LetterSlot {
+    pub fn new(chr: char, state: SlotState) -> Self {
+        LetterSlot { chr, state }
+    }
+}
+
+impl SubmittedGuessInfo {
+    pub fn from_row(row: &[LetterSlot]) -> Self {
+        let mut output = SubmittedGuessInfo {
+            word: row.iter().map(|slot| slot.chr).collect(),
+            ..SubmittedGuessInfo::default()
+        };
+        for slot in row {
+            match slot.state {
+                SlotState::Match => output.matches.push(slot.chr),
+                SlotState::WrongPos => output.mismatches.push(slot.chr),
+                SlotState::NoMatch => output.no_matches.push(slot.chr),
+            }
+        }
+        output
+    }
+}
+
+/// Scores `guess` against `answer`
+///
+/// Letters in the right place are marked first, then letters in the wrong place are
+/// marked left to right, but only while the answer still has unmarked copies of that letter
+/// So, if the word was "THEME" and the guess was "EERIE" then
+/// 1st E will be yellow
+/// 2nd E will be grey (as the only E left was used by the 1st E)
+/// 3rd E will be green
+///
+/// Words are expected to be uppercase A-Z, as they are everywhere in the engine
+pub fn score(guess: &str, answer: &str) -> Vec<LetterSlot> {
+    let mut states = vec![SlotState::NoMatch; guess.len()];
+    mark(guess.as_bytes(), answer.as_bytes(), &mut states);
+    guess
+        .chars()
+        .zip(states)
+        .map(|(chr, state)| LetterSlot::new(chr, state))
+        .collect()
+}
+
+/// Longest word [pattern] works for, longer patterns don't fit in a `u32`
+pub const MAX_PATTERN_LEN: usize = 20;
+
+/// Same as [score] but the result is a single number, one digit (base 3) per letter
+/// where 0 is [SlotState::NoMatch], 1 is [SlotState::WrongPos] and 2 is [SlotState::Match]
+///
+/// Two guesses have the same feedback if they have the same pattern.
+/// `None` if the words are different lengths or longer than [MAX_PATTERN_LEN]
+pub fn pattern(guess: &str, answer: &str) -> Option<u32> {
+    if guess.len() != answer.len() || guess.len() > MAX_PATTERN_LEN {
+        return None;
+    }
+    let mut states = [SlotState::NoMatch; MAX_PATTERN_LEN];
+    let states = &mut states[..guess.len()];
+    mark(guess.as_bytes(), answer.as_bytes(), states);
+    Some(states.iter().rev().fold(0, |code, state| {
+        code * 3
+            + match state {
+                SlotState::NoMatch => 0,
+                SlotState::WrongPos => 1,
+                SlotState::Match => 2,
+            }
+    }))
+}
+
+/// Scoring used by [score] and [pattern], `states` must be all [SlotState::NoMatch]
+fn mark(guess: &[u8], answer: &[u8], states: &mut [SlotState]) {
+    let mut remaining = [0_usize; 256];
+    for ((state, letter), chr) in states.iter_mut().zip(guess).zip(answer) {
+        if letter == chr {
+            *state = SlotState::Match;
+        } else {
+            remaining[*chr as usize] += 1;
+        }
+    }
+    for (state, letter) in states.iter_mut().zip(guess) {
+        if *state == SlotState::Match {
+            continue;
+        }
+        let count = &mut remaining[*letter as usize];
+        if *count > 0 {
+            *count -= 1;
+            *state = SlotState::WrongPos;
+        }
+    }
+}
+
+/// A single game, can be serialized to save and resume games
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct WordleEngine {
+    pub word_size: usize,
+    /// For adversarial games this is only one of the possible answers until the game is won
+    pub word: String,
+    pub guesses: Vec<Vec<LetterSlot>>,
+    pub max_guess_count: usize,
+    pub state: EngineState,
+    pub current_guess: Vec<char>,
+    pub difficulty: Difficulty,
+    /// Number of hints the player has used, not changed by the engine
+    #[serde(default)]
+    pub hints: usize,
+    /// The answer is picked after each guess, see [WordleEngine::new_adversarial]
+    #[serde(default)]
+    pub adversarial: bool,
+}
+
+impl WordleEngine {
+    /// Start a game using the answer at `idx` for `word_size`
+    pub fn new(word_size: usize, idx: usize, difficulty: Difficulty) -> Result<Self, EngineError> {
+        let answers =
+            answers_for_size(word_size).ok_or(EngineError::UnsupportedWordSize(word_size))?;
+        let word = answers
+            .get(idx)
+            .ok_or(EngineError::InvalidWordIndex { word_size, idx })?
+            .to_string();
+        Ok(WordleEngine {
+            word_size,
+            word,
+            guesses: vec![],
+            max_guess_count: word_size + 1,
+            state: EngineState::Guessing,
+            current_guess: vec![],
+            difficulty,
+            hints: 0,
+            adversarial: false,
+        })
+    }
+
+    /// Start a game where no word is picked up front
+    ///
+    /// Each guess is scored against whichever answer keeps the most answers possible
+    /// (see [crate::adversary]), so the game is only won once the word is forced
+    /// There's no limit on the number of guesses
+    pub fn new_adversarial(word_size: usize, difficulty: Difficulty) -> Result<Self, EngineError> {
+        let mut engine = WordleEngine::new(word_size, 0, difficulty)?;
+        engine.max_guess_count = usize::MAX;
+        engine.adversarial = true;
+        Ok(engine)
+    }
+
+    /// Start a game for `word`, e.g. one picked by another player
+    ///
+    /// `word` can be any case but must be in the dictionary for its length (see [is_word])
+    pub fn with_word(word: &str, difficulty: Difficulty) -> Result<Self, EngineError> {
+        let word = word.trim().to_ascii_uppercase();
+        let word_size = word.chars().count();
+        let mut engine = WordleEngine::new(word_size, 0, difficulty)?;
+        if !is_word(word_size, &word) {
+            return Err(EngineError::NotAWord);
+        }
+        engine.word = word;
+        Ok(engine)
+    }
+}
+
+/// Converts `letter` to uppercase, only A-Z (in any case) are valid
+pub(crate) fn normalise_letter(letter: char) -> Result<char, GuessError> {
+    if letter.is_ascii_alphabetic() {
+        Ok(letter.to_ascii_uppercase())
+    } else {
+        Err(GuessError::InvalidCharacter(letter))
+    }
+}
+
+impl WordleEngine {
+    pub fn add_letter(&mut self, letter: char) -> Result<(), GuessError> {
+        if self.state != EngineState::Guessing {
+            return Err(GuessError::GameFinished);
+        }
+        let letter = normalise_letter(letter)?;
+        if self.current_guess.len() >= self.word_size {
+            return Err(GuessError::WrongLength {
+                expected: self.word_size,
+                actual: self.current_guess.len() + 1,
+            });
+        }
+        self.current_guess.push(letter);
+        Ok(())
+    }
+
+    /// Replaces the current guess with `word` and submits it
+    ///
+    /// Surrounding whitespace is ignored and letters can be in any case
+    pub fn guess(&mut self, word: &str) -> Result<SubmittedGuessInfo, GuessError> {
+        if self.state != EngineState::Guessing {
+            return Err(GuessError::GameFinished);
+        }
+        let letters = word
+            .trim()
+            .chars()
+            .map(normalise_letter)
+            .collect::<Result<Vec<char>, GuessError>>()?;
+        let previous = std::mem::replace(&mut self.current_guess, letters);
+        let result = self.submit();
+        if result.is_err() {
+            self.current_guess = previous;
+        }
+        result
+    }
+
+    pub fn submit(&mut self) -> Result<SubmittedGuessInfo, GuessError> {
+        self.validate(&self.current_guess)?;
+        let guess: String = self.current_guess.iter().collect();
+        if self.adversarial {
+            if let Some(answer) = adversarial_answer(self.word_size, &self.guesses, &guess) {
+                self.word = answer.to_string();
+            }
+        }
+        let row = score(&guess, &self.word);
+        let output = SubmittedGuessInfo::from_row(&row);
+        let word_found = row.iter().all(|slot| slot.state == SlotState::Match);
+        self.guesses.push(row);
+        if word_found {
+            self.state = EngineState::Found
+        } else if self.guesses.len() >= self.max_guess_count {
+            self.state = EngineState::OutOfGuesses
+        }
+        self.current_guess.clear();
+        Ok(output)
+    }
+
+    /// Checks `guess` could be submitted, without changing anything
+    pub(crate) fn validate(&self, guess: &[char]) -> Result<(), GuessError> {
+        if self.state != EngineState::Guessing {
+            return Err(GuessError::GameFinished);
+        }
+        if guess.len() != self.word_size {
+            return Err(GuessError::WrongLength {
+                expected: self.word_size,
+                actual: guess.len(),
+            });
+        }
+        let word: String = guess.iter().collect();
+        if !is_word(self.word_size, &word) {
+            return Err(GuessError::NotAWord);
+        }
+        self.check_hard_mode(guess).map_err(GuessError::HardMode)
+    }
+
+    pub fn backspace(&mut self) {
+        if self.state == EngineState::Guessing && !self.current_guess.is_empty() {
+            self.current_guess.remove(self.current_guess.len() - 1);
+        }
+    }
+
+    /// Checks `guess` uses all the hints revealed by previous guesses
+    /// Always passes in [Difficulty::Normal]
+    pub(crate) fn check_hard_mode(&self, guess: &[char]) -> Result<(), HardModeViolation> {
+        if self.difficulty == Difficulty::Normal {
+            return Ok(());
+        }
+        let count_in_guess = |letter: char| guess.iter().filter(|c| **c == letter).count();
+        //number of times letter is known to be in word according to row
+        let count_in_row = |row: &[LetterSlot], letter: char| {
+            row.iter()
+                .filter(|slot| slot.chr == letter && slot.state != SlotState::NoMatch)
+                .count()
+        };
+
+        for row in &self.guesses {
+            for (position, slot) in row.iter().enumerate() {
+                if slot.state == SlotState::Match && guess[position] != slot.chr {
+                    return Err(HardModeViolation::MissingMatch {
+                        letter: slot.chr,
+                        position,
+                    });
+                }
+            }
+        }
+        for row in &self.guesses {
+            for slot in row {
+                let count = count_in_row(row, slot.chr);
+                if count_in_guess(slot.chr) < count {
+                    return Err(HardModeViolation::MissingLetter {
+                        letter: slot.chr,
+                        count,
+                    });
+                }
+            }
+        }
+        if self.difficulty == Difficulty::UltraHard {
+            for row in &self.guesses {
+                for (position, slot) in row.iter().enumerate() {
+                    if slot.state != SlotState::Match && guess[position] == slot.chr {
+                        return Err(HardModeViolation::MisplacedLetter {
+                            letter: slot.chr,
+                            position,
+                        });
+                    }
+                }
+            }
+            for row in &self.guesses {
+                for slot in row.iter().filter(|slot| slot.state == SlotState::NoMatch) {
+                    //a grey letter means the word has exactly as many as were green/yellow
+                    let count = count_in_row(row, slot.chr);
+                    if count_in_guess(slot.chr) > count {
+                        return Err(HardModeViolation::TooManyLetter {
+                            letter: slot.chr,
+                            count,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Game for `answer` after playing `guesses`, `answer` doesn't have to be in the answer list
+#[cfg(test)]
+pub(crate) fn played_game(answer: &str, guesses: &[&str], difficulty: Difficulty) -> WordleEngine {
+    let mut engine = WordleEngine::new(answer.len(), 0, difficulty).unwrap();
+    engine.word = answer.to_string();
+    for guess in guesses {
+        engine.guess(guess).unwrap();
+    }
+    engine
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_typing() {
+        let mut engine = WordleEngine::new(4, 1244, Difficulty::Normal).unwrap();
+        engine.add_letter('A').unwrap();
+        assert_eq!(engine.state, EngineState::Guessing);
+        assert_eq!(engine.current_guess, vec!['A']);
+        engine.backspace();
+        assert_eq!(engine.current_guess, vec![]);
+    }
+
+    #[test]
+    fn mismatch_test() {
+        let mut engine = WordleEngine::new(5, 0, Difficulty::Normal).unwrap();
+        engine.word = "SHOTS".to_string();
+        engine.current_guess = vec!['L', 'O', 'O', 'K', 'S'];
+        let result = engine.submit().unwrap();
+        let expected = SubmittedGuessInfo {
+            word: "LOOKS".to_string(),
+            matches: vec!['O', 'S'],
+            mismatches: vec![],
+            no_matches: vec!['L', 'O', 'K'],
+        };
+        assert_eq!(result.word, expected.word);
+        assert_eq!(result.mismatches, expected.mismatches);
+        assert_eq!(result.matches, expected.matches);
+        assert_eq!(result.no_matches, expected.no_matches);
+        assert_eq!(
+            engine.guesses[0],
+            vec![
+                LetterSlot {
+                    chr: 'L',
+                    state: SlotState::NoMatch
+                },
+                LetterSlot {
+                    chr: 'O',
+                    state: SlotState::NoMatch
+                },
+                LetterSlot {
+                    chr: 'O',
+                    state: SlotState::Match
+                },
+                LetterSlot {
+                    chr: 'K',
+                    state: SlotState::NoMatch
+                },
+                LetterSlot {
+                    chr: 'S',
+                    state: SlotState::Match
+                }
+            ]
+        );
+    }
+
+    /// Converts a pattern such as "GY-" into slot states
+    /// G = Match, Y = WrongPos, - = NoMatch
+    fn pattern(text: &str) -> Vec<SlotState> {
+        text.chars()
+            .map(|c| match c {
+                'G' => SlotState::Match,
+                'Y' => SlotState::WrongPos,
+                '-' => SlotState::NoMatch,
+                _ => panic!("Invalid pattern char {c}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn duplicate_letter_scoring() {
+        let cases = [
+            //guess, answer, expected
+            ("EERIE", "THEME", "Y---G"),
+            ("THEME", "EERIE", "--Y-G"),
+            ("LOOKS", "SHOTS", "--G-G"),
+            ("SPEED", "ABIDE", "--Y-Y"),
+            ("SPEED", "STEAL", "G-G--"),
+            ("ABBEY", "KEBAB", "YYGY-"),
+            ("ALLOY", "LOYAL", "YYYYY"),
+            ("LLAMA", "SALAD", "Y-Y-Y"),
+            ("EEEEE", "THEME", "--G-G"),
+            ("EEEEE", "EERIE", "GG--G"),
+            ("SASSY", "CLASS", "YY-G-"),
+            ("ROBOT", "FLOOR", "YY-G-"),
+            ("GEESE", "EERIE", "-GY-G"),
+            ("ERROR", "RARER", "YYG-G"),
+            ("PAPPY", "APPLE", "YYG--"),
+            ("MAMMA", "MADAM", "GGY-Y"),
+            ("EERIE", "EERIE", "GGGGG"),
+            ("TOOT", "OTTO", "YYYY"),
+            ("BOOK", "OBOE", "YYG-"),
+            ("BANANA", "CABANA", "YG-GGG"),
+            ("LETTER", "TATTER", "--GGGG"),
+            ("COOKOO", "CUCKOO", "G--GGG"),
+            ("LLLLLLL", "BALLOON", "--GG---"),
+        ];
+        for (guess, answer, expected) in cases {
+            let states: Vec<SlotState> = score(guess, answer)
+                .into_iter()
+                .map(|slot| slot.state)
+                .collect();
+            assert_eq!(states, pattern(expected), "{guess} vs {answer}");
+        }
+    }
+
+    #[test]
+    fn guess_info_from_row() {
+        let info = SubmittedGuessInfo::from_row(&score("EERIE", "THEME"));
+        assert_eq!(info.word, "EERIE");
+        assert_eq!(info.matches, vec!['E']);
+        assert_eq!(info.mismatches, vec!['E']);
+        assert_eq!(info.no_matches, vec!['E', 'R', 'I']);
+    }
+
+    fn guess(engine: &mut WordleEngine, word: &str) -> Result<(), GuessError> {
+        engine.guess(word).map(|_| ())
+    }
+
+    #[test]
+    fn hard_mode_keeps_greens() {
+        let mut engine = WordleEngine::new(5, 0, Difficulty::Hard).unwrap();
+        engine.word = "GHOST".to_string();
+        guess(&mut engine, "BOOST").unwrap();
+        assert_eq!(
+            guess(&mut engine, "TOAST"),
+            Err(GuessError::HardMode(HardModeViolation::MissingMatch {
+                letter: 'O',
+                position: 2
+            }))
+        );
+        assert_eq!(engine.guesses.len(), 1);
+        assert_eq!(guess(&mut engine, "GHOST"), Ok(()));
+        assert_eq!(engine.state, EngineState::Found);
+    }
+
+    #[test]
+    fn hard_mode_reuses_yellows() {
+        let mut engine = WordleEngine::new(5, 0, Difficulty::Hard).unwrap();
+        engine.word = "GHOST".to_string();
+        guess(&mut engine, "SOUTH").unwrap();
+        assert_eq!(
+            guess(&mut engine, "PLANT"),
+            Err(GuessError::HardMode(HardModeViolation::MissingLetter {
+                letter: 'S',
+                count: 1
+            }))
+        );
+        //yellows can be reused in the same place and greys can be reused in hard mode
+        assert_eq!(guess(&mut engine, "SHOOT"), Ok(()));
+    }
+
+    #[test]
+    fn ultra_hard_mode_rules_out_positions_and_greys() {
+        let mut engine = WordleEngine::new(5, 0, Difficulty::UltraHard).unwrap();
+        engine.word = "GHOST".to_string();
+        guess(&mut engine, "SOUTH").unwrap();
+        assert_eq!(
+            guess(&mut engine, "SHOOT"),
+            Err(GuessError::HardMode(HardModeViolation::MisplacedLetter {
+                letter: 'S',
+                position: 0
+            }))
+        );
+
+        let mut engine = WordleEngine::new(5, 0, Difficulty::UltraHard).unwrap();
+        engine.word = "GHOST".to_string();
+        guess(&mut engine, "LOOKS").unwrap();
+        assert_eq!(
+            guess(&mut engine, "SHOOT"),
+            Err(GuessError::HardMode(HardModeViolation::TooManyLetter {
+                letter: 'O',
+                count: 1
+            }))
+        );
+        assert_eq!(guess(&mut engine, "GHOST"), Ok(()));
+    }
+
+    #[test]
+    fn normal_mode_ignores_hints() {
+        let mut engine = WordleEngine::new(5, 0, Difficulty::Normal).unwrap();
+        engine.word = "GHOST".to_string();
+        guess(&mut engine, "BOOST").unwrap();
+        assert_eq!(guess(&mut engine, "PLANT"), Ok(()));
+    }
+
+    #[test]
+    fn invalid_engines() {
+        assert_eq!(
+            WordleEngine::new(3, 0, Difficulty::Normal).unwrap_err(),
+            EngineError::UnsupportedWordSize(3)
+        );
+        assert_eq!(
+            WordleEngine::new(5, usize::MAX, Difficulty::Normal).unwrap_err(),
+            EngineError::InvalidWordIndex {
+                word_size: 5,
+                idx: usize::MAX
+            }
+        );
+    }
+
+    #[test]
+    fn engine_for_word() {
+        let engine = WordleEngine::with_word(" ghost ", Difficulty::Hard).unwrap();
+        assert_eq!(engine.word, "GHOST");
+        assert_eq!(engine.word_size, 5);
+        assert_eq!(engine.max_guess_count, 6);
+        assert_eq!(engine.difficulty, Difficulty::Hard);
+        //guess only words are allowed too
+        assert!(WordleEngine::with_word("CHIMP", Difficulty::Normal).is_ok());
+        assert_eq!(
+            WordleEngine::with_word("GHOSTLIEST", Difficulty::Normal).unwrap_err(),
+            EngineError::UnsupportedWordSize(10)
+        );
+        assert_eq!(
+            WordleEngine::with_word("GHOSX", Difficulty::Normal).unwrap_err(),
+            EngineError::NotAWord
+        );
+        assert_eq!(
+            WordleEngine::with_word("GH0ST", Difficulty::Normal).unwrap_err(),
+            EngineError::NotAWord
+        );
+    }
+
+    #[test]
+    fn input_is_normalised() {
+        let mut engine = WordleEngine::new(5, 0, Difficulty::Normal).unwrap();
+        engine.word = "GHOST".to_string();
+        engine.add_letter('b').unwrap();
+        assert_eq!(engine.current_guess, vec!['B']);
+        assert_eq!(
+            engine.add_letter('1'),
+            Err(GuessError::InvalidCharacter('1'))
+        );
+        let info = engine.guess("  boost\n").unwrap();
+        assert_eq!(info.word, "BOOST");
+        assert_eq!(engine.current_guess, vec![]);
+    }
+
+    #[test]
+    fn guess_errors() {
+        let mut engine = WordleEngine::new(5, 0, Difficulty::Normal).unwrap();
+        engine.word = "GHOST".to_string();
+        assert_eq!(
+            engine.submit().unwrap_err(),
+            GuessError::WrongLength {
+                expected: 5,
+                actual: 0
+            }
+        );
+        assert_eq!(
+            engine.guess("BOOSTS").unwrap_err(),
+            GuessError::WrongLength {
+                expected: 5,
+                actual: 6
+            }
+        );
+        assert_eq!(
+            engine.guess("BO-ST").unwrap_err(),
+            GuessError::InvalidCharacter('-')
+        );
+        assert_eq!(engine.guess("QQQQQ").unwrap_err(), GuessError::NotAWord);
+        for letter in "GHOST".chars() {
+            engine.add_letter(letter).unwrap();
+        }
+        assert_eq!(
+            engine.add_letter('S'),
+            Err(GuessError::WrongLength {
+                expected: 5,
+                actual: 6
+            })
+        );
+        engine.submit().unwrap();
+        assert_eq!(engine.state, EngineState::Found);
+        assert_eq!(engine.guess("GHOST").unwrap_err(), GuessError::GameFinished);
+        assert_eq!(engine.add_letter('A'), Err(GuessError::GameFinished));
+    }
+
+    #[test]
+    fn save_and_restore() {
+        let mut engine = WordleEngine::new(5, 0, Difficulty::Hard).unwrap();
+        engine.word = "GHOST".to_string();
+        engine.guess("BOOST").unwrap();
+        engine.add_letter('G').unwrap();
+        let json = serde_json::to_string(&engine).unwrap();
+        let mut restored: WordleEngine = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, engine);
+        assert_eq!(
+            restored.guess("TOAST").unwrap_err(),
+            GuessError::HardMode(HardModeViolation::MissingMatch {
+                letter: 'O',
+                position: 2
+            })
+        );
+    }
+
+    #[test]
+    fn pattern_matches_score() {
+        assert_eq!(super::pattern("CRANE", "CRANE"), Some(242));
+        assert_eq!(super::pattern("CRANE", "BOOST"), Some(0));
+        //E Y, E -, R -, I -, E G
+        assert_eq!(super::pattern("EERIE", "THEME"), Some(1 + 2 * 81));
+        let digit = |state| match state {
+            SlotState::NoMatch => 0,
+            SlotState::WrongPos => 1,
+            SlotState::Match => 2,
+        };
+        for (guess, answer) in [("SPEED", "ABIDE"), ("OOZED", "GOOFY"), ("ROBOT", "FLOOR")] {
+            let from_row = score(guess, answer)
+                .iter()
+                .rev()
+                .fold(0, |code, slot| code * 3 + digit(slot.state));
+            assert_eq!(super::pattern(guess, answer), Some(from_row));
+        }
+    }
+
+    #[test]
+    fn pattern_rejects_bad_lengths() {
+        assert_eq!(super::pattern("CRANE", "GHOSTS"), None);
+        let long = "A".repeat(MAX_PATTERN_LEN + 1);
+        assert_eq!(super::pattern(&long, &long), None);
+        let longest = "A".repeat(MAX_PATTERN_LEN);
+        assert_eq!(
+            super::pattern(&longest, &longest),
+            Some(3_u32.pow(MAX_PATTERN_LEN as u32) - 1)
+        );
+        //more copies of a letter than fit in a byte
+        let many = "A".repeat(300);
+        assert!(score(&many, &"B".repeat(300))
+            .iter()
+            .all(|slot| slot.state == SlotState::NoMatch));
+    }
+
+    #[test]
+    fn restore_without_hints() {
+        let engine = WordleEngine::new(5, 0, Difficulty::Normal).unwrap();
+        let mut json = serde_json::to_value(&engine).unwrap();
+        json.as_object_mut().unwrap().remove("hints");
+        json.as_object_mut().unwrap().remove("adversarial");
+        let restored: WordleEngine = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, engine);
+    }
+
+    #[test]
+    fn basic_play() {
+        let mut engine = WordleEngine::new(4, 1244, Difficulty::Normal).unwrap();
+
+        assert_eq!(engine.guesses, Vec::<Vec<LetterSlot>>::new());
+        assert_eq!(engine.current_guess, vec![]);
+        assert_eq!(engine.state, EngineState::Guessing);
+        engine.add_letter('Q').unwrap();
+        assert_eq!(engine.current_guess, vec!['Q']);
+        assert_eq!(engine.state, EngineState::Guessing);
+        engine.add_letter('Q').unwrap();
+        assert_eq!(engine.current_guess, vec!['Q', 'Q']);
+        assert_eq!(engine.state, EngineState::Guessing);
+        engine.add_letter('A').unwrap();
+        assert_eq!(engine.current_guess, vec!['Q', 'Q', 'A']);
+        assert_eq!(engine.state, EngineState::Guessing);
+        engine.add_letter('S').unwrap();
+        assert_eq!(engine.current_guess, vec!['Q', 'Q', 'A', 'S']);
+        assert_eq!(engine.state, EngineState::Guessing);
+        assert_eq!(engine.guesses, Vec::<Vec<LetterSlot>>::new());
+        assert!(engine.submit().is_err());
+        assert_eq!(engine.guesses, Vec::<Vec<LetterSlot>>::new());
+        engine.current_guess = vec!['O', 'V', 'E', 'R'];
+        assert!(engine.submit().is_ok());
+        assert_eq!(
+            engine.guesses,
+            vec![vec![
+                LetterSlot::new('O', SlotState::WrongPos),
+                LetterSlot::new('V', SlotState::NoMatch),
+                LetterSlot::new('E', SlotState::NoMatch),
+                LetterSlot::new('R', SlotState::WrongPos),
+            ]]
+        );
+        assert_eq!(engine.current_guess, vec![]);
+        assert_eq!(engine.state, EngineState::Guessing);
+        engine.add_letter('T').unwrap();
+        engine.add_letter('O').unwrap();
+        engine.add_letter('R').unwrap();
+        engine.add_letter('T').unwrap();
+        assert!(engine.submit().is_ok());
+        assert_eq!(engine.state, EngineState::Found);
+    }
+}
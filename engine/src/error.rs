@@ -0,0 +1,170 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Problems creating a [WordleEngine](crate::WordleEngine)
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EngineError {
+    /// Only 4 to 7 letter words are supported
+    UnsupportedWordSize(usize),
+    /// There is no answer at `idx` for `word_size`
+    InvalidWordIndex { word_size: usize, idx: usize },
+    /// The word isn't in the dictionary
+    NotAWord,
+}
+
+/// Problems creating or reading a puzzle code, see [puzzle_code](crate::puzzle_code)
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CodeError {
+    /// Only 4 to 7 letter words are supported
+    UnsupportedWordSize(usize),
+    /// The word isn't in the dictionary
+    NotAWord,
+    /// Codes are 7 to 10 letters long, this one has this many
+    WrongLength(usize),
+    /// Only A-Z (in any case) are allowed
+    InvalidCharacter(char),
+    /// The code has been changed or mistyped
+    Invalid,
+}
+
+/// Reasons a guess (or letter) was rejected
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GuessError {
+    /// Guess has `actual` letters but needs `expected`
+    WrongLength {
+        expected: usize,
+        actual: usize,
+    },
+    /// Only A-Z (in any case) are allowed
+    InvalidCharacter(char),
+    NotAWord,
+    /// The word has been found or there are no guesses left
+    GameFinished,
+    HardMode(HardModeViolation),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum HardModeViolation {
+    /// `letter` must be at `position`
+    MissingMatch { letter: char, position: usize },
+    /// guess must contain `letter` at least `count` times
+    MissingLetter { letter: char, count: usize },
+    /// `letter` has already been ruled out at `position`
+    MisplacedLetter { letter: char, position: usize },
+    /// guess can't contain `letter` more than `count` times
+    TooManyLetter { letter: char, count: usize },
+}
+
+impl Display for EngineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EngineError::UnsupportedWordSize(size) => {
+                write!(f, "Unsupported word size: {size}")
+            }
+            EngineError::InvalidWordIndex { word_size, idx } => {
+                write!(f, "No {word_size} letter word at {idx}")
+            }
+            EngineError::NotAWord => write!(f, "Not a word"),
+        }
+    }
+}
+
+impl Display for CodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CodeError::UnsupportedWordSize(size) => {
+                write!(f, "Unsupported word size: {size}")
+            }
+            CodeError::NotAWord => write!(f, "Not a word"),
+            CodeError::WrongLength(len) => write!(f, "Code must be 7 to 10 letters, was {len}"),
+            CodeError::InvalidCharacter(chr) => write!(f, "Invalid character: {chr:?}"),
+            CodeError::Invalid => write!(f, "Invalid code"),
+        }
+    }
+}
+
+impl Display for GuessError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuessError::WrongLength { expected, actual } => {
+                write!(f, "Guess must be {expected} letters, was {actual}")
+            }
+            GuessError::InvalidCharacter(chr) => write!(f, "Invalid character: {chr:?}"),
+            GuessError::NotAWord => write!(f, "Not a word"),
+            GuessError::GameFinished => write!(f, "Game has finished"),
+            GuessError::HardMode(violation) => violation.fmt(f),
+        }
+    }
+}
+
+impl Display for HardModeViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HardModeViolation::MissingMatch { letter, position } => {
+                write!(f, "{} letter must be {letter}", ordinal(position + 1))
+            }
+            HardModeViolation::MissingLetter { letter, count: 1 } => {
+                write!(f, "Guess must contain {letter}")
+            }
+            HardModeViolation::MissingLetter { letter, count } => {
+                write!(f, "Guess must contain {count} {letter}s")
+            }
+            HardModeViolation::MisplacedLetter { letter, position } => {
+                write!(f, "{} letter can't be {letter}", ordinal(position + 1))
+            }
+            HardModeViolation::TooManyLetter { letter, count: 0 } => {
+                write!(f, "Guess can't contain {letter}")
+            }
+            HardModeViolation::TooManyLetter { letter, count } => {
+                write!(f, "Guess can't contain more than {count} {letter}")
+            }
+        }
+    }
+}
+
+impl Error for EngineError {}
+impl Error for CodeError {}
+impl Error for GuessError {}
+impl Error for HardModeViolation {}
+
+fn ordinal(num: usize) -> String {
+    match num {
+        1 => "1st".to_string(),
+        2 => "2nd".to_string(),
+        3 => "3rd".to_string(),
+        _ => format!("{num}th"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hard_mode_messages() {
+        assert_eq!(
+            HardModeViolation::MissingMatch {
+                letter: 'O',
+                position: 2
+            }
+            .to_string(),
+            "3rd letter must be O"
+        );
+        assert_eq!(
+            HardModeViolation::MissingLetter {
+                letter: 'E',
+                count: 2
+            }
+            .to_string(),
+            "Guess must contain 2 Es"
+        );
+        assert_eq!(
+            HardModeViolation::TooManyLetter {
+                letter: 'L',
+                count: 0
+            }
+            .to_string(),
+            "Guess can't contain L"
+        );
+    }
+}
@@ -0,0 +1,202 @@
+//! Record of finished games, kept as a file with one JSON object per line
+//!
+//! Records are only ever appended so a crash can at worst leave a partial last line,
+//! lines that can't be read are skipped when loading
+//!
+//! Records are generic over the game mode, tools that don't know the modes can use `String`
+
+use crate::{Difficulty, EngineState, LetterSlot, WordleEngine};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Outcome {
+    Won,
+    Lost,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameRecord<M> {
+    pub answer: String,
+    pub word_size: usize,
+    pub mode: M,
+    pub difficulty: Difficulty,
+    /// Every guess with how each letter was scored
+    pub guesses: Vec<Vec<LetterSlot>>,
+    /// Seconds since 1970-01-01 UTC that the game was started
+    pub started: u64,
+    /// Seconds since 1970-01-01 UTC that the game ended
+    pub finished: u64,
+    /// Seconds spent playing, not counting time the game was closed
+    pub duration: f64,
+    pub hints: usize,
+    pub outcome: Outcome,
+}
+
+impl<M> GameRecord<M> {
+    /// Record for a finished game, `None` if `engine` is still being played
+    pub fn new(
+        engine: &WordleEngine,
+        mode: M,
+        started: u64,
+        finished: u64,
+        duration: f64,
+    ) -> Option<Self> {
+        let outcome = match engine.state {
+            EngineState::Guessing => return None,
+            EngineState::Found => Outcome::Won,
+            EngineState::OutOfGuesses => Outcome::Lost,
+        };
+        Some(GameRecord {
+            answer: engine.word.clone(),
+            word_size: engine.word_size,
+            mode,
+            difficulty: engine.difficulty,
+            guesses: engine.guesses.clone(),
+            started,
+            finished,
+            duration,
+            hints: engine.hints,
+            outcome,
+        })
+    }
+
+    /// The game as it was after the first `rows` guesses, for replaying it
+    ///
+    /// The guess limit isn't recorded so it's the usual limit, or the number of guesses
+    /// for games that went past it
+    pub fn board(&self, rows: usize) -> WordleEngine {
+        let rows = rows.min(self.guesses.len());
+        let state = match (rows == self.guesses.len(), self.outcome) {
+            (false, _) => EngineState::Guessing,
+            (true, Outcome::Won) => EngineState::Found,
+            (true, Outcome::Lost) => EngineState::OutOfGuesses,
+        };
+        WordleEngine {
+            word_size: self.word_size,
+            word: self.answer.clone(),
+            guesses: self.guesses[..rows].to_vec(),
+            max_guess_count: (self.word_size + 1).max(self.guesses.len()),
+            state,
+            current_guess: vec![],
+            difficulty: self.difficulty,
+            hints: self.hints,
+            adversarial: false,
+        }
+    }
+}
+
+/// Adds `record` to the end of the file at `path`, creating it if needed
+pub fn append<M: Serialize>(path: &Path, record: &GameRecord<M>) -> io::Result<()> {
+    let mut line = serde_json::to_string(record)?;
+    line.push('\n');
+    let mut file = OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .open(path)?;
+    if !ends_with_newline(&mut file)? {
+        //the last record was cut off, it's skipped when loading but this one shouldn't be
+        line.insert(0, '\n');
+    }
+    file.write_all(line.as_bytes())
+}
+
+/// Every record that can be read from the file at `path`, oldest first
+///
+/// A missing file has no records
+pub fn load<M: DeserializeOwned>(path: &Path) -> io::Result<Vec<GameRecord<M>>> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(parse(&String::from_utf8_lossy(&bytes))),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(vec![]),
+        Err(err) => Err(err),
+    }
+}
+
+/// Records from `text`, one per line, lines that aren't valid records are skipped
+pub fn parse<M: DeserializeOwned>(text: &str) -> Vec<GameRecord<M>> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// True if `file` is empty or its last byte is a newline
+fn ends_with_newline(file: &mut File) -> io::Result<bool> {
+    if file.metadata()?.len() == 0 {
+        return Ok(true);
+    }
+    file.seek(SeekFrom::End(-1))?;
+    let mut last = [0];
+    file.read_exact(&mut last)?;
+    Ok(last[0] == b'\n')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::engine::played_game;
+    use std::env::temp_dir;
+
+    fn record(guesses: &[&str]) -> GameRecord<String> {
+        let engine = played_game("GHOST", guesses, Difficulty::Normal);
+        GameRecord::new(&engine, String::from("Classic"), 100, 160, 42.5).unwrap()
+    }
+
+    #[test]
+    fn unfinished_games_arent_recorded() {
+        let engine = WordleEngine::with_word("GHOST", Difficulty::Normal).unwrap();
+        assert!(GameRecord::new(&engine, (), 0, 0, 0.0).is_none());
+        assert_eq!(record(&["GHOST"]).outcome, Outcome::Won);
+        assert_eq!(record(&["CRANE"; 6]).outcome, Outcome::Lost);
+    }
+
+    #[test]
+    fn boards() {
+        let record = record(&["CRANE", "BOOST", "GHOST"]);
+        let start = record.board(0);
+        assert!(start.guesses.is_empty());
+        assert_eq!(start.state, EngineState::Guessing);
+        let middle = record.board(2);
+        assert_eq!(middle.guesses, record.guesses[..2]);
+        assert_eq!(middle.state, EngineState::Guessing);
+        assert_eq!(record.board(10).state, EngineState::Found);
+        assert_eq!(record.board(3).max_guess_count, 6);
+    }
+
+    #[test]
+    fn bad_lines_are_skipped() {
+        let won = serde_json::to_string(&record(&["CRANE", "GHOST"])).unwrap();
+        let lost = serde_json::to_string(&record(&["CRANE"; 6])).unwrap();
+        let text = format!(
+            "{won}\nnot json\n\n{{\"answer\":\"GHOST\"}}\n{lost}\n{}",
+            &won[..won.len() / 2]
+        );
+        let records: Vec<GameRecord<String>> = parse(&text);
+        assert_eq!(
+            records,
+            vec![record(&["CRANE", "GHOST"]), record(&["CRANE"; 6])]
+        );
+    }
+
+    #[test]
+    fn append_after_truncated_line() {
+        let path = temp_dir().join(format!("wordle_history_test_{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        assert!(load::<String>(&path).unwrap().is_empty());
+
+        append(&path, &record(&["GHOST"])).unwrap();
+        let line = serde_json::to_string(&record(&["CRANE", "GHOST"])).unwrap();
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(&line.as_bytes()[..line.len() - 5]).unwrap();
+        drop(file);
+        append(&path, &record(&["CRANE"; 6])).unwrap();
+
+        let records: Vec<GameRecord<String>> = load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(records, vec![record(&["GHOST"]), record(&["CRANE"; 6])]);
+    }
+}
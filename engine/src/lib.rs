@@ -0,0 +1,50 @@
+//! Game logic for Wordle
+//!
+//! [WordleEngine] runs a single game and [MultiEngine] runs several at once, guesses are
+//! scored with [score]. Answers are played in a [WordOrder] and the result of each answer
+//! is kept in [WordProgress]
+//!
+//! - [word_list] has the answers and the dictionary guesses are checked against
+//! - [daily] picks the word for each day's puzzle
+//! - [solver] finds the words still possible after some guesses
+//! - [advisor] ranks the next guess
+//! - [analysis] rates the guesses of a game
+//! - [adversary] picks the answer for games where it isn't chosen up front
+//! - [endless] scores games played back to back
+//! - [time_attack] times games played back to back
+//! - [versus] scores two players picking words for each other
+//! - [puzzle_code] shares a word without showing it
+//! - [share] shares a result without showing the letters
+//! - [stats] keeps the results of finished games
+//! - [history] stores every finished game
+//!
+//! This crate has no UI dependencies so it can be used by tools as well as the game
+
+pub mod adversary;
+pub mod advisor;
+pub mod analysis;
+pub mod daily;
+pub mod endless;
+mod engine;
+mod error;
+pub mod history;
+mod multi;
+mod order;
+mod progress;
+pub mod puzzle_code;
+mod rng;
+pub mod share;
+pub mod solver;
+pub mod stats;
+pub mod time_attack;
+pub mod versus;
+pub mod word_list;
+
+pub use engine::{
+    pattern, score, Difficulty, EngineState, LetterSlot, SlotState, SubmittedGuessInfo,
+    WordleEngine, MAX_PATTERN_LEN,
+};
+pub use error::{CodeError, EngineError, GuessError, HardModeViolation};
+pub use multi::MultiEngine;
+pub use order::WordOrder;
+pub use progress::{ProgressCounts, WordOutcome, WordProgress};
@@ -0,0 +1,234 @@
+use crate::engine::normalise_letter;
+use crate::{Difficulty, EngineError, EngineState, GuessError, SubmittedGuessInfo, WordleEngine};
+use serde::{Deserialize, Serialize};
+
+/// Several games played at once, every guess is submitted to each unsolved board
+///
+/// The game is won when every board is solved, and lost if any board is
+/// still unsolved after `max_guess_count` guesses
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MultiEngine {
+    pub word_size: usize,
+    pub boards: Vec<WordleEngine>,
+    pub max_guess_count: usize,
+    pub state: EngineState,
+    pub current_guess: Vec<char>,
+}
+
+impl MultiEngine {
+    /// Start a game with one board for each index into the answers for `word_size`
+    ///
+    /// Players get one extra guess for each board after the first
+    pub fn new(
+        word_size: usize,
+        idxs: &[usize],
+        difficulty: Difficulty,
+    ) -> Result<Self, EngineError> {
+        let max_guess_count = word_size + idxs.len();
+        let boards = idxs
+            .iter()
+            .map(|idx| {
+                WordleEngine::new(word_size, *idx, difficulty).map(|mut engine| {
+                    engine.max_guess_count = max_guess_count;
+                    engine
+                })
+            })
+            .collect::<Result<Vec<WordleEngine>, EngineError>>()?;
+        Ok(MultiEngine {
+            word_size,
+            boards,
+            max_guess_count,
+            state: EngineState::Guessing,
+            current_guess: vec![],
+        })
+    }
+
+    /// Number of guesses submitted so far
+    pub fn guess_count(&self) -> usize {
+        self.boards
+            .iter()
+            .map(|board| board.guesses.len())
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub fn add_letter(&mut self, letter: char) -> Result<(), GuessError> {
+        if self.state != EngineState::Guessing {
+            return Err(GuessError::GameFinished);
+        }
+        let letter = normalise_letter(letter)?;
+        if self.current_guess.len() >= self.word_size {
+            return Err(GuessError::WrongLength {
+                expected: self.word_size,
+                actual: self.current_guess.len() + 1,
+            });
+        }
+        self.current_guess.push(letter);
+        self.sync_current_guess();
+        Ok(())
+    }
+
+    pub fn backspace(&mut self) {
+        if self.state == EngineState::Guessing && !self.current_guess.is_empty() {
+            self.current_guess.remove(self.current_guess.len() - 1);
+            self.sync_current_guess();
+        }
+    }
+
+    /// Replaces the current guess with `word` and submits it
+    ///
+    /// Surrounding whitespace is ignored and letters can be in any case
+    pub fn guess(&mut self, word: &str) -> Result<Vec<Option<SubmittedGuessInfo>>, GuessError> {
+        if self.state != EngineState::Guessing {
+            return Err(GuessError::GameFinished);
+        }
+        let letters = word
+            .trim()
+            .chars()
+            .map(normalise_letter)
+            .collect::<Result<Vec<char>, GuessError>>()?;
+        let previous = std::mem::replace(&mut self.current_guess, letters);
+        let result = self.submit();
+        if result.is_err() {
+            self.current_guess = previous;
+        }
+        self.sync_current_guess();
+        result
+    }
+
+    /// Submits the current guess to every unsolved board
+    ///
+    /// Returns the result for each board, `None` for boards that were already solved
+    /// The guess is checked against every board first so either all boards accept it or none do
+    pub fn submit(&mut self) -> Result<Vec<Option<SubmittedGuessInfo>>, GuessError> {
+        if self.state != EngineState::Guessing {
+            return Err(GuessError::GameFinished);
+        }
+        for board in self.unsolved() {
+            board.validate(&self.current_guess)?;
+        }
+        let mut results = vec![];
+        for board in &mut self.boards {
+            if board.state == EngineState::Guessing {
+                board.current_guess.clone_from(&self.current_guess);
+                results.push(Some(board.submit()?));
+            } else {
+                results.push(None);
+            }
+        }
+        if self
+            .boards
+            .iter()
+            .all(|board| board.state == EngineState::Found)
+        {
+            self.state = EngineState::Found;
+        } else if self
+            .boards
+            .iter()
+            .any(|board| board.state == EngineState::OutOfGuesses)
+        {
+            self.state = EngineState::OutOfGuesses;
+        }
+        self.current_guess.clear();
+        Ok(results)
+    }
+
+    fn unsolved(&self) -> impl Iterator<Item = &WordleEngine> {
+        self.boards
+            .iter()
+            .filter(|board| board.state == EngineState::Guessing)
+    }
+
+    /// Unsolved boards show the current guess
+    fn sync_current_guess(&mut self) {
+        for board in &mut self.boards {
+            if board.state == EngineState::Guessing {
+                board.current_guess.clone_from(&self.current_guess);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::word_list::answers_for_size;
+
+    fn engine(words: &[&str]) -> MultiEngine {
+        let answers = answers_for_size(5).unwrap();
+        let idxs: Vec<usize> = words
+            .iter()
+            .map(|word| answers.iter().position(|answer| answer == word).unwrap())
+            .collect();
+        MultiEngine::new(5, &idxs, Difficulty::Normal).unwrap()
+    }
+
+    #[test]
+    fn guess_limit_grows() {
+        assert_eq!(engine(&["CRANE", "GHOST"]).max_guess_count, 7);
+        let quad = engine(&["CRANE", "GHOST", "SHARK", "PIANO"]);
+        assert_eq!(quad.max_guess_count, 9);
+        assert!(quad.boards.iter().all(|board| board.max_guess_count == 9));
+        assert!(MultiEngine::new(3, &[0, 1], Difficulty::Normal).is_err());
+        assert!(MultiEngine::new(5, &[0, 99999], Difficulty::Normal).is_err());
+    }
+
+    #[test]
+    fn solved_boards_stop_guessing() {
+        let mut multi = engine(&["CRANE", "GHOST"]);
+        "crane"
+            .chars()
+            .for_each(|chr| multi.add_letter(chr).unwrap());
+        assert!(multi
+            .boards
+            .iter()
+            .all(|board| board.current_guess == multi.current_guess));
+        let results = multi.submit().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(multi.boards[0].state, EngineState::Found);
+        assert_eq!(multi.state, EngineState::Guessing);
+
+        let results = multi.guess("ghost").unwrap();
+        assert!(results[0].is_none());
+        assert!(results[1].is_some());
+        assert_eq!(multi.boards[0].guesses.len(), 1);
+        assert_eq!(multi.boards[1].guesses.len(), 2);
+        assert_eq!(multi.guess_count(), 2);
+        assert_eq!(multi.state, EngineState::Found);
+        assert_eq!(multi.guess("crane").unwrap_err(), GuessError::GameFinished);
+    }
+
+    #[test]
+    fn out_of_guesses() {
+        let mut multi = engine(&["CRANE", "GHOST"]);
+        for _ in 0..6 {
+            multi.guess("SHARK").unwrap();
+        }
+        assert_eq!(multi.state, EngineState::Guessing);
+        multi.guess("CRANE").unwrap();
+        assert_eq!(multi.state, EngineState::OutOfGuesses);
+        assert_eq!(multi.boards[0].state, EngineState::Found);
+    }
+
+    #[test]
+    fn invalid_guesses_change_nothing() {
+        let mut multi = engine(&["CRANE", "GHOST"]);
+        assert_eq!(multi.guess("XXXXX").unwrap_err(), GuessError::NotAWord);
+        assert_eq!(
+            multi.guess("CRAN").unwrap_err(),
+            GuessError::WrongLength {
+                expected: 5,
+                actual: 4
+            }
+        );
+        assert!(multi.boards.iter().all(|board| board.guesses.is_empty()));
+
+        let mut hard = MultiEngine::new(5, &[0, 1], Difficulty::Hard).unwrap();
+        hard.boards[0].word = String::from("GHOST");
+        hard.boards[1].word = String::from("CRANE");
+        hard.guess("BOOST").unwrap();
+        //fine for the second board but not the first
+        assert!(hard.guess("CRANE").is_err());
+        assert!(hard.boards.iter().all(|board| board.guesses.len() == 1));
+    }
+}
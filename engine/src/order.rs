@@ -0,0 +1,133 @@
+use crate::rng::{mix, Rng};
+use crate::word_list::word_count_for_size;
+use crate::EngineError;
+use serde::{Deserialize, Serialize};
+
+/// Shuffled order to play the answers for a word size in
+///
+/// Each player should have their own `seed`, the same seed always gives the same order.
+/// Words added to the end of the answer list are shuffled separately and played after
+/// the existing words, so positions already played keep the same words
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct WordOrder {
+    seed: u64,
+    word_size: usize,
+    /// Length of the answer list each time words were added, the first is the original length
+    segments: Vec<usize>,
+}
+
+impl WordOrder {
+    pub fn new(seed: u64, word_size: usize) -> Result<Self, EngineError> {
+        let count = word_count_for_size(word_size);
+        if count == 0 {
+            return Err(EngineError::UnsupportedWordSize(word_size));
+        }
+        Ok(WordOrder {
+            seed,
+            word_size,
+            segments: vec![count],
+        })
+    }
+
+    /// Add any words appended to the answer list since the order was made
+    ///
+    /// If words have been removed the order is reshuffled, returns true in this case
+    /// as any progress through the order no longer applies
+    pub fn update(&mut self) -> bool {
+        let count = word_count_for_size(self.word_size);
+        let last = self.len();
+        if count < last {
+            self.segments = vec![count];
+            true
+        } else {
+            if count > last {
+                self.segments.push(count);
+            }
+            false
+        }
+    }
+
+    /// Number of words in the order
+    pub fn len(&self) -> usize {
+        self.segments.last().copied().unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn word_size(&self) -> usize {
+        self.word_size
+    }
+
+    /// Indexes into the answer list in the order they should be played
+    pub fn indexes(&self) -> Vec<usize> {
+        let mut order = Vec::with_capacity(self.len());
+        let mut start = 0;
+        for &end in &self.segments {
+            let mut segment: Vec<usize> = (start..end).collect();
+            Rng::new(mix(self.seed ^ mix(self.word_size as u64) ^ start as u64))
+                .shuffle(&mut segment);
+            order.extend(segment);
+            start = end;
+        }
+        order
+    }
+
+    /// Index into the answer list of the word at `position` in the order
+    pub fn word_idx(&self, position: usize) -> Option<usize> {
+        self.indexes().get(position).copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn order_is_permutation() {
+        let order = WordOrder::new(42, 5).unwrap();
+        let indexes = order.indexes();
+        assert_eq!(indexes.len(), word_count_for_size(5));
+        assert_eq!(
+            indexes.iter().copied().collect::<HashSet<_>>().len(),
+            indexes.len()
+        );
+        assert_ne!(indexes[..20], (0..20).collect::<Vec<_>>());
+        assert_eq!(indexes, WordOrder::new(42, 5).unwrap().indexes());
+        assert_ne!(indexes, WordOrder::new(43, 5).unwrap().indexes());
+        assert!(WordOrder::new(42, 3).is_err());
+    }
+
+    #[test]
+    fn appended_words_are_played_last() {
+        let count = word_count_for_size(6);
+        let mut order = WordOrder {
+            seed: 7,
+            word_size: 6,
+            segments: vec![count - 30],
+        };
+        let before = order.indexes();
+        assert!(!order.update());
+        assert_eq!(order.len(), count);
+        let after = order.indexes();
+        assert_eq!(after[..before.len()], before[..]);
+        let mut added = after[before.len()..].to_vec();
+        added.sort();
+        assert_eq!(added, (count - 30..count).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn removed_words_reshuffle() {
+        let count = word_count_for_size(4);
+        let mut order = WordOrder {
+            seed: 7,
+            word_size: 4,
+            segments: vec![count - 10, count + 5],
+        };
+        assert!(order.update());
+        assert_eq!(order.len(), count);
+        assert!(order.indexes().iter().all(|idx| *idx < count));
+    }
+}
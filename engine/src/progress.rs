@@ -0,0 +1,296 @@
+use crate::word_list::{answers_for_size, guesses_for_size};
+use crate::{EngineState, WordOrder, WordleEngine};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Result of an answer in classic play
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum WordOutcome {
+    #[default]
+    Unplayed,
+    Solved,
+    Failed,
+    /// Played before the result of each word was kept, so it's not known if it was solved
+    Skipped,
+}
+
+/// Number of answers with each result, unplayed answers aren't counted
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct ProgressCounts {
+    pub solved: usize,
+    pub failed: usize,
+    pub skipped: usize,
+}
+
+/// Result of every answer played for a word size
+///
+/// Results are kept by word rather than by position in the answer list, so they survive
+/// words being added to or removed from the list
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(from = "SavedProgress", into = "SavedProgress")]
+pub struct WordProgress {
+    outcomes: HashMap<String, WordOutcome>,
+}
+
+/// How [WordProgress] is saved, the words with each result joined into one string
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SavedProgress {
+    #[serde(default)]
+    solved: String,
+    #[serde(default)]
+    failed: String,
+    #[serde(default)]
+    skipped: String,
+}
+
+impl From<SavedProgress> for WordProgress {
+    fn from(saved: SavedProgress) -> Self {
+        let mut progress = WordProgress::default();
+        for (words, outcome) in [
+            (saved.solved, WordOutcome::Solved),
+            (saved.failed, WordOutcome::Failed),
+            (saved.skipped, WordOutcome::Skipped),
+        ] {
+            for word in words.split_whitespace() {
+                progress.set(word, outcome);
+            }
+        }
+        progress
+    }
+}
+
+impl From<WordProgress> for SavedProgress {
+    fn from(progress: WordProgress) -> Self {
+        let mut words: Vec<(String, WordOutcome)> = progress.outcomes.into_iter().collect();
+        words.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let join = |outcome: WordOutcome| {
+            words
+                .iter()
+                .filter(|(_, word_outcome)| *word_outcome == outcome)
+                .map(|(word, _)| word.as_str())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        SavedProgress {
+            solved: join(WordOutcome::Solved),
+            failed: join(WordOutcome::Failed),
+            skipped: join(WordOutcome::Skipped),
+        }
+    }
+}
+
+impl WordProgress {
+    /// Progress from before results were kept, when the dictionary was played in its original
+    /// order and only the number of words played was saved, those words are marked
+    /// [WordOutcome::Skipped]
+    ///
+    /// The original dictionary is the start of the guess list, played words that aren't
+    /// answers any more are ignored. `None` if `word_size` isn't supported
+    pub fn from_played_count(word_size: usize, played: usize) -> Option<Self> {
+        let guesses = guesses_for_size(word_size)?;
+        let answers = answers_for_size(word_size)?;
+        let mut progress = WordProgress::default();
+        for word in &guesses[..played.min(guesses.len())] {
+            progress.set(word, WordOutcome::Skipped);
+        }
+        progress
+            .outcomes
+            .retain(|word, _| answers.contains(&word.as_str()));
+        Some(progress)
+    }
+
+    pub fn get(&self, word: &str) -> WordOutcome {
+        self.outcomes.get(word).copied().unwrap_or_default()
+    }
+
+    pub fn set(&mut self, word: &str, outcome: WordOutcome) {
+        if outcome == WordOutcome::Unplayed {
+            self.outcomes.remove(word);
+        } else {
+            self.outcomes.insert(word.to_string(), outcome);
+        }
+    }
+
+    /// Records the result of a finished game, unfinished games and words that aren't
+    /// answers are ignored
+    pub fn add_game(&mut self, engine: &WordleEngine) {
+        let outcome = match engine.state {
+            EngineState::Guessing => return,
+            EngineState::Found => WordOutcome::Solved,
+            EngineState::OutOfGuesses => WordOutcome::Failed,
+        };
+        let is_answer = answers_for_size(engine.word_size)
+            .map(|answers| answers.contains(&engine.word.as_str()))
+            .unwrap_or_default();
+        if is_answer {
+            self.set(&engine.word, outcome);
+        }
+    }
+
+    /// Number of answers for `word_size` with each result, words removed from the answer
+    /// list aren't counted
+    pub fn counts(&self, word_size: usize) -> ProgressCounts {
+        let mut counts = ProgressCounts::default();
+        for word in answers_for_size(word_size).unwrap_or_default() {
+            match self.get(word) {
+                WordOutcome::Unplayed => {}
+                WordOutcome::Solved => counts.solved += 1,
+                WordOutcome::Failed => counts.failed += 1,
+                WordOutcome::Skipped => counts.skipped += 1,
+            }
+        }
+        counts
+    }
+
+    /// Index into the answer list of the first word in `order` with one of `outcomes`
+    pub fn next(&self, order: &WordOrder, outcomes: &[WordOutcome]) -> Option<usize> {
+        self.next_after(order, outcomes, "")
+    }
+
+    /// Like [WordProgress::next] but starts after `after` in `order`, wrapping back to
+    /// the start, so `after` is only picked if no other word has one of `outcomes`
+    pub fn next_after(
+        &self,
+        order: &WordOrder,
+        outcomes: &[WordOutcome],
+        after: &str,
+    ) -> Option<usize> {
+        let answers = answers_for_size(order.word_size())?;
+        let indexes = order.indexes();
+        let start = indexes
+            .iter()
+            .position(|idx| answers[*idx] == after)
+            .map(|pos| pos + 1)
+            .unwrap_or_default();
+        indexes[start..]
+            .iter()
+            .chain(&indexes[..start])
+            .copied()
+            .find(|idx| outcomes.contains(&self.get(answers[*idx])))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Difficulty;
+
+    #[test]
+    fn saved_by_word() {
+        let mut progress = WordProgress::default();
+        progress.set("GHOST", WordOutcome::Solved);
+        progress.set("CRANE", WordOutcome::Solved);
+        progress.set("BOOST", WordOutcome::Failed);
+        progress.set("TEARS", WordOutcome::Skipped);
+        progress.set("TEARS", WordOutcome::Unplayed);
+        let json = serde_json::to_string(&progress).unwrap();
+        assert_eq!(
+            json,
+            r#"{"solved":"CRANE GHOST","failed":"BOOST","skipped":""}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<WordProgress>(&json).unwrap(),
+            progress
+        );
+        assert_eq!(
+            serde_json::from_str::<WordProgress>("{}").unwrap(),
+            WordProgress::default()
+        );
+
+        //words that aren't answers any more keep their result but aren't counted
+        progress.set("ZZZZZ", WordOutcome::Failed);
+        assert_eq!(progress.get("ZZZZZ"), WordOutcome::Failed);
+        assert_eq!(
+            progress.counts(5),
+            ProgressCounts {
+                solved: 2,
+                failed: 1,
+                skipped: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn played_count_marks_original_order() {
+        //early versions saved `word_idx`, the number of words played from the start of the list
+        let word_idx = HashMap::from([(4, 10), (5, 3), (7, 100_000)]);
+        let four = WordProgress::from_played_count(4, word_idx[&4]).unwrap();
+        let four_answers = answers_for_size(4).unwrap();
+        for word in &guesses_for_size(4).unwrap()[..10] {
+            let expected = if four_answers.contains(word) {
+                WordOutcome::Skipped
+            } else {
+                WordOutcome::Unplayed
+            };
+            assert_eq!(four.get(word), expected);
+        }
+        assert_eq!(four.outcomes.len(), four.counts(4).skipped);
+
+        let five = WordProgress::from_played_count(5, word_idx[&5]).unwrap();
+        let answers = answers_for_size(5).unwrap();
+        assert_eq!(five.counts(5).skipped, 3);
+        for word in &answers[..3] {
+            assert_eq!(five.get(word), WordOutcome::Skipped);
+        }
+        assert_eq!(five.get(answers[3]), WordOutcome::Unplayed);
+
+        let seven = WordProgress::from_played_count(7, word_idx[&7]).unwrap();
+        assert_eq!(seven.counts(7).skipped, answers_for_size(7).unwrap().len());
+        assert!(WordProgress::from_played_count(3, 1).is_none());
+    }
+
+    #[test]
+    fn games_and_next_word() {
+        let order = WordOrder::new(42, 5).unwrap();
+        let indexes = order.indexes();
+        let answers = answers_for_size(5).unwrap();
+        let word = |pos: usize| answers[indexes[pos]];
+        let mut progress = WordProgress::default();
+        for pos in 0..3 {
+            progress.set(word(pos), WordOutcome::Skipped);
+        }
+        assert_eq!(
+            progress.next(&order, &[WordOutcome::Unplayed]),
+            Some(indexes[3])
+        );
+
+        let mut engine = WordleEngine::with_word(word(3), Difficulty::Normal).unwrap();
+        progress.add_game(&engine);
+        assert_eq!(progress.get(word(3)), WordOutcome::Unplayed);
+        for _ in 0..engine.max_guess_count {
+            engine.guess(word(4)).unwrap();
+        }
+        progress.add_game(&engine);
+        assert_eq!(progress.get(word(3)), WordOutcome::Failed);
+        assert_eq!(
+            progress.next(&order, &[WordOutcome::Unplayed]),
+            Some(indexes[4])
+        );
+        assert_eq!(
+            progress.next(&order, &[WordOutcome::Failed]),
+            Some(indexes[3])
+        );
+
+        progress.set(word(10), WordOutcome::Failed);
+        let failed = [WordOutcome::Failed];
+        assert_eq!(
+            progress.next_after(&order, &failed, word(3)),
+            Some(indexes[10])
+        );
+        assert_eq!(
+            progress.next_after(&order, &failed, word(10)),
+            Some(indexes[3])
+        );
+        progress.set(word(10), WordOutcome::Solved);
+        assert_eq!(
+            progress.next_after(&order, &failed, word(3)),
+            Some(indexes[3])
+        );
+
+        let mut solved = WordProgress::default();
+        for answer in answers {
+            solved.set(answer, WordOutcome::Solved);
+        }
+        assert_eq!(solved.next(&order, &[WordOutcome::Unplayed]), None);
+    }
+}
@@ -0,0 +1,171 @@
+//! Short codes for sharing a puzzle without giving away the word
+//!
+//! A code is the word size, a checksum and the letters (scrambled using the checksum) packed
+//! into a number and written in base 26 using A-Z, so it can be typed on the game's keyboard
+//! Codes are always 3 letters longer than the word
+//!
+//! This only hides the word from a glance, it isn't encryption
+
+use crate::error::CodeError;
+use crate::rng::mix;
+use crate::word_list::{is_word, WORD_SIZES};
+
+const SIZE_BITS: u32 = 2;
+const CHECK_BITS: u32 = 10;
+const LETTER_BITS: u32 = 5;
+const CHECK_MASK: u64 = (1 << CHECK_BITS) - 1;
+const LETTER_MASK: u64 = (1 << LETTER_BITS) - 1;
+/// Changing this changes every code
+const KEY: u64 = 0x5eed_c0de_2024_0001;
+/// Number of letters a code has on top of the word
+const EXTRA_LETTERS: usize = 3;
+
+/// Code for a puzzle with the answer `word`, which can be any case but must be in the dictionary
+pub fn encode(word: &str) -> Result<String, CodeError> {
+    let word = word.trim().to_ascii_uppercase();
+    let word_size = word.chars().count();
+    if !WORD_SIZES.contains(&word_size) {
+        return Err(CodeError::UnsupportedWordSize(word_size));
+    }
+    if !is_word(word_size, &word) {
+        return Err(CodeError::NotAWord);
+    }
+    Ok(pack(&word))
+}
+
+/// `code` as [decode] reads it, in upper case without spaces or dashes
+pub fn normalise(code: &str) -> String {
+    code.chars()
+        .filter(|chr| !chr.is_whitespace() && *chr != '-')
+        .map(|chr| chr.to_ascii_uppercase())
+        .collect()
+}
+
+/// Word for a code made by [encode]
+///
+/// Case, spaces and dashes are ignored
+pub fn decode(code: &str) -> Result<String, CodeError> {
+    let code: Vec<char> = normalise(code).chars().collect();
+    let word_size = code
+        .len()
+        .checked_sub(EXTRA_LETTERS)
+        .filter(|size| WORD_SIZES.contains(size))
+        .ok_or(CodeError::WrongLength(code.len()))?;
+    let mut value: u64 = 0;
+    for chr in &code {
+        if !chr.is_ascii_uppercase() {
+            return Err(CodeError::InvalidCharacter(*chr));
+        }
+        value = value * 26 + (*chr as u64 - 'A' as u64);
+    }
+    if (value & ((1 << SIZE_BITS) - 1)) as usize + WORD_SIZES.start() != word_size {
+        return Err(CodeError::Invalid);
+    }
+    let check = (value >> SIZE_BITS) & CHECK_MASK;
+    let mut letters = value >> (SIZE_BITS + CHECK_BITS);
+    let key = mix(KEY ^ check);
+    let mut word = String::with_capacity(word_size);
+    for i in 0..word_size {
+        let letter = (letters & LETTER_MASK) ^ ((key >> (i as u32 * LETTER_BITS)) & LETTER_MASK);
+        if letter >= 26 {
+            return Err(CodeError::Invalid);
+        }
+        word.push((b'A' + letter as u8) as char);
+        letters >>= LETTER_BITS;
+    }
+    if letters != 0 || checksum(&word) != check {
+        return Err(CodeError::Invalid);
+    }
+    if !is_word(word_size, &word) {
+        return Err(CodeError::NotAWord);
+    }
+    Ok(word)
+}
+
+/// Code for `word` (uppercase A-Z, with a supported size), doesn't check the dictionary
+fn pack(word: &str) -> String {
+    let check = checksum(word);
+    let key = mix(KEY ^ check);
+    let mut value = 0;
+    for (i, letter) in word.bytes().enumerate().rev() {
+        let letter = (letter - b'A') as u64;
+        value =
+            (value << LETTER_BITS) | (letter ^ ((key >> (i as u32 * LETTER_BITS)) & LETTER_MASK));
+    }
+    value = (value << CHECK_BITS) | check;
+    value = (value << SIZE_BITS) | (word.len() - WORD_SIZES.start()) as u64;
+
+    let mut code = vec!['A'; word.len() + EXTRA_LETTERS];
+    for chr in code.iter_mut().rev() {
+        *chr = (b'A' + (value % 26) as u8) as char;
+        value /= 26;
+    }
+    code.into_iter().collect()
+}
+
+fn checksum(word: &str) -> u64 {
+    //the leading 1 means words of different lengths can't have the same value
+    let value = word.bytes().fold(1, |value, letter| {
+        (value << LETTER_BITS) | (letter - b'A') as u64
+    });
+    mix(KEY.wrapping_add(value)) & CHECK_MASK
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::word_list::answers_for_size;
+
+    #[test]
+    fn answers_round_trip() {
+        for size in WORD_SIZES {
+            for word in answers_for_size(size).unwrap() {
+                let code = encode(word).unwrap();
+                assert_eq!(code.len(), size + EXTRA_LETTERS);
+                assert!(!code.contains(word));
+                assert_eq!(decode(&code).as_deref(), Ok(*word));
+            }
+        }
+    }
+
+    #[test]
+    fn codes_are_forgiving() {
+        let code = encode("ghost").unwrap();
+        let typed = format!(" {}-{} ", &code[..4], &code[4..].to_lowercase());
+        assert_eq!(decode(&typed).as_deref(), Ok("GHOST"));
+        assert_eq!(normalise(&typed), code);
+        assert_eq!(normalise("ab-c d\n"), "ABCD");
+    }
+
+    #[test]
+    fn bad_words() {
+        assert_eq!(encode("GHOSX"), Err(CodeError::NotAWord));
+        assert_eq!(
+            encode("GHOSTLIEST"),
+            Err(CodeError::UnsupportedWordSize(10))
+        );
+        //valid code for a word that isn't in the dictionary
+        assert_eq!(decode(&pack("GHOSX")), Err(CodeError::NotAWord));
+    }
+
+    #[test]
+    fn tampered_codes() {
+        assert_eq!(decode("ABC"), Err(CodeError::WrongLength(3)));
+        assert_eq!(decode("ABCDEFGHIJK"), Err(CodeError::WrongLength(11)));
+        assert_eq!(decode("ABCD3FGH"), Err(CodeError::InvalidCharacter('3')));
+        for word in ["BAKE", "GHOST", "PLANET", "BALANCE"] {
+            let code = encode(word).unwrap();
+            for i in 0..code.len() {
+                for chr in 'A'..='Z' {
+                    let mut changed: Vec<char> = code.chars().collect();
+                    if changed[i] == chr {
+                        continue;
+                    }
+                    changed[i] = chr;
+                    let changed: String = changed.into_iter().collect();
+                    assert!(decode(&changed).is_err(), "{changed} for {word}");
+                }
+            }
+        }
+    }
+}
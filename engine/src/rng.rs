@@ -0,0 +1,43 @@
+//! Small deterministic random number generator
+//!
+//! The std hashers and external crates aren't guaranteed to produce the same numbers between
+//! versions, puzzles and word orders have to stay the same so this is implemented here
+
+/// splitmix64 finalizer, spreads the bits of `value`
+pub(crate) fn mix(value: u64) -> u64 {
+    let mut value = value;
+    value ^= value >> 30;
+    value = value.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value ^= value >> 27;
+    value = value.wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
+
+/// splitmix64
+#[derive(Debug, Clone)]
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        mix(self.state)
+    }
+
+    /// Random number in `0..max`, `max` must not be 0
+    pub fn below(&mut self, max: usize) -> usize {
+        ((self.next_u64() as u128 * max as u128) >> 64) as usize
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Spoiler free results for sharing, the colours of each guess without the letters
+
+use crate::{Difficulty, EngineState, LetterSlot, SlotState, WordleEngine};
+use serde::{Deserialize, Serialize};
+
+/// Symbols used for each letter in a shared grid
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Palette {
+    /// Green, yellow and black squares
+    #[default]
+    Standard,
+    /// Orange and blue squares, for players who can't tell green and yellow apart
+    Colourblind,
+    /// `#`, `+` and `.`, for places that can't show emoji
+    Ascii,
+}
+
+impl Palette {
+    pub fn symbol(&self, state: SlotState) -> &'static str {
+        match (self, state) {
+            (Palette::Standard, SlotState::Match) => "\u{1F7E9}",
+            (Palette::Standard, SlotState::WrongPos) => "\u{1F7E8}",
+            (Palette::Colourblind, SlotState::Match) => "\u{1F7E7}",
+            (Palette::Colourblind, SlotState::WrongPos) => "\u{1F7E6}",
+            (Palette::Standard | Palette::Colourblind, SlotState::NoMatch) => "\u{2B1B}",
+            (Palette::Ascii, SlotState::Match) => "#",
+            (Palette::Ascii, SlotState::WrongPos) => "+",
+            (Palette::Ascii, SlotState::NoMatch) => ".",
+        }
+    }
+
+    /// Palettes in turn, wrapping back to [Palette::Standard]
+    pub fn next(&self) -> Palette {
+        match self {
+            Palette::Standard => Palette::Colourblind,
+            Palette::Colourblind => Palette::Ascii,
+            Palette::Ascii => Palette::Standard,
+        }
+    }
+}
+
+/// One line of symbols per guess
+pub fn grid(rows: &[Vec<LetterSlot>], palette: Palette) -> String {
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|slot| palette.symbol(slot.state))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Guesses used out of the limit, e.g. `3/6`, or `X/6` if the word wasn't found
+///
+/// Games without a guess limit only show the guesses used, hard modes add a `*` for each level
+pub fn score_text(engine: &WordleEngine) -> String {
+    let used = if engine.state == EngineState::Found {
+        engine.guesses.len().to_string()
+    } else {
+        String::from("X")
+    };
+    let score = if engine.max_guess_count == usize::MAX {
+        used
+    } else {
+        format!("{used}/{}", engine.max_guess_count)
+    };
+    match engine.difficulty {
+        Difficulty::Normal => score,
+        Difficulty::Hard => format!("{score}*"),
+        Difficulty::UltraHard => format!("{score}**"),
+    }
+}
+
+/// `title` and the score on the first line, then a blank line and the grid
+pub fn share_text(title: &str, engine: &WordleEngine, palette: Palette) -> String {
+    format!(
+        "{title} {}\n\n{}",
+        score_text(engine),
+        grid(&engine.guesses, palette)
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::engine::played_game;
+
+    #[test]
+    fn palettes() {
+        let engine = played_game("GHOST", &["BOOST", "GHOST"], Difficulty::Normal);
+        assert_eq!(
+            share_text("Wordle", &engine, Palette::Standard),
+            "Wordle 2/6\n\n\u{2B1B}\u{2B1B}\u{1F7E9}\u{1F7E9}\u{1F7E9}\n\u{1F7E9}\u{1F7E9}\u{1F7E9}\u{1F7E9}\u{1F7E9}"
+        );
+        let engine = played_game("GHOST", &["SHORT"], Difficulty::Normal);
+        assert_eq!(
+            grid(&engine.guesses, Palette::Colourblind),
+            "\u{1F7E6}\u{1F7E7}\u{1F7E7}\u{2B1B}\u{1F7E7}"
+        );
+        assert_eq!(grid(&engine.guesses, Palette::Ascii), "+##.#");
+        assert_eq!(Palette::Ascii.next(), Palette::Standard);
+    }
+
+    #[test]
+    fn scores() {
+        let lost = played_game("GHOST", &["CRANE"; 6], Difficulty::Normal);
+        assert_eq!(score_text(&lost), "X/6");
+        let hard = played_game("BAKE", &["CAKE", "BAKE"], Difficulty::Hard);
+        assert_eq!(score_text(&hard), "2/5*");
+        let mut unlimited = played_game("BAKE", &["BAKE"], Difficulty::UltraHard);
+        unlimited.max_guess_count = usize::MAX;
+        assert_eq!(score_text(&unlimited), "1**");
+    }
+}
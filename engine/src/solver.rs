@@ -0,0 +1,195 @@
+//! Find the words still possible after some guesses
+//!
+//! [Constraints] are built from scored rows and can then be checked against any word list,
+//! [candidates] checks the full dictionary for a word size
+
+use crate::word_list::guesses_for_size;
+use crate::{LetterSlot, SlotState};
+
+/// Everything known about the answer from the guesses so far
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Constraints {
+    word_size: usize,
+    /// Letter (0 = A) known to be at each position
+    fixed: Vec<Option<u8>>,
+    /// Bitmask of letters (bit 0 = A) ruled out at each position
+    excluded: Vec<u32>,
+    /// Fewest times each letter can appear
+    min: [u8; 26],
+    /// Most times each letter can appear, exact when equal to `min`
+    max: [u8; 26],
+}
+
+impl Constraints {
+    /// Constraints with nothing known yet, every word of `word_size` matches
+    pub fn new(word_size: usize) -> Self {
+        Constraints {
+            word_size,
+            fixed: vec![None; word_size],
+            excluded: vec![0; word_size],
+            min: [0; 26],
+            max: [u8::MAX; 26],
+        }
+    }
+
+    pub fn from_rows(word_size: usize, rows: &[Vec<LetterSlot>]) -> Self {
+        let mut constraints = Constraints::new(word_size);
+        for row in rows {
+            constraints.add_row(row);
+        }
+        constraints
+    }
+
+    /// Add what's learnt from a scored guess
+    ///
+    /// A letter that's green or yellow `n` times must appear at least `n` times,
+    /// if the same letter is also grey in the row it must appear exactly `n` times
+    pub fn add_row(&mut self, row: &[LetterSlot]) {
+        let mut found = [0_u8; 26];
+        let mut capped = [false; 26];
+        for (i, slot) in row.iter().enumerate().take(self.word_size) {
+            let Some(letter) = letter_idx(slot.chr) else {
+                continue;
+            };
+            match slot.state {
+                SlotState::Match => {
+                    self.fixed[i] = Some(letter as u8);
+                    found[letter] += 1;
+                }
+                SlotState::WrongPos => {
+                    self.excluded[i] |= 1 << letter;
+                    found[letter] += 1;
+                }
+                SlotState::NoMatch => {
+                    self.excluded[i] |= 1 << letter;
+                    capped[letter] = true;
+                }
+            }
+        }
+        for letter in 0..26 {
+            self.min[letter] = self.min[letter].max(found[letter]);
+            if capped[letter] {
+                self.max[letter] = self.max[letter].min(found[letter]);
+            }
+        }
+    }
+
+    /// Whether `word` (uppercase) could still be the answer
+    pub fn matches(&self, word: &str) -> bool {
+        let bytes = word.as_bytes();
+        if bytes.len() != self.word_size {
+            return false;
+        }
+        let mut counts = [0_u8; 26];
+        for (i, &byte) in bytes.iter().enumerate() {
+            if !byte.is_ascii_uppercase() {
+                return false;
+            }
+            let letter = byte - b'A';
+            if let Some(fixed) = self.fixed[i] {
+                if fixed != letter {
+                    return false;
+                }
+            } else if self.excluded[i] & (1 << letter) != 0 {
+                return false;
+            }
+            counts[letter as usize] += 1;
+        }
+        counts
+            .iter()
+            .zip(self.min.iter().zip(self.max.iter()))
+            .all(|(count, (min, max))| count >= min && count <= max)
+    }
+
+    /// Words from `words` that could still be the answer, in the same order
+    pub fn filter<'a>(&self, words: &[&'a str]) -> Vec<&'a str> {
+        words
+            .iter()
+            .filter(|word| self.matches(word))
+            .copied()
+            .collect()
+    }
+}
+
+/// Every dictionary word of `word_size` that's consistent with all `rows`
+///
+/// Returns an empty list if `word_size` is unsupported
+pub fn candidates(word_size: usize, rows: &[Vec<LetterSlot>]) -> Vec<&'static str> {
+    guesses_for_size(word_size)
+        .map(|words| Constraints::from_rows(word_size, rows).filter(words))
+        .unwrap_or_default()
+}
+
+fn letter_idx(chr: char) -> Option<usize> {
+    chr.is_ascii_uppercase()
+        .then(|| chr as usize - 'A' as usize)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::score;
+    use crate::word_list::answers_for_size;
+
+    #[test]
+    fn no_rows() {
+        assert_eq!(candidates(7, &[]).len(), guesses_for_size(7).unwrap().len());
+        assert!(candidates(3, &[]).is_empty());
+    }
+
+    #[test]
+    fn duplicate_letters() {
+        //one E is yellow and the other grey so there must be exactly one E
+        let rows = vec![score("SPEED", "ABIDE")];
+        let words = candidates(5, &rows);
+        assert!(words.contains(&"ABIDE"));
+        assert!(words
+            .iter()
+            .all(|word| word.chars().filter(|chr| *chr == 'E').count() == 1));
+        assert!(!words.contains(&"ELDER"));
+
+        //both Os are found so there must be at least two
+        let rows = vec![score("OOZED", "GOOFY")];
+        let words = candidates(5, &rows);
+        assert!(words.contains(&"GOOFY"));
+        assert!(words
+            .iter()
+            .all(|word| word.chars().filter(|chr| *chr == 'O').count() >= 2));
+    }
+
+    #[test]
+    fn wrong_length_never_matches() {
+        let constraints = Constraints::new(5);
+        assert!(constraints.matches("CRANE"));
+        assert!(!constraints.matches("CRANES"));
+        assert!(!constraints.matches("crane"));
+    }
+
+    #[test]
+    fn same_as_scoring() {
+        let mut rng = Rng::new(1);
+        for size in [4, 5, 6] {
+            let answers = answers_for_size(size).unwrap();
+            let words = guesses_for_size(size).unwrap();
+            for _ in 0..10 {
+                let answer = answers[rng.below(answers.len())];
+                let guesses: Vec<&str> = (0..rng.below(3) + 1)
+                    .map(|_| words[rng.below(words.len())])
+                    .collect();
+                let rows: Vec<Vec<LetterSlot>> =
+                    guesses.iter().map(|guess| score(guess, answer)).collect();
+                let expected: Vec<&str> = words
+                    .iter()
+                    .filter(|word| {
+                        guesses
+                            .iter()
+                            .all(|guess| score(guess, word) == score(guess, answer))
+                    })
+                    .copied()
+                    .collect();
+                assert_eq!(candidates(size, &rows), expected, "{answer} {guesses:?}");
+            }
+        }
+    }
+}
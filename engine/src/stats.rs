@@ -0,0 +1,99 @@
+//! Results of finished games, for a word size and mode
+
+use crate::{EngineState, WordleEngine};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub played: usize,
+    pub wins: usize,
+    /// Games won in a row, up to the latest game
+    pub current_streak: usize,
+    pub max_streak: usize,
+    /// Number of wins for each number of guesses, the first is for wins in 1 guess
+    pub distribution: Vec<usize>,
+}
+
+impl Stats {
+    /// Adds a finished game, unfinished games are ignored
+    pub fn add_game(&mut self, engine: &WordleEngine) {
+        match engine.state {
+            EngineState::Guessing => {}
+            EngineState::Found => {
+                let guesses = engine.guesses.len();
+                self.played += 1;
+                self.wins += 1;
+                self.current_streak += 1;
+                self.max_streak = self.max_streak.max(self.current_streak);
+                if self.distribution.len() < guesses {
+                    self.distribution.resize(guesses, 0);
+                }
+                self.distribution[guesses - 1] += 1;
+            }
+            EngineState::OutOfGuesses => {
+                self.played += 1;
+                self.current_streak = 0;
+            }
+        }
+    }
+
+    /// Percentage of games won, rounded down
+    pub fn win_percent(&self) -> usize {
+        (self.wins * 100)
+            .checked_div(self.played)
+            .unwrap_or_default()
+    }
+
+    /// Number of wins with `guesses` guesses
+    pub fn wins_in(&self, guesses: usize) -> usize {
+        guesses
+            .checked_sub(1)
+            .and_then(|idx| self.distribution.get(idx))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::engine::played_game;
+    use crate::Difficulty;
+
+    #[test]
+    fn streaks_and_distribution() {
+        let mut stats = Stats::default();
+        assert_eq!(stats.win_percent(), 0);
+        stats.add_game(&played_game("GHOST", &["CRANE"], Difficulty::Normal));
+        assert_eq!(stats.played, 0);
+
+        stats.add_game(&played_game(
+            "GHOST",
+            &["CRANE", "GHOST"],
+            Difficulty::Normal,
+        ));
+        stats.add_game(&played_game("GHOST", &["GHOST"], Difficulty::Normal));
+        stats.add_game(&played_game(
+            "GHOST",
+            &["CRANE", "BOOST", "GHOST"],
+            Difficulty::Normal,
+        ));
+        assert_eq!(stats.current_streak, 3);
+        stats.add_game(&played_game("GHOST", &["CRANE"; 6], Difficulty::Normal));
+        stats.add_game(&played_game(
+            "GHOST",
+            &["BOOST", "GHOST"],
+            Difficulty::Normal,
+        ));
+
+        assert_eq!(stats.played, 5);
+        assert_eq!(stats.wins, 4);
+        assert_eq!(stats.win_percent(), 80);
+        assert_eq!(stats.current_streak, 1);
+        assert_eq!(stats.max_streak, 3);
+        assert_eq!(stats.distribution, vec![1, 2, 1]);
+        assert_eq!(stats.wins_in(2), 2);
+        assert_eq!(stats.wins_in(0), 0);
+        assert_eq!(stats.wins_in(6), 0);
+    }
+}
@@ -0,0 +1,188 @@
+//! Find as many words as possible before the clock runs out
+//!
+//! The clock doesn't measure time itself, the game adds the time played with [TimeAttack::tick]
+//! so it can be paused (e.g. while guesses are animated)
+
+use serde::{Deserialize, Serialize};
+
+/// Length of a time attack game, in seconds
+pub const TIME_LIMIT: f64 = 180.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeAttack {
+    limit: f64,
+    /// Seconds played so far
+    pub elapsed: f64,
+    /// Seconds spent on the current word
+    pub word_time: f64,
+    /// Number of words found
+    pub words: usize,
+    /// Seconds spent on the words that were found, time on lost words isn't included
+    pub found_time: f64,
+    /// Quickest word found, in seconds
+    pub fastest: Option<f64>,
+}
+
+impl TimeAttack {
+    /// Start a clock that runs out after `limit` seconds
+    pub fn new(limit: f64) -> Self {
+        TimeAttack {
+            limit,
+            elapsed: 0.0,
+            word_time: 0.0,
+            words: 0,
+            found_time: 0.0,
+            fastest: None,
+        }
+    }
+
+    /// Seconds left, never negative
+    pub fn remaining(&self) -> f64 {
+        (self.limit - self.elapsed).max(0.0)
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.elapsed >= self.limit
+    }
+
+    /// Add `secs` of play, returns true if this ran the clock out
+    ///
+    /// Does nothing once the clock has run out
+    pub fn tick(&mut self, secs: f64) -> bool {
+        if self.is_over() {
+            return false;
+        }
+        self.elapsed += secs;
+        self.word_time += secs;
+        self.is_over()
+    }
+
+    /// The current word was found, the next word starts timing from zero
+    pub fn word_found(&mut self) {
+        self.words += 1;
+        self.found_time += self.word_time;
+        self.fastest = Some(
+            self.fastest
+                .map(|fastest| fastest.min(self.word_time))
+                .unwrap_or(self.word_time),
+        );
+        self.word_time = 0.0;
+    }
+
+    /// The current word was lost, the next word starts timing from zero
+    pub fn word_lost(&mut self) {
+        self.word_time = 0.0;
+    }
+
+    /// Words found for each minute spent on them, so the time on lost words and the
+    /// unfinished word when the clock runs out don't count
+    pub fn words_per_minute(&self) -> f64 {
+        if self.found_time <= 0.0 {
+            0.0
+        } else {
+            self.words as f64 * 60.0 / self.found_time
+        }
+    }
+}
+
+/// Best results for a word size
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TimeAttackBest {
+    /// Most words found in one game
+    pub words: usize,
+    /// Best [TimeAttack::words_per_minute] of a finished game
+    pub words_per_minute: f64,
+    /// Quickest word found, in seconds
+    pub fastest: Option<f64>,
+}
+
+impl TimeAttackBest {
+    /// Keeps anything `clock` beat, returns true if something changed
+    ///
+    /// The quickest word counts straight away, the number of words only once the clock has run out
+    pub fn update(&mut self, clock: &TimeAttack) -> bool {
+        let mut changed = false;
+        if let Some(fastest) = clock.fastest {
+            if self.fastest.map(|best| fastest < best).unwrap_or(true) {
+                self.fastest = Some(fastest);
+                changed = true;
+            }
+        }
+        if clock.is_over() {
+            if clock.words > self.words {
+                self.words = clock.words;
+                changed = true;
+            }
+            if clock.words_per_minute() > self.words_per_minute {
+                self.words_per_minute = clock.words_per_minute();
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn clock_runs_out() {
+        let mut clock = TimeAttack::new(10.0);
+        assert!(!clock.tick(4.0));
+        clock.word_found();
+        assert!(!clock.tick(2.0));
+        clock.word_lost();
+        assert!(!clock.tick(3.0));
+        clock.word_found();
+        assert_eq!(clock.words, 2);
+        assert_eq!(clock.fastest, Some(3.0));
+        //the 2 seconds on the lost word don't count
+        assert_eq!(clock.found_time, 7.0);
+        assert_eq!(clock.words_per_minute(), 2.0 * 60.0 / 7.0);
+        assert_eq!(clock.remaining(), 1.0);
+        assert!(clock.tick(5.0));
+        assert!(clock.is_over());
+        assert_eq!(clock.remaining(), 0.0);
+        //already over
+        assert!(!clock.tick(1.0));
+        assert_eq!(clock.elapsed, 14.0);
+    }
+
+    #[test]
+    fn best_results() {
+        let mut best = TimeAttackBest::default();
+        let mut clock = TimeAttack::new(60.0);
+        clock.tick(20.0);
+        clock.word_found();
+        assert!(best.update(&clock));
+        assert_eq!(best.fastest, Some(20.0));
+        //the game isn't over so the words don't count yet
+        assert_eq!(best.words, 0);
+        assert!(!best.update(&clock));
+
+        //the unfinished word when the clock runs out doesn't lower the rate
+        clock.tick(40.0);
+        assert!(best.update(&clock));
+        assert_eq!(best.words, 1);
+        assert_eq!(best.words_per_minute, 3.0);
+
+        let mut slower = TimeAttack::new(60.0);
+        slower.tick(30.0);
+        slower.word_found();
+        slower.tick(30.0);
+        assert!(!best.update(&slower));
+        assert_eq!(best.fastest, Some(20.0));
+        assert_eq!(best.words_per_minute, 3.0);
+
+        let mut quicker = TimeAttack::new(60.0);
+        for _ in 0..2 {
+            quicker.tick(10.0);
+            quicker.word_found();
+        }
+        quicker.tick(40.0);
+        assert!(best.update(&quicker));
+        assert_eq!(best.words, 2);
+        assert_eq!(best.words_per_minute, 6.0);
+    }
+}
@@ -0,0 +1,104 @@
+//! Two players taking turns to pick a word for the other to guess
+//!
+//! Scored like golf, each player's score is the number of guesses they've needed so far
+//! and the lowest score wins
+
+use crate::{EngineState, WordleEngine};
+
+/// Scores for a match, player one picks the first word
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Versus {
+    /// Total guesses needed by player one and player two
+    pub scores: [usize; 2],
+    /// Number of rounds played
+    pub rounds: usize,
+}
+
+impl Versus {
+    /// Player picking the word this round, 0 for player one or 1 for player two
+    pub fn setter(&self) -> usize {
+        self.rounds % 2
+    }
+
+    /// Player guessing the word this round
+    pub fn guesser(&self) -> usize {
+        1 - self.setter()
+    }
+
+    /// Adds a finished game to the guesser's score and moves on to the next round
+    ///
+    /// Returns the score for the round (see [round_score]), or `None` if the game isn't finished
+    pub fn add_round(&mut self, engine: &WordleEngine) -> Option<usize> {
+        if engine.state == EngineState::Guessing {
+            return None;
+        }
+        let score = round_score(engine);
+        self.scores[self.guesser()] += score;
+        self.rounds += 1;
+        Some(score)
+    }
+
+    /// Player with the lowest score, only once both players have guessed the same number of words
+    ///
+    /// `None` if the scores are tied or a pair of rounds is half played
+    pub fn leader(&self) -> Option<usize> {
+        if self.rounds == 0 || self.setter() != 0 || self.scores[0] == self.scores[1] {
+            None
+        } else if self.scores[0] < self.scores[1] {
+            Some(0)
+        } else {
+            Some(1)
+        }
+    }
+}
+
+/// Number of guesses needed to find the word, a missed word counts as one more than the limit
+pub fn round_score(engine: &WordleEngine) -> usize {
+    if engine.state == EngineState::Found {
+        engine.guesses.len()
+    } else {
+        engine.max_guess_count + 1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::engine::played_game;
+    use crate::Difficulty;
+
+    #[test]
+    fn players_take_turns() {
+        let mut versus = Versus::default();
+        assert_eq!((versus.setter(), versus.guesser()), (0, 1));
+        assert_eq!(
+            versus.add_round(&played_game("GHOST", &["CRANE"], Difficulty::Normal)),
+            None
+        );
+        assert_eq!(versus.rounds, 0);
+
+        assert_eq!(
+            versus.add_round(&played_game(
+                "GHOST",
+                &["CRANE", "GHOST"],
+                Difficulty::Normal
+            )),
+            Some(2)
+        );
+        assert_eq!((versus.setter(), versus.guesser()), (1, 0));
+        assert_eq!(versus.leader(), None);
+
+        let missed = played_game("PIANO", &["CRANE"; 6], Difficulty::Normal);
+        assert_eq!(versus.add_round(&missed), Some(7));
+        assert_eq!(versus.scores, [7, 2]);
+        assert_eq!(versus.leader(), Some(1));
+
+        versus.add_round(&played_game("CHIMP", &["CHIMP"], Difficulty::Normal));
+        assert_eq!(versus.scores, [7, 3]);
+        //player one hasn't had their turn yet
+        assert_eq!(versus.leader(), None);
+        versus.add_round(&played_game("BASIL", &["BASIL"], Difficulty::Normal));
+        assert_eq!(versus.scores, [8, 3]);
+        assert_eq!(versus.leader(), Some(1));
+    }
+}
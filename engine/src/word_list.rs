@@ -1,14 +1,663 @@
-pub fn word_count_for_size(size: usize) -> usize {
+use std::ops::RangeInclusive;
+
+/// Supported word sizes
+pub const WORD_SIZES: RangeInclusive<usize> = 4..=7;
+
+/// Words that can be picked as the answer, or None if `size` isn't supported
+pub fn answers_for_size(size: usize) -> Option<&'static [&'static str]> {
     match size {
-        4 => FOUR.len(),
-        5 => FIVE.len(),
-        6 => SIX.len(),
-        7 => SEVEN.len(),
-        _ => panic!("invalid word size for count: {size}"),
+        4 => Some(&FOUR_ANSWERS),
+        5 => Some(&FIVE_ANSWERS),
+        6 => Some(&SIX_ANSWERS),
+        7 => Some(&SEVEN_ANSWERS),
+        _ => None,
     }
 }
 
-pub const FOUR: [&str; 2096] = [
+/// Words that are accepted as guesses, includes all answers, or None if `size` isn't supported
+///
+/// Each list starts with the original dictionary in its original order, early versions
+/// saved how far through it the player was. New words are only added to the end
+pub fn guesses_for_size(size: usize) -> Option<&'static [&'static str]> {
+    match size {
+        4 => Some(&FOUR_GUESSES),
+        5 => Some(&FIVE_GUESSES),
+        6 => Some(&SIX_GUESSES),
+        7 => Some(&SEVEN_GUESSES),
+        _ => None,
+    }
+}
+
+/// Number of answers for `size`, 0 if `size` isn't supported
+pub fn word_count_for_size(size: usize) -> usize {
+    answers_for_size(size)
+        .map(|list| list.len())
+        .unwrap_or_default()
+}
+
+/// Returns true if `word` (uppercase) is accepted as a guess for words of `size` letters
+pub fn is_word(size: usize, word: &str) -> bool {
+    guesses_for_size(size)
+        .map(|list| list.contains(&word))
+        .unwrap_or_default()
+}
+
+static FOUR_ANSWERS: [&str; 1623] = [
+    "DELL", "ZEAL", "LIEN", "ROVE", "BUNT", "YACK", "GILT", "DIRT", "GENE", "YEAR", "HEAR", "DOCK",
+    "HACK", "COVE", "FANG", "HECK", "SKEW", "MAYO", "BREW", "VISE", "PUNY", "KELP", "HIDE", "MIST",
+    "DOJO", "BARB", "GAWK", "SKIT", "COWL", "LEAK", "ROOM", "JAMB", "RAGE", "BOON", "GORY", "LILT",
+    "AQUA", "HELP", "REAL", "HELM", "HUNT", "EXIT", "GUST", "DONE", "RUMP", "TREK", "AFAR", "SOUP",
+    "QUAD", "WEST", "RIFF", "GAGA", "KIWI", "BONY", "WHAM", "BEAM", "BRAG", "BEND", "PURL", "ARID",
+    "DEFT", "SPAN", "PUMA", "HEAP", "KITE", "BOLO", "GORE", "LURE", "MONK", "BLED", "JOKE", "TEMP",
+    "MOLD", "UPON", "HEIR", "GNAT", "MUSK", "RODE", "ELSE", "SLID", "WEEK", "MINI", "DEEM", "DOSE",
+    "OUST", "COLD", "PINT", "LEVY", "GEEK", "TIDY", "EDDY", "LOPE", "SUCH", "WEAK", "SORE", "BURY",
+    "MEAD", "TUTU", "BOUT", "GOLD", "TUBA", "POLE", "FORM", "CASK", "RINK", "CHEW", "BIDE", "SCUD",
+    "HOUR", "BOOT", "SHOE", "JAIL", "HUSK", "THEY", "RICE", "JERK", "WIRE", "CLAN", "PIKE", "YEAH",
+    "HEED", "BENT", "AWAY", "SHIV", "LOGO", "REED", "DOZE", "BALL", "VARY", "MOAN", "BAIT", "ZOOM",
+    "CONE", "COAX", "NOUN", "PONY", "AMID", "RUBY", "KNEE", "CREW", "RAZZ", "PEER", "SPAT", "WATT",
+    "RAID", "PUKE", "PEAT", "FUME", "HARK", "CARD", "GRIN", "MOPE", "MASS", "ISLE", "USER", "DUCT",
+    "ALOE", "LOFT", "HOPE", "CEDE", "MELT", "ERGO", "TOLD", "UGLY", "MAIL", "HULA", "CITY", "BURP",
+    "WADE", "ACRE", "TURN", "AMMO", "BIRD", "GULP", "YARN", "RAZE", "SLIM", "PANE", "SKID", "BADE",
+    "PIPE", "DODO", "TOFU", "NUDE", "HOME", "KILT", "LIST", "SEAL", "HULK", "LUBE", "TASK", "GLUT",
+    "TOMB", "MOWN", "FEST", "LIFT", "SECT", "BELT", "AVID", "EACH", "HOLY", "CALF", "MUFF", "STEP",
+    "BOTH", "ZINC", "WOMB", "COOP", "LIVE", "PULP", "DIVA", "DISK", "ACHY", "TYPO", "FOOL", "FEAR",
+    "GOON", "BERM", "DRUM", "FIRM", "FERN", "SHED", "YOGA", "TOUT", "MATH", "SOIL", "HINT", "POLL",
+    "URGE", "DAMP", "FAIL", "WARP", "CROP", "OVER", "SNIP", "SPUD", "GARB", "LENT", "DICE", "PUNT",
+    "LUMP", "FEET", "BANG", "DUMP", "MAIM", "SWAP", "FAWN", "FREE", "VETO", "GLAM", "MOON", "CURL",
+    "ZEST", "DEAF", "PALL", "WAND", "LONG", "MITT", "HASH", "TECH", "SOLE", "WARE", "YORE", "CAMP",
+    "WORK", "OVAL", "MENU", "PACT", "NOSH", "ROAM", "RASP", "SOLO", "ASHY", "PAIR", "DUCK", "SLIT",
+    "BEEP", "TEAM", "WISP", "LODE", "OKAY", "DUNE", "RIOT", "NOOK", "JAZZ", "HOLE", "GRAB", "SOUR",
+    "TACT", "PERM", "SNUB", "LOIN", "YANG", "PEND", "FOND", "POST", "BASS", "CURD", "SEED", "HALT",
+    "RIFE", "GASH", "THIN", "GIFT", "POET", "WINK", "OBOE", "LOCH", "BOAR", "TEND", "RICH", "WAGE",
+    "LOOM", "SUED", "BORN", "OXEN", "WEAR", "WART", "DILL", "PLUM", "SENT", "RUNT", "WAVE", "GOUT",
+    "BODY", "LOOP", "DUMB", "HOOT", "SMUG", "IDLE", "MUSE", "LEFT", "FARM", "PAGE", "USED", "HAIL",
+    "THEN", "JEST", "PACE", "POSH", "WILE", "STOP", "SOUL", "TEST", "NONE", "BURN", "MISS", "SEEN",
+    "BUSK", "RACY", "PATE", "SLOW", "SOFT", "RING", "PAIL", "PARK", "TRIO", "FLIP", "DING", "CRUX",
+    "DRIP", "TILE", "CLOT", "ROLL", "ROSY", "CURE", "FUEL", "AXLE", "OBEY", "MERE", "SMOG", "STUD",
+    "MART", "EDGY", "SOME", "REAP", "MUCH", "SULK", "MOLT", "MOJO", "CAFE", "DART", "ICKY", "HEAT",
+    "LYRE", "VEIN", "SAND", "CHAT", "BABE", "REAR", "DIAL", "FUZZ", "DOUR", "PIER", "PURE", "AREA",
+    "FLAX", "TIED", "BOLE", "MOSS", "HIRE", "WHEE", "NORM", "INCH", "HONE", "BASH", "BAND", "EDGE",
+    "FAST", "EASY", "MULL", "GUSH", "HARP", "WORD", "DUDE", "WOKE", "SASH", "LUSH", "HIKE", "STUB",
+    "ANTE", "PANG", "GROG", "TRAM", "WRIT", "DEAR", "WING", "VASE", "COPY", "BUSH", "FIRE", "WIRY",
+    "SIRE", "PROD", "FLAW", "HOSE", "EVIL", "RANT", "TIER", "IDOL", "SOAR", "LAID", "NEAT", "AMEN",
+    "CLAW", "ASAP", "RUST", "HUMP", "BANK", "LATE", "DOFF", "THAW", "DUNG", "NICK", "PORE", "NIGH",
+    "JEEP", "BOSS", "GOAD", "WASP", "POLY", "BILL", "SORT", "GAIN", "NEAR", "SOAP", "RAVE", "SUMO",
+    "PAPA", "ACHE", "GAZE", "BUCK", "TORE", "TAIL", "SPAR", "GOLF", "TAUT", "OWED", "DECK", "SICK",
+    "TENT", "OINK", "GIRL", "MEND", "EAST", "FOOD", "LASH", "PASS", "MESA", "GRIT", "SHUN", "STAR",
+    "MOTH", "HIVE", "STEM", "QUIT", "DASH", "LINT", "WELD", "GLEN", "AIRY", "LACK", "SIDE", "ALSO",
+    "FOUR", "KNIT", "CLAY", "SONG", "YULE", "CITE", "HEAL", "LEND", "WILL", "WAIL", "SPAM", "PLAN",
+    "LOUD", "LAND", "GOSH", "FILL", "TRAY", "THEM", "KILL", "YELP", "FALL", "STOW", "GANG", "TAME",
+    "LEAF", "DATE", "TUSK", "READ", "WOLF", "OPAL", "LAME", "COOK", "PACK", "VAMP", "RAIL", "ALUM",
+    "BLOB", "PAVE", "MAGI", "TAKE", "POLO", "ZING", "PLUG", "MICE", "CARP", "PAIN", "MANY", "LEER",
+    "BEEF", "COME", "INTO", "HATH", "LOSE", "EYED", "BOLD", "GROW", "ROMP", "BETA", "MACE", "TERM",
+    "PORT", "MONO", "ITCH", "PANT", "FAZE", "ROUT", "LOSS", "BEAN", "LIMO", "MAZE", "ZERO", "FLAP",
+    "SILO", "VAIN", "ONCE", "MOOR", "PEEK", "STIR", "TOOT", "HEEL", "NOSY", "SEEK", "FLUE", "DISH",
+    "HERO", "PART", "VALE", "IFFY", "TUBE", "SPUR", "PUTZ", "PULL", "DULY", "FLIT", "GLUE", "SIGN",
+    "MAID", "ARMY", "HANG", "MEAT", "LOOT", "CLUE", "HILT", "ONLY", "THAN", "HUSH", "JACK", "ANEW",
+    "SNOB", "PLED", "GUNK", "ROPE", "LESS", "OVEN", "FIEF", "RISK", "FOIL", "DRAB", "LIED", "AJAR",
+    "NEST", "BLOW", "DANK", "FIFE", "GRID", "RAKE", "RIDE", "HARM", "RUNE", "SIZE", "TONE", "ACID",
+    "CRAM", "HUNG", "YANK", "ORCA", "WHEY", "CULL", "ALTO", "JADE", "WAFT", "REEK", "SHIP", "TOSS",
+    "CASE", "SALE", "LUNG", "DARK", "VANE", "LUST", "CLEF", "MORE", "DISC", "RUIN", "CRAG", "GREW",
+    "ROOK", "MALE", "COOL", "GLUM", "POND", "ACED", "CANT", "WAIF", "POKE", "BOLT", "ATOM", "PILL",
+    "RULE", "TRAP", "TEAR", "DELI", "GAPE", "BLUE", "SWAB", "ROAR", "POUR", "SINK", "SWAT", "ROCK",
+    "RITZ", "NUKE", "IDEA", "OGRE", "FOAL", "LIME", "VENT", "CASH", "RIPE", "GULF", "SAME", "MITE",
+    "TWIT", "VAST", "DAZE", "POSE", "FAKE", "TOON", "FIST", "LICE", "BODE", "PLOD", "SHAM", "FAME",
+    "TALC", "PLEA", "LURK", "SWIG", "CHUG", "BRAY", "SUNG", "PICK", "LASS", "GONE", "GALE", "DOOM",
+    "LUAU", "HOOK", "MILL", "WELT", "WHEN", "YOLK", "BLOT", "BEAR", "BROW", "CODE", "FEEL", "HERD",
+    "HAND", "PYRE", "CLOD", "RENT", "FOLK", "BEET", "WIND", "AWRY", "PATH", "PREP", "DINE", "LACE",
+    "VERB", "FILM", "CHIN", "REAM", "WILY", "TAXI", "WORM", "BOIL", "GIVE", "UNTO", "MAKE", "HOOF",
+    "DAFT", "TRUE", "PREY", "BAKE", "TEAT", "CRUD", "HORN", "FLOP", "DYED", "SOFA", "NOPE", "WENT",
+    "DEBT", "CLIP", "FARE", "FIZZ", "SUSS", "SHOO", "SAVE", "TOOK", "GILD", "SOOT", "BARD", "FULL",
+    "MYTH", "TROD", "FUND", "KNOW", "EXAM", "BASE", "POUT", "CHIC", "TAPE", "EVER", "POPE", "YELL",
+    "AKIN", "SEND", "DINT", "LAMP", "CUBE", "UNDO", "MUSH", "GASP", "PITY", "ROOF", "JOIN", "POOL",
+    "NARY", "BELL", "WANT", "OILY", "NEXT", "CUSS", "TWIG", "DUNK", "WARM", "CARB", "POSY", "DAWN",
+    "RASH", "LONE", "TOLL", "TWIN", "FLEE", "BECK", "FLAY", "PUPA", "ZONE", "AIDE", "GERM", "HISS",
+    "PAWN", "WHAT", "JUST", "GRIM", "SEEM", "VOLT", "FAVE", "TEXT", "TUNA", "DUET", "SLOT", "TREE",
+    "BRIE", "HEAD", "LARD", "DOLL", "TART", "THUD", "GAFF", "PEAL", "HOOP", "LOVE", "SOCK", "LICK",
+    "LAIR", "NOIR", "NECK", "TILT", "GAVE", "JUKE", "PUCE", "PRAY", "SLAY", "BONE", "SACK", "CRAB",
+    "PEEL", "VICE", "HOVE", "SAGA", "YOKE", "PEAK", "HUFF", "PITH", "SLUG", "WARD", "SPRY", "ROAD",
+    "VERY", "LEAN", "FLEW", "EXPO", "RISE", "DUPE", "CHAP", "CRAW", "GAME", "NICE", "RAIN", "RELY",
+    "SITE", "NOSE", "BLAH", "FLAG", "DENT", "CARE", "CORE", "COIL", "WARY", "BIKE", "MIND", "BLOC",
+    "DEFY", "COPE", "SKIM", "SOON", "DIME", "FOOT", "FUSE", "WITH", "TRIP", "OMEN", "NAME", "DARE",
+    "SOWN", "GOAT", "CHUM", "WALK", "HOAX", "SNIT", "GEAR", "LISP", "MANE", "ROOT", "TANG", "CAME",
+    "BOOM", "BITE", "ABET", "PAID", "WOVE", "POMP", "SAKE", "COAL", "GIBE", "BILK", "DORM", "BULK",
+    "RUSH", "RACK", "SWAG", "AURA", "LINK", "BEAD", "REIN", "CUED", "ONYX", "JULY", "HEMP", "MINE",
+    "RACE", "SPED", "DRAW", "FIAT", "MOOT", "PEEN", "STAB", "DIRE", "BARE", "GULL", "LUTE", "SWIM",
+    "WHOM", "CAVE", "LARK", "LION", "WAVY", "ABLY", "FLAK", "COIN", "HAVE", "BLAB", "DULL", "HARD",
+    "MOVE", "OMIT", "GYRO", "ROIL", "MINK", "BUZZ", "SUMP", "SNUG", "LUXE", "SEEP", "TOED", "DIET",
+    "SWAN", "CLUB", "TOOL", "YARD", "JUMP", "AGOG", "MESH", "CLAM", "GLIB", "GIRD", "HYPE", "WALL",
+    "SILT", "TICK", "DOOR", "SELL", "COLA", "CHEF", "RILE", "LAZY", "NEWT", "MUTT", "FROM", "DIED",
+    "OOZE", "FINE", "KEEP", "HAZE", "OATH", "PUNK", "BRIM", "HATE", "BRED", "BRAN", "LINE", "DUSK",
+    "PERT", "LIEU", "TOME", "LIKE", "RITE", "CULT", "TIDE", "YETI", "PERK", "MOAT", "SCAM", "JIVE",
+    "SLEW", "CHAR", "LOAD", "PROP", "CANE", "EARN", "LADY", "TACO", "CYST", "BARN", "BASK", "CHOP",
+    "FATE", "HOST", "LOAM", "MEAL", "WICK", "BRIG", "JUNK", "COZY", "PELT", "AMOK", "SANG", "DATA",
+    "RUBE", "GRIP", "WORN", "OKRA", "SPIN", "PUFF", "ICED", "TALL", "REEL", "WISH", "TWEE", "ARCH",
+    "DEAL", "PINE", "WREN", "TOIL", "FOUL", "GONG", "LIMB", "DISS", "AHOY", "LIAR", "PUSS", "HONK",
+    "SANE", "DEAN", "JINX", "DUKE", "KICK", "ATOP", "AXEL", "TACK", "WERE", "WARN", "GOTH", "BUFF",
+    "QUIZ", "THUG", "RARE", "SKIP", "MEAN", "LIFE", "TARP", "WHOA", "BURR", "FORD", "DEED", "GAIT",
+    "SEAT", "YUCK", "MULE", "FLOG", "DEAD", "RATE", "PING", "CART", "FLEA", "BOMB", "HILL", "CORD",
+    "BLEW", "LOUT", "LOAN", "UNIT", "SLOG", "MIME", "TOGA", "WANE", "LAUD", "FADE", "VEER", "DOME",
+    "DUFF", "SPUN", "PENT", "SCAT", "KEEN", "DROP", "FLAN", "IDLY", "FEAT", "WHET", "TIFF", "LOST",
+    "SALT", "KIND", "SHIN", "HULL", "LEEK", "HALL", "GNAW", "MALL", "CALL", "KING", "FETE", "REEF",
+    "LANE", "MODE", "HART", "DANG", "KISS", "CHIP", "TEEM", "QUIP", "VIAL", "BALE", "BARK", "GATE",
+    "KILN", "CUTE", "HUGE", "DENY", "YURT", "PEON", "BEAK", "LULL", "BEAT", "PONG", "DRAT", "BOND",
+    "TANK", "CROC", "ARTY", "BABY", "WHIZ", "GOOP", "LAWN", "CLAP", "FORT", "HALO", "BORE", "HALF",
+    "DIVE", "VILE", "TEAL", "WELL", "MAIN", "KEPT", "EDIT", "NOTE", "SWAM", "ONTO", "BOOP", "JUNE",
+    "FORE", "WASH", "BOZO", "MEEK", "SIGH", "SATE", "SANK", "LOOK", "SHOT", "COLT", "SHAG", "FISH",
+    "TOAD", "NULL", "MAST", "MOCK", "JILT", "CALM", "FACE", "RAMP", "JOCK", "MASK", "RANK", "TUNE",
+    "TROT", "POOF", "SNOT", "CZAR", "SLOP", "PUMP", "BALM", "TARE", "SODA", "SCAB", "BUST", "COMA",
+    "FELL", "SELF", "SNOW", "KEEL", "KNEW", "TYPE", "VEST", "TILL", "TORT", "CAKE", "PLOP", "ITEM",
+    "CUFF", "LORD", "THEE", "PLAY", "ROBE", "SLUM", "BUSY", "DALE", "FEND", "SEWN", "CELL", "OBIT",
+    "FONT", "FLOW", "SCAN", "LAST", "HAUL", "VEND", "ALLY", "HAZY", "FUSS", "LILY", "PECK", "GIST",
+    "GOER", "SPEW", "BALD", "MINT", "SING", "BUMP", "GOWN", "FILE", "FORK", "WOOF", "YOWL", "RICK",
+    "FLEX", "SERF", "VOTE", "GLEE", "ABLE", "LIMP", "SLUR", "FOLD", "TIME", "TOTE", "SLAP", "TRIM",
+    "PEST", "HELD", "SAID", "WILT", "SILK", "SCUM", "IRON", "COUP", "DOLE", "SKIN", "NEED", "SHUT",
+    "NOON", "NAAN", "THAT", "MALT", "JOLT", "NINE", "DARN", "REND", "TEAK", "SLAM", "CENT", "DEEP",
+    "SHIM", "CLOG", "BANE", "DOPE", "BEER", "PLOT", "RUFF", "BEAU", "SAFE", "LOBE", "DEER", "DUST",
+    "NUMB", "MADE", "GOOF", "SUNK", "JIBE", "CROW", "TALK", "FURL", "LEAP", "FOWL", "THOU", "QUAY",
+    "LOAF", "LUGE", "REST", "PILE", "LEST", "PEEP", "SAGE", "PLOY", "FUNK", "VISA", "FRET", "VIEW",
+    "BUNK", "SPOT", "OUCH", "WIFE", "ODOR", "NOVA", "WILD", "LYNX", "BACK", "FEED", "GOOD", "DOTH",
+    "EASE", "JOEY", "FRAY", "SINE", "PORK", "FRAG", "HERB", "EARL", "ABUT", "LORE", "AUTO", "GALA",
+    "YAWN", "SASS", "HARE", "PUTT", "MUTE", "LAIN", "MIRE", "SAIL", "WAKE", "RAFT", "SWAY", "WIPE",
+    "IOTA", "FOAM", "EVEN", "DOWN", "OGLE", "KINK", "FELT", "POEM", "HIGH", "VIBE", "ACNE", "MARK",
+    "MOOD", "PUCK", "GRAM", "MOST", "FAIR", "TORN", "WHIM", "PLOW", "WINE", "FLUX", "WIMP", "COOT",
+    "RUSE", "RAPT", "COAT", "BILE", "DRAG", "FURY", "WORE", "PALE", "SNAG", "DOVE", "CAGE", "BLIP",
+    "VINE", "MASH", "TIRE", "STUN", "NAVY", "WEAN", "TEEN", "VOID", "JURY", "TALE", "FIVE", "BEST",
+    "HUNK", "KALE", "METH", "SHOP", "PUSH", "PEAR", "TINT", "LACY", "CURB", "SPIT", "SURF", "CORK",
+    "HURT", "TUFT", "GURU", "RANG", "CAPE", "COST", "AUNT", "SLOB", "CLAD", "JEER", "BLUR", "LOON",
+    "WEEP", "HEFT", "DRUG", "KNOB", "FIND", "EPIC", "WAIT", "PRIM", "WEED", "RUNG", "ACME", "TELL",
+    "NERD", "PALM", "ETCH", "MUCK", "NAIL", "LAMB", "ANTI", "BALK", "STYE", "WEPT", "MACH", "BYTE",
+    "WOOL", "ICON", "FACT", "MATE", "FEUD", "JUJU", "TINY", "BOAT", "BUOY", "WHIP", "DUAL", "AFRO",
+    "HAIR", "STAT", "REDO", "GLAD", "SIFT", "RIND", "SNAP", "BOWL", "BOOK", "BRAT", "COKE", "WIDE",
+    "LAZE", "CLOP", "CORN", "WISE", "HICK", "POOR", "HOBO", "PAST", "MILD", "AGED", "ROSE", "CURT",
+    "BULB", "SUIT", "ENVY", "GRAY", "FAUX", "BATH", "STAG", "FLAT", "COMB", "ROLE", "DESK", "ZANY",
+    "LOCK", "NEON", "HERE", "DEMO", "KNOT", "STEW", "VEIL", "MAGE", "TURF", "WOOD", "DREW", "SURE",
+    "PROM", "DUTY", "CRIB", "MOLE", "BULL", "BIND", "APEX", "ORAL", "PINK", "GILL", "NODE", "PARE",
+    "SOLD", "JOWL", "DAME", "JUDO", "WAXY", "HIND", "PURR", "MAUL", "GRUB", "FROG", "BAIL", "MUST",
+    "SLED", "SEAR", "TUCK", "HOLD", "SCAR", "LUCK", "RIFT", "VEAL", "SEAM", "MEMO", "MILK", "MESS",
+    "SPEC", "DUEL", "TONG", "WRAP", "FLED", "OPEN", "HURL", "LEAD", "YOUR", "HOOD", "CAST", "TOWN",
+    "MARE", "TYKE", "AVOW", "SLAT", "SLIP", "SOAK", "MILE", "GOAL", "MOSH", "RUDE", "HOWL", "DOLT",
+    "MEOW", "NAPE", "EMIT", "HYMN", "LAKE", "STAY", "SLAB", "CUSP", "GALL", "SILL", "GLOW", "SEER",
+    "ECHO", "SHOW", "LAVA",
+];
+
+static FIVE_ANSWERS: [&str; 2312] = [
+    "RASPY", "JELLY", "CRIMP", "SPOKE", "LOCUS", "BASIL", "APART", "SCALD", "PAGAN", "FINCH",
+    "MONEY", "TAPIR", "PENAL", "AGATE", "EVERY", "BUNCH", "BOXER", "MOIST", "RAYON", "FAIRY",
+    "SPELL", "COURT", "HUNKY", "MANGA", "HALVE", "WORSE", "SMACK", "UNCUT", "AGILE", "BREAD",
+    "SLOPE", "PROXY", "STEER", "FRITZ", "KNOCK", "ZONAL", "PERKY", "BEVEL", "AUDIT", "NAIVE",
+    "FILLY", "DISCO", "INTER", "RISER", "ALARM", "ANTIC", "FIEND", "PETAL", "CACAO", "YOUNG",
+    "VOMIT", "WOODY", "BOOZE", "MEDIA", "BELLY", "VOILA", "CRANE", "BARON", "CROUP", "NASAL",
+    "FLUNG", "JUROR", "UMBRA", "ETHER", "RESIN", "DAILY", "SPREE", "MUSKY", "CUTIE", "REHAB",
+    "FILET", "ARBOR", "FENCE", "SLINK", "EARTH", "WISER", "CHURN", "PRICK", "IRONY", "ABOVE",
+    "SWILL", "VALUE", "EYING", "SPENT", "DRUNK", "WAIST", "BRUTE", "TIGER", "LIKEN", "ESTER",
+    "TENSE", "SPIKY", "TONGA", "VAULT", "MAPLE", "CREST", "ANGST", "QUEUE", "WRYLY", "PHONE",
+    "BREED", "MINUS", "DUMPY", "DUCHY", "GROWN", "OWNER", "FIZZY", "HEADY", "NURSE", "SMELL",
+    "PARRY", "MOLDY", "UNFED", "GENRE", "DANCE", "DRAIN", "APTLY", "WOOZY", "TRUNK", "FLAIL",
+    "SHIRK", "PUPIL", "SONIC", "PRIMO", "CABIN", "SLUNG", "ASKEW", "RUMBA", "SOWER", "ABIDE",
+    "ODDER", "DEFER", "EATEN", "PERCH", "STRIP", "BLURB", "SHELF", "SNUFF", "BADLY", "HAVOC",
+    "OUGHT", "FORTE", "SUGAR", "NINNY", "BRUSH", "BEZEL", "EAGER", "CLING", "EMBER", "PRIDE",
+    "FROCK", "PURSE", "PINTO", "SONAR", "SCALP", "OVATE", "AFIRE", "SLASH", "UNLIT", "EVOKE",
+    "STILT", "BUNNY", "CRONY", "FAUNA", "PIQUE", "ALIVE", "GNOME", "PARTY", "PRONE", "CHIDE",
+    "RALPH", "BEAST", "AWARE", "WIMPY", "THREE", "GIVEN", "SHOWN", "ANNUL", "TIPSY", "MOTOR",
+    "HYPER", "ASCOT", "JOUST", "PULPY", "SOOTY", "PASTA", "MIMIC", "TAMER", "TRYST", "BONEY",
+    "PAINT", "GRIPE", "OPINE", "MOUSE", "HAVEN", "CREAM", "TITLE", "HOARD", "FREED", "START",
+    "BARGE", "TREAD", "SLUSH", "ARGUE", "CATCH", "STOCK", "QUICK", "GIPSY", "GUSTO", "ENTRY",
+    "ICILY", "WHERE", "SYNOD", "DIODE", "ESSAY", "LEAFY", "CHILI", "IDYLL", "VALID", "SHINY",
+    "KNEEL", "FERRY", "GLOBE", "BLOAT", "GAFFE", "WEARY", "EQUAL", "SAVVY", "QUITE", "LOWLY",
+    "SLING", "GOLLY", "SMILE", "MONTH", "CURVY", "KRILL", "POSER", "INLET", "AMONG", "RATIO",
+    "PIVOT", "FRILL", "DEIGN", "SIEGE", "PHOTO", "PLUSH", "SNUCK", "CHILD", "THEME", "SPOOF",
+    "HOWDY", "FUNNY", "LATTE", "GUMBO", "FLOCK", "JERKY", "GAUDY", "DINER", "CURIO", "AFOOT",
+    "CHEAT", "DROVE", "FLUTE", "CURLY", "GRIND", "STEEL", "PESTO", "TRAIL", "EVICT", "DOWDY",
+    "LOAMY", "ROBOT", "AGLOW", "HITCH", "VIGOR", "ROVER", "WHACK", "STANK", "STAID", "NOTCH",
+    "WAXEN", "GORGE", "MANLY", "EQUIP", "TORSO", "LEASH", "DALLY", "CAIRN", "CIRCA", "BUTCH",
+    "ENEMY", "ATTIC", "LUNAR", "THROB", "SUPER", "GROIN", "USHER", "PALER", "SAUTE", "DADDY",
+    "FORGE", "TWINE", "SIXTH", "ERODE", "MADAM", "SHAPE", "SLIME", "DRAMA", "MODEL", "BLOCK",
+    "MOSSY", "HARSH", "RENAL", "AWFUL", "CRUEL", "LEERY", "THYME", "SEPIA", "PESKY", "BLUFF",
+    "VOGUE", "PLUME", "CLUNG", "FERAL", "CANAL", "JUMBO", "BASTE", "SHARE", "ARTSY", "FROWN",
+    "STARK", "STOUT", "TRAIT", "BOAST", "COPSE", "SCOOP", "CLEFT", "AVERT", "GOLEM", "GRUFF",
+    "LINER", "BROTH", "KIOSK", "ARMOR", "ABODE", "FROZE", "BACON", "FIERY", "SAFER", "HORDE",
+    "SIREN", "HASTE", "TOWER", "BANAL", "ANGRY", "CHAIN", "MERGE", "INPUT", "SCRUM", "FLARE",
+    "BREAK", "VICAR", "SWEET", "LINEN", "SIGMA", "SPINE", "PINEY", "SWOOP", "BURST", "OLIVE",
+    "TAWNY", "OAKEN", "NAVAL", "AIDER", "OVOID", "HOIST", "PURER", "MOTIF", "GULCH", "LARGE",
+    "ROOMY", "SHEEN", "MERRY", "ABLED", "BRIAR", "RETRY", "QUIET", "TANGO", "FILTH", "GAZER",
+    "SAINT", "SWEPT", "EPOCH", "TITHE", "BLESS", "BLEAT", "STALK", "CIVIL", "PRIVY", "BLIMP",
+    "SHAKY", "PILOT", "USURP", "GRAVY", "SQUAD", "LOFTY", "RESET", "PRIED", "PARER", "WALTZ",
+    "GRANT", "SANER", "CINCH", "SLIDE", "POISE", "FUZZY", "TUBER", "ETUDE", "SMELT", "AGAPE",
+    "UNDID", "CLASP", "RETRO", "ROBIN", "EKING", "FLOUR", "LURID", "SWIFT", "STOVE", "SWUNG",
+    "LOOSE", "SOUND", "GRAVE", "WORRY", "FILER", "ENSUE", "NIGHT", "SHAVE", "YOUTH", "TYING",
+    "REFER", "SPLIT", "TOKEN", "EXTOL", "HOVER", "FRIED", "PLIED", "BROOD", "SLANG", "FRAME",
+    "PANSY", "GRAIN", "GRAND", "STAIR", "CLAMP", "FUSSY", "ISSUE", "TARDY", "GRAIL", "STAFF",
+    "LAGER", "ARENA", "WISPY", "BEGAT", "ELFIN", "CHIRP", "STALL", "WRONG", "CORAL", "PUDGY",
+    "TAUNT", "BRAKE", "SWARM", "MIDGE", "FISHY", "GAYLY", "SLUNK", "AMAZE", "PRISM", "BLUER",
+    "PIECE", "CABLE", "PIPER", "SHOCK", "ROUTE", "AGREE", "DINGY", "SWISH", "DODGY", "BRINY",
+    "DULLY", "SPASM", "CURRY", "FLAIR", "SHARD", "HYMEN", "FORUM", "FUROR", "THUMP", "GOOSE",
+    "TRUST", "CREDO", "LOYAL", "CROWD", "MUDDY", "ROYAL", "SPEND", "SOBER", "SCOLD", "ONION",
+    "HEAVY", "RIPER", "BLOKE", "CONCH", "MURAL", "THANK", "ADMIT", "BLISS", "CHUNK", "STRAW",
+    "IMBUE", "CREED", "ALLOY", "EASEL", "CHAOS", "TANGY", "DEATH", "GHOUL", "TIMER", "THIEF",
+    "CLEAR", "FRIAR", "AFTER", "SLEEP", "HOVEL", "RATTY", "BLOWN", "STINT", "WHINY", "STOIC",
+    "DITCH", "EMPTY", "ERASE", "ACTOR", "ILIAC", "HONOR", "WHARF", "STEED", "CARRY", "MISER",
+    "EGRET", "COBRA", "LAPEL", "LOATH", "STORM", "CHORE", "HORSE", "STASH", "EATER", "SUNNY",
+    "COUNT", "TIBIA", "UNCLE", "PLACE", "VALOR", "BLEND", "AXION", "WRIST", "HEIST", "AWOKE",
+    "DINGO", "CORNY", "SPORE", "DREAM", "ABBOT", "SKIER", "INDEX", "METAL", "NEEDY", "ETHOS",
+    "DEITY", "PROUD", "MASSE", "FORTH", "GASSY", "BRINE", "PREEN", "GLIDE", "HYENA", "GIVER",
+    "GROUP", "REMIT", "OVARY", "DRILL", "SINGE", "STEAL", "STEEP", "FLAME", "QUASH", "GOING",
+    "CRAVE", "FLESH", "CHEEK", "RUMOR", "HUMID", "BULKY", "VILLA", "TRICE", "GRIMY", "DIRTY",
+    "NOBLE", "BRISK", "STOKE", "SPANK", "SNAKY", "ABBEY", "WHELP", "GROVE", "GODLY", "CLOSE",
+    "GRILL", "FRAUD", "RABID", "MODEM", "IDIOM", "CLOAK", "FRAIL", "KITTY", "BICEP", "OVINE",
+    "GUIDE", "RELAX", "DELAY", "SPOUT", "SAPPY", "CRUDE", "DELTA", "CARVE", "GNASH", "FIRST",
+    "QUELL", "ALOOF", "MATCH", "PLUCK", "ADOBE", "COMFY", "SMOKY", "FJORD", "STOOL", "ERUPT",
+    "GLADE", "STORK", "SHEET", "DROSS", "LEARN", "CLOTH", "TABOO", "CROCK", "SENSE", "FIELD",
+    "FIFTY", "BLANK", "CUMIN", "GAMUT", "DRUID", "JOINT", "DEVIL", "LUNGE", "SCARE", "SHIRE",
+    "DAIRY", "GRIEF", "SWAMI", "ODDLY", "CEDAR", "BEACH", "BATON", "TAROT", "WHOOP", "SINCE",
+    "CRASS", "SPIEL", "AGAIN", "HOBBY", "SPRIG", "QUEEN", "NEVER", "RELIC", "ZESTY", "SILKY",
+    "FLUID", "DERBY", "HAUTE", "AMUSE", "BIGOT", "BRAIN", "UNDER", "GUEST", "ARRAY", "GUESS",
+    "STONY", "RISKY", "ASHEN", "SHACK", "ADAPT", "WORST", "BASIC", "DUTCH", "SOGGY", "VOWEL",
+    "PENNE", "HOUSE", "SHUSH", "ROGUE", "ARROW", "SUITE", "SEEDY", "RENEW", "COMET", "DRANK",
+    "PENCE", "OUTDO", "GRAZE", "FELLA", "DROWN", "GROPE", "ANIME", "LILAC", "ACRID", "FOIST",
+    "PLEAD", "CAULK", "ANKLE", "JUNTO", "FUNKY", "HOMER", "PINCH", "OWING", "BORNE", "STAND",
+    "ITCHY", "HUTCH", "SWORD", "NOISY", "SHOWY", "PLUNK", "VOCAL", "MANGY", "CELLO", "PLEAT",
+    "DONUT", "LUCKY", "BELIE", "LABOR", "MAUVE", "STATE", "BAGEL", "DEUCE", "TIDAL", "MUSIC",
+    "CHARM", "ASIDE", "UNIFY", "SPLAT", "TEASE", "CHEAP", "SPILT", "POSIT", "ABASE", "BRASH",
+    "PLANK", "BLITZ", "CRONE", "VAPOR", "SMIRK", "SLOOP", "TOOTH", "KNOLL", "APHID", "METER",
+    "UNZIP", "BAWDY", "RIGID", "ELATE", "RAINY", "BIDDY", "HANDY", "LEMON", "CRUST", "WELSH",
+    "WIELD", "SCENE", "STEAD", "TAKEN", "FAULT", "CRYPT", "STOOP", "GLARE", "FEMME", "QUAKE",
+    "UNITY", "CHINA", "SLYLY", "URINE", "MAGIC", "GROSS", "OVERT", "LADLE", "TRACE", "REBEL",
+    "AMISS", "TENOR", "SWEEP", "POUCH", "BOUND", "SKIRT", "SHOVE", "SLEPT", "PRICE", "DREAD",
+    "MINOR", "LYRIC", "STAGE", "FLACK", "SORRY", "CANOE", "GAUNT", "CONIC", "RURAL", "CHORD",
+    "MAYOR", "IMAGE", "TORCH", "STUFF", "GLEAN", "CRUMB", "HURRY", "BRACE", "BUTTE", "REPAY",
+    "RARER", "EARLY", "MUCKY", "BLINK", "ULTRA", "SCOUT", "BOWEL", "TACKY", "STAMP", "ERROR",
+    "COLOR", "ELIDE", "JUNTA", "FLAKE", "SEGUE", "RAJAH", "SUSHI", "SKIMP", "GRATE", "POSSE",
+    "FLUSH", "PRIZE", "INCUR", "DEBAR", "BUDGE", "FORCE", "WRUNG", "MOLAR", "CHEER", "AWAIT",
+    "CRIME", "BUGGY", "EDIFY", "CRAFT", "PAPAL", "SPADE", "CREME", "EBONY", "HILLY", "SATYR",
+    "ENTER", "PENNY", "LIMIT", "ARDOR", "SMASH", "TRAWL", "TWEAK", "WOMAN", "SNEER", "AWAKE",
+    "THERE", "GUISE", "SNEAK", "THICK", "PAUSE", "NIECE", "FLAKY", "MODAL", "BADGE", "SIGHT",
+    "WIDTH", "DEALT", "JUICE", "SALTY", "BRAWN", "LIGHT", "TURBO", "YIELD", "DOUGH", "TUNIC",
+    "PARSE", "MEDAL", "HAPPY", "RINSE", "VISOR", "IDEAL", "BEADY", "THREW", "JOIST", "MAJOR",
+    "HENCE", "PUTTY", "LOUSY", "SHALE", "MIGHT", "ALONE", "GLASS", "WHIRL", "SHORT", "HASTY",
+    "SAVOR", "DRAPE", "SLANT", "RAMEN", "TWANG", "VAPID", "PATCH", "MISSY", "OLDEN", "TEACH",
+    "SNAKE", "CRASH", "ALIBI", "COYLY", "LUPUS", "METRO", "MORPH", "GROWL", "CABBY", "CLOVE",
+    "CROAK", "ALLOT", "HAREM", "STIFF", "VIRAL", "PYGMY", "LOBBY", "COMIC", "ABYSS", "SCALY",
+    "FURRY", "STAVE", "CACTI", "MAXIM", "GULLY", "BOSOM", "CLOUT", "CABAL", "GAUZE", "DIVER",
+    "SULLY", "SPUNK", "DETOX", "DANDY", "GAILY", "NEIGH", "JIFFY", "FLING", "LIEGE", "VIVID",
+    "STEIN", "ARSON", "FETUS", "RUDDY", "EXALT", "ROUGE", "BIRTH", "VERGE", "SNACK", "AMASS",
+    "WITCH", "WHISK", "RADII", "SOUTH", "PORCH", "HUMPH", "REACT", "TALON", "TAKER", "GUILD",
+    "SPRAY", "LUNCH", "STUCK", "FRISK", "DOWRY", "HUMUS", "HOUND", "ALIKE", "MADLY", "MIRTH",
+    "DRIER", "CRACK", "CRICK", "TENTH", "CLANK", "NUDGE", "MELEE", "AUNTY", "TWIRL", "SNOOP",
+    "CRAMP", "PRONG", "FIFTH", "BROWN", "TEPEE", "FETID", "SOAPY", "AORTA", "LOUSE", "TIARA",
+    "OTHER", "WORDY", "DITTO", "ERECT", "MUMMY", "COWER", "SNARL", "MOVIE", "STEAK", "CAMEL",
+    "CHART", "PRANK", "THING", "SHRUG", "HYDRO", "BLADE", "DYING", "SKULK", "LUCID", "REPEL",
+    "DRIVE", "SCREW", "NOMAD", "SEVEN", "BITTY", "TRUTH", "BOARD", "UTILE", "POLKA", "FEIGN",
+    "APING", "MACAW", "LIBEL", "NINJA", "GREEN", "UNWED", "BRING", "GUARD", "CREAK", "MORAL",
+    "OFFER", "EMAIL", "CRESS", "LUMEN", "BLOND", "ORGAN", "SKIFF", "SCRAM", "COAST", "LEDGE",
+    "GUSTY", "TIGHT", "ENEMA", "TEARY", "THEFT", "MOWER", "OUNCE", "BUYER", "TEPID", "THUMB",
+    "CHECK", "DUVET", "HEATH", "JUMPY", "QUEER", "SCOWL", "YEAST", "PANIC", "LASSO", "NOBLY",
+    "PROOF", "MOVER", "VERVE", "MACRO", "SALSA", "PIGGY", "TRIPE", "ANNOY", "CIDER", "BOSSY",
+    "FROST", "RANDY", "CAGEY", "SNOWY", "DECRY", "FRANK", "CREPT", "READY", "HOTLY", "TITAN",
+    "HEDGE", "PUREE", "TRICK", "BULGE", "FLUFF", "CHAMP", "OUTGO", "PITCH", "WIDEN", "SWAMP",
+    "BETEL", "THRUM", "VYING", "AWASH", "AUDIO", "GONER", "GROAN", "DRAWN", "WHEAT", "SHREW",
+    "SALAD", "RHYME", "SMEAR", "BRICK", "REALM", "INANE", "PROSE", "LOGIC", "TEMPO", "SIXTY",
+    "UNTIE", "PEACE", "LOVER", "CAPER", "STYLE", "ALIEN", "RUSTY", "CRUMP", "TUMOR", "PEDAL",
+    "WELCH", "LIMBO", "DILLY", "INBOX", "SASSY", "RELAY", "DODGE", "LURCH", "JAZZY", "GEESE",
+    "VENOM", "SCORN", "LLAMA", "GREED", "QUASI", "FATAL", "BOUGH", "SNARE", "DOWNY", "GLOVE",
+    "PIXIE", "BLAND", "RIPEN", "BILGE", "SHOUT", "LEECH", "BROIL", "FETAL", "GIRTH", "CLUCK",
+    "PUMPS", "JOKER", "MOULT", "ALERT", "LOWER", "KNEED", "FALSE", "RIGHT", "STORY", "SPILL",
+    "AUGUR", "BLIND", "ROUSE", "FOYER", "DEBUG", "HERON", "AMEND", "WITTY", "CLONE", "ULCER",
+    "MUCUS", "RODEO", "GRASS", "TWEET", "BLOOD", "DEBIT", "SOOTH", "WATER", "MARCH", "LYMPH",
+    "ALTER", "CHIEF", "KNIFE", "MOCHA", "GONAD", "EXTRA", "WASTE", "DROIT", "GLYPH", "BRIBE",
+    "WEDGE", "EVADE", "GUMMY", "DELVE", "TOPIC", "SNIPE", "GEEKY", "SHARK", "BIBLE", "CARAT",
+    "NATAL", "SLATE", "AVIAN", "SHRUB", "DRAFT", "FLECK", "LOOPY", "SCREE", "PATTY", "SAVOY",
+    "GRUEL", "DONOR", "COULD", "SHELL", "AMITY", "CRAZE", "TROOP", "HUMAN", "MAGMA", "INGOT",
+    "CANNY", "ROWDY", "COUPE", "FEVER", "MOUTH", "POESY", "RETCH", "PINKY", "WRITE", "QUEST",
+    "OCTET", "VAUNT", "THESE", "FIGHT", "JEWEL", "TRUCK", "LITHE", "FABLE", "SAUCE", "SETUP",
+    "DENIM", "CREPE", "EXULT", "THIRD", "UDDER", "CLOCK", "TRITE", "TACIT", "WREAK", "GAYER",
+    "PLAIN", "SHORN", "LARVA", "PERIL", "FLORA", "SHEEP", "STAKE", "ROGER", "INTRO", "MYRRH",
+    "ROOST", "SMITH", "DWARF", "RERUN", "ALTAR", "MUNCH", "QUACK", "WRATH", "POKER", "SLAIN",
+    "HOTEL", "TOAST", "ANNEX", "FOCAL", "HUMOR", "SHUCK", "AVAIL", "HABIT", "THEIR", "CIVIC",
+    "BERRY", "RAPID", "TOTEM", "BALER", "HATCH", "STILL", "DUNCE", "ACUTE", "DIGIT", "LEMUR",
+    "BROOK", "NOVEL", "LUSTY", "MOUND", "SKUNK", "DUSTY", "ASSET", "PRAWN", "PANEL", "LYING",
+    "SOLAR", "FROTH", "FLOAT", "LEACH", "VOUCH", "DOWEL", "TOUGH", "CHUTE", "TOUCH", "GLEAM",
+    "DRONE", "RAISE", "FEMUR", "QUOTH", "SHALT", "ADEPT", "CLICK", "MOTTO", "EERIE", "ABHOR",
+    "ADMIN", "ANVIL", "MINIM", "TULIP", "SNORT", "HATER", "PRINT", "BRAWL", "BEFIT", "ELBOW",
+    "CLERK", "MINCE", "PARKA", "AVOID", "CRISP", "COUCH", "CLASS", "WHOSE", "CHALK", "SHEIK",
+    "CROSS", "CIGAR", "FLUKE", "ENDOW", "FLASK", "UTTER", "BURLY", "MOOSE", "RULER", "EXCEL",
+    "BOOTY", "GOOFY", "GRAPH", "AGONY", "QUART", "VALET", "UNION", "POUND", "WOKEN", "PAYER",
+    "ENNUI", "KAYAK", "MAMMY", "PUSHY", "BUSED", "FOAMY", "PIANO", "WEEDY", "EIGHT", "JAUNT",
+    "SLOSH", "CAPUT", "STUDY", "APRON", "CLOUD", "TRIBE", "LEAST", "DETER", "MUSTY", "PETTY",
+    "QUALM", "CHILL", "VISIT", "ENACT", "ROAST", "CHAIR", "LAPSE", "FORGO", "TEDDY", "CAMEO",
+    "DROOL", "VOTER", "HARDY", "SWASH", "ZEBRA", "THIGH", "RIVET", "GIANT", "MASON", "BONGO",
+    "STALE", "INNER", "REVUE", "HELLO", "PICKY", "TWIST", "WHILE", "HAIRY", "BOOST", "SYRUP",
+    "WHIFF", "SPIED", "SATIN", "BORAX", "OXIDE", "SLURP", "VIDEO", "DRIFT", "PIZZA", "WARTY",
+    "PLATE", "FLOWN", "MAFIA", "NYLON", "PSALM", "TWICE", "SHOAL", "TOXIC", "HAUNT", "SULKY",
+    "RABBI", "BROOM", "IDLER", "CLASH", "EDICT", "SMOKE", "HEFTY", "TILDE", "GYPSY", "VODKA",
+    "QUERY", "GOODY", "RUGBY", "NEWLY", "LABEL", "ELECT", "BUILD", "BEECH", "MARRY", "MAIZE",
+    "APPLE", "FICUS", "KOALA", "EXACT", "SWINE", "UNTIL", "DOGMA", "WHOLE", "EVENT", "SLICE",
+    "DEBUT", "STERN", "WATCH", "BINGE", "AMPLY", "WEIGH", "RACER", "PEACH", "GAVEL", "BULLY",
+    "CADDY", "GAWKY", "GOUGE", "WOVEN", "FEAST", "COVEY", "WAFER", "TENET", "LEPER", "SHINE",
+    "ABORT", "DECAY", "MACHO", "GLOAT", "FREER", "SALON", "CLOWN", "POINT", "IDIOT", "TALLY",
+    "BOOBY", "ANGEL", "SNAIL", "OTTER", "DAISY", "GIDDY", "MANOR", "SPORT", "RUPEE", "STACK",
+    "SCOPE", "STICK", "TRUMP", "OCEAN", "KARMA", "KNACK", "SLEEK", "SALVE", "NYMPH", "STOLE",
+    "PROVE", "WOOER", "HIPPO", "BLAST", "PASTE", "SWING", "WINDY", "WINCH", "SHANK", "MAMBO",
+    "QUAIL", "SOLVE", "BEING", "SALLY", "CLANG", "BRINK", "ADAGE", "KHAKI", "DIRGE", "FLANK",
+    "STEAM", "STRAP", "RIGOR", "OPERA", "CLIMB", "PRUNE", "GLORY", "CRIER", "BATTY", "WROTE",
+    "MAMMA", "HOLLY", "ADULT", "NERDY", "SURLY", "WEIRD", "WIGHT", "BOBBY", "THONG", "INERT",
+    "SMITE", "TRUER", "ICING", "ALBUM", "GHOST", "DUSKY", "NEWER", "SERVE", "HUSSY", "DRIED",
+    "LANCE", "FILMY", "TAPER", "BROAD", "FARCE", "WINCE", "HUSKY", "TUBAL", "SCAMP", "FATTY",
+    "GLOSS", "TWEED", "DECOR", "RIVER", "TRASH", "STING", "WOMEN", "SCORE", "VOICE", "FETCH",
+    "BLURT", "SURER", "CHOIR", "PIXEL", "AXIAL", "VIXEN", "EXERT", "DECOY", "ELOPE", "NASTY",
+    "BUDDY", "DEPOT", "FORAY", "SCOFF", "BATCH", "POLYP", "VIOLA", "LOCAL", "ALONG", "KNELT",
+    "NERVE", "ROTOR", "WORTH", "WORLD", "FIBER", "LODGE", "MARSH", "UNMET", "SQUAT", "TASTY",
+    "HEART", "BUILT", "BEARD", "OUTER", "DATUM", "WHEEL", "MANIC", "RIVAL", "OMBRE", "DOPEY",
+    "MATEY", "BLEED", "SHADE", "ROUND", "FUDGE", "FOGGY", "TERRA", "CRUSH", "SUAVE", "FLUME",
+    "ALOUD", "BAYOU", "STRUT", "MERCY", "SCUBA", "SMOTE", "FLOSS", "AMBER", "BROKE", "CRAZY",
+    "STOMP", "TRUCE", "BATHE", "CHANT", "PUPPY", "CARGO", "BRUNT", "BEGET", "BEGAN", "VIRUS",
+    "APNEA", "GROUT", "SWATH", "COACH", "FAVOR", "ALIGN", "ELDER", "TEETH", "FLIER", "SPARE",
+    "SMALL", "PUBIC", "PUNCH", "WRECK", "GENIE", "NICER", "CHESS", "STRAY", "TASTE", "GRAFT",
+    "SHIED", "WEAVE", "STAIN", "FROND", "QUIRK", "LEGAL", "THOSE", "FLEET", "SWOON", "PURGE",
+    "CAUSE", "SPAWN", "OCCUR", "BERTH", "DIMLY", "PLANT", "THORN", "CYCLE", "SCOUR", "WHINE",
+    "CHOSE", "STINK", "ELITE", "BRAND", "ROUGH", "BELOW", "DEPTH", "SPECK", "NINTH", "DOING",
+    "PLAZA", "PRIME", "SPOOL", "RECUT", "BOOTH", "CANON", "SPICY", "SCONE", "MECCA", "KNAVE",
+    "TABBY", "ATONE", "TRUSS", "FRESH", "LEGGY", "AMPLE", "SEVER", "ECLAT", "CHICK", "TRIED",
+    "SNIDE", "SUMAC", "VIGIL", "CHAFE", "SHONE", "FINER", "SIEVE", "DRESS", "AWARD", "LINGO",
+    "KNEAD", "USAGE", "LEAVE", "BISON", "FUNGI", "UNSET", "MEATY", "SPARK", "PECAN", "ELEGY",
+    "PADDY", "CLEAT", "CAVIL", "DRYLY", "SQUIB", "SAUCY", "USING", "AHEAD", "FLYER", "PHASE",
+    "LOGIN", "BINGO", "ADORE", "SLEET", "FECAL", "CRAWL", "TERSE", "RANCH", "CUBIC", "CRANK",
+    "WREST", "SPACE", "SMART", "LAYER", "UNITE", "STUNG", "CORER", "PATIO", "BRAVE", "IGLOO",
+    "LEAKY", "GLAND", "EAGLE", "SPEAK", "SHUNT", "CAROL", "AFFIX", "GRIME", "SPIKE", "GAMER",
+    "GLOOM", "TAINT", "FREAK", "ATOLL", "ABACK", "BRIEF", "CROWN", "DUMMY", "BALMY", "JETTY",
+    "MOTEL", "WOULD", "OPTIC", "SCRUB", "SHEER", "ABUSE", "SPEED", "HELIX", "SPERM", "PRUDE",
+    "CLINK", "LIVER", "THROW", "PRESS", "NAVEL", "SHAME", "DEMON", "TATTY", "CONDO", "SHYLY",
+    "ACORN", "DIZZY", "IRATE", "UPSET", "POUTY", "BLUSH", "LEFTY", "BEEFY", "TULLE", "VITAL",
+    "OMEGA", "CHEST", "BLARE", "REIGN", "SEMEN", "RIFLE", "STONE", "SANDY", "PAPER", "COUGH",
+    "TAFFY", "ROCKY", "REACH", "GREAT", "CHUCK", "BONUS", "REBUS", "CEASE", "TRACT", "NOOSE",
+    "AMBLE", "FULLY", "PHONY", "INLAY", "BEGUN", "CLAIM", "SHARP", "WRACK", "QUOTE", "PLUMB",
+    "BAGGY", "SWORE", "TREND", "REFIT", "FACET", "DOLLY", "CHUMP", "VIPER", "CHASM", "QUOTA",
+    "HEAVE", "OZONE", "CADET", "REBAR", "COLON", "OFTEN", "GREET", "HUNCH", "ENJOY", "ABOUT",
+    "MURKY", "TROVE", "LATHE", "NANNY", "VEGAN", "RAZOR", "TOPAZ", "STUMP", "CATTY", "BEGIN",
+    "AXIOM", "WIDER", "AZURE", "LIVID", "GLAZE", "CHIME", "GROOM", "WAIVE", "SEWER", "USUAL",
+    "DENSE", "SHAKE", "OBESE", "TRADE", "BELLE", "ROACH", "STOOD", "SHORE", "COVET", "COVER",
+    "SHOOT", "DROOP", "PAYEE", "JUDGE", "WHITE", "DECAL", "QUILT", "REVEL", "SHEAR", "LEVEL",
+    "LEVER", "GRASP", "GRUNT", "ANGER", "TONIC", "SLUMP", "BRASS", "CRIED", "SLOTH", "NOSEY",
+    "FRONT", "BERET", "FOLIO", "SPOOK", "MANIA", "CURSE", "REBUT", "OLDER", "BYLAW", "PLAID",
+    "KEBAB", "PROBE", "BENCH", "SKILL", "TODAY", "MINER", "SPICE", "LEASE", "CREEP", "DRYER",
+    "PEARL", "VISTA", "SPOON", "HORNY", "FUGUE", "BUXOM", "LIPID", "MAYBE", "ADOPT", "FANCY",
+    "BAKER", "SCARF", "TRAMP", "EXIST", "RHINO", "DRAWL", "GLINT", "CLIFF", "CLEAN", "WAGER",
+    "WRING", "STUNT", "WOUND", "CACHE", "SALVO", "IONIC", "RAVEN", "SNIFF", "GAUGE", "KAPPA",
+    "LAUGH", "BRIDE", "WAGON", "FRUIT", "SPURT", "ETHIC", "POWER", "SWIRL", "ELUDE", "GRADE",
+    "CANDY", "SPURN", "TODDY", "LUMPY", "OPIUM", "BASIN", "HONEY", "JUICY", "COMMA", "TESTY",
+    "NOISE", "GIRLY", "BELCH", "SUING", "LORRY", "CLACK", "HINGE", "LATCH", "DITTY", "MEALY",
+    "ROWER", "SHAWL", "SCRAP", "TIMID", "WHICH", "PLANE", "BIOME", "LEAPT", "DOUBT", "LOSER",
+    "SCARY", "BRAVO", "CLUED", "CROOK", "ALGAE", "MOUNT", "CHOCK", "GUPPY", "BLAME", "REUSE",
+    "AGING", "SLICK", "REEDY", "TONAL", "EXPEL", "FOLLY", "COVEN", "UNDUE", "ALLEY", "SURGE",
+    "FEWER", "SCALE", "OFFAL", "MAKER", "FIXER", "REGAL", "PLIER", "NORTH", "MORON", "SPELT",
+    "ASSAY", "BOOZY", "RANGE", "SPEAR", "FLASH", "MUSHY", "BLAZE", "WILLY", "ARISE", "FLOOD",
+    "SNOUT", "SERIF", "TOXIN", "DWELL", "CURVE", "MELON", "PALSY", "BUGLE", "SWORN", "SHADY",
+    "TREAT", "BLEAK", "RADAR", "THINK", "TUTOR", "PULSE", "WOOLY", "AROSE", "GRAPE", "SWEAR",
+    "ORBIT", "PASTY", "DRAKE", "REPLY", "SHAFT", "URBAN", "DICEY", "SKULL", "SHIRT", "BASAL",
+    "ADORN", "CHARD", "SPOIL", "WHALE", "FINAL", "WIDOW", "MULCH", "WAVER", "RIDGE", "SAUNA",
+    "BRAID", "NICHE", "AGENT", "CYNIC", "FLINT", "TOTAL", "QUILL", "YACHT", "YEARN", "SINEW",
+    "ALLAY", "MICRO", "LADEN", "TABLE", "POLAR", "HAZEL", "VERSO", "DWELT", "BASIS", "RISEN",
+    "GAMMA", "ALOFT", "GUILE", "SHIFT", "SPINY", "EJECT", "INEPT", "DRINK", "STARE", "AFOUL",
+    "VERSE", "BOULE", "FAITH", "TROLL", "MANGE", "RIDER", "SILLY", "BURNT", "MERIT", "ONSET",
+    "JOLLY", "IMPLY", "BUSHY", "POPPY", "VINYL", "BLUNT", "ALPHA", "EMCEE", "SADLY", "SCION",
+    "PITHY", "TORUS", "CLUMP", "SLIMY", "BANJO", "ANGLE", "FOUND", "TRIAL", "SEDAN", "SPIRE",
+    "LEANT", "DROLL", "RUDER", "SHOOK", "SPITE", "PLUMP", "SEIZE", "HIPPY", "MANGO", "SISSY",
+    "DOZEN", "SMOCK", "GECKO", "DIARY", "STORE", "MINTY", "PROWL", "RADIO", "AROMA", "IMPEL",
+    "PIETY", "OCTAL", "TRIAD", "TROUT", "TROPE", "VENUE", "HEARD", "POOCH", "FLOUT", "PLAIT",
+    "SOLID", "ISLET", "THETA", "VAGUE", "ABATE", "CRATE", "SCENT", "VALVE", "BESET", "WACKY",
+    "CHOKE", "CATER", "KNOWN", "CASTE", "EMBED", "HARPY", "HARRY", "SLACK", "NUTTY", "RALLY",
+    "GUAVA", "ORDER", "FANNY", "CREEK", "TOWEL", "GOOEY", "STUNK", "PATSY", "PRIOR", "ENVOY",
+    "CYBER", "BILLY", "TWIXT", "FOCUS", "BOTCH", "IVORY", "MEDIC", "EPOXY", "FLUNK", "SERUM",
+    "QUARK", "LANKY", "MEANT", "GUILT", "UPPER", "AISLE", "BLACK", "EXILE", "MILKY", "APPLY",
+    "TRAIN", "RECUR", "RECAP", "DEMUR", "COCOA", "INFER", "BLOOM", "PUFFY", "TRULY", "DAUNT",
+    "SKATE", "NADIR", "MOURN", "CHAFF", "TRACK", "FAINT", "SWELL", "UNFIT", "SWEAT", "MIDST",
+    "FELON", "FORTY", "SHALL", "SNORE", "FLICK", "REARM", "FLIRT", "FLOOR", "BIRCH", "SCANT",
+    "MOODY", "ALLOW", "LATER", "GOURD", "CHASE", "KINKY", "ANODE", "GRACE", "BLEEP", "MOGUL",
+    "LOOKS", "GOODS",
+];
+
+static SIX_ANSWERS: [&str; 1103] = [
+    "FIGURE", "MANUAL", "FOLLOW", "CAMPUS", "REPORT", "GOSSIP", "TISSUE", "SUPPLY", "KENNEL",
+    "LATTER", "ANYONE", "OUTING", "GRUMPY", "NATION", "CUTLER", "LISTEN", "MURMUR", "RACKET",
+    "RATHER", "COCOON", "EXCUSE", "UNIQUE", "BOTTOM", "RARELY", "POLICE", "RETAIL", "BURDEN",
+    "APPEAR", "DEPEND", "RADIUS", "PIGEON", "STENCH", "APPEAL", "ENDING", "EMPIRE", "MINNOW",
+    "CENTER", "MAKING", "RAMBLE", "MOSAIC", "CHOOSE", "CEMENT", "CARAFE", "JOCKEY", "FAMILY",
+    "INTEND", "PROPER", "CURFEW", "PATENT", "PARROT", "LIVING", "LEGUME", "TOGGLE", "PALLET",
+    "RATING", "CACTUS", "RECIPE", "COFFIN", "EITHER", "DROWSY", "SYMBOL", "FOREST", "SPIRAL",
+    "UNREST", "FAUCET", "FREEZE", "FRIDGE", "REVOLT", "BANNER", "JESTER", "DIVIDE", "SCREEN",
+    "FARMER", "PUPPET", "EFFORT", "MALLET", "WARMTH", "PLUNGE", "CASTLE", "FOURTH", "CORNER",
+    "BOUGHT", "ORCHID", "ESCORT", "BLOUSE", "TUXEDO", "THOUGH", "ALWAYS", "INHALE", "RENOWN",
+    "MEMORY", "RETIRE", "AUTHOR", "LITTLE", "BACKUP", "REPLAY", "VESSEL", "SHOWER", "RESIST",
+    "VICTIM", "PRINCE", "DEFIED", "FRIGHT", "NAPKIN", "LOUNGE", "DURING", "SCROLL", "COLONY",
+    "WRITER", "FERVOR", "MODERN", "PATROL", "RACING", "SOCIAL", "MELLOW", "VANISH", "LENGTH",
+    "BORROW", "BEACON", "BUCKLE", "SHOVEL", "SIPHON", "SUPERB", "OBJECT", "SIMPLE", "TYCOON",
+    "NOODLE", "REMARK", "BRIGHT", "REMOTE", "CRISIS", "DIFFER", "SKETCH", "MEDLEY", "RETAIN",
+    "BUNDLE", "FORMAL", "MARGIN", "REBATE", "BETTER", "DEFINE", "MUTUAL", "INSIDE", "PENNED",
+    "ADVICE", "GINGER", "DEADLY", "SERIES", "SOURCE", "THIRST", "WAGGLE", "BAMBOO", "LOTION",
+    "DIRECT", "STUFFY", "SEESAW", "THIRTY", "SCHEME", "SECOND", "DEFTLY", "MERGER", "CHAPEL",
+    "ORANGE", "INJURY", "FLEECE", "STREET", "GARAGE", "BROKEN", "SQUARE", "UNFOLD", "PROVEN",
+    "POLISH", "FRINGE", "FEMALE", "GROWTH", "TACKLE", "TRIPLE", "USEFUL", "TURNIP", "RENTAL",
+    "DEPICT", "BASKET", "EATING", "BRUTAL", "SENIOR", "LIQUID", "STATUS", "CREDIT", "TAVERN",
+    "LETTER", "BEAUTY", "EASILY", "SYRUPY", "EXTENT", "SCULPT", "LINGER", "LIZARD", "BURGER",
+    "PRAISE", "TROPHY", "FIDDLE", "PEOPLE", "COARSE", "SCHOOL", "HEALTH", "RASCAL", "CARBON",
+    "LAUNCH", "HEAVEN", "AGENDA", "KIDNEY", "RELATE", "SONNET", "LOCKET", "MEMBER", "AIRWAY",
+    "DOMAIN", "LEAVEN", "TONGUE", "WINTER", "MORSEL", "SQUASH", "RHYTHM", "REFILL", "BELIEF",
+    "GALAXY", "TOWARD", "IMMUNE", "MENTAL", "JOGGER", "CRADLE", "REJECT", "SPEECH", "PREACH",
+    "INLAND", "TWELVE", "COMBAT", "HORNET", "PURSUE", "STRIFE", "TURKEY", "DECIDE", "RANSOM",
+    "MOBILE", "COMEDY", "OXYGEN", "LATELY", "STRICT", "GALLOP", "SLEEVE", "BODILY", "FACTOR",
+    "POLLEN", "ENIGMA", "EAGLET", "ZENITH", "GLOBAL", "NARROW", "REALLY", "MOTION", "SERMON",
+    "SADDLE", "MONKEY", "ACHING", "BALLAD", "KERNEL", "BOXING", "UPBEAT", "VIRTUE", "ENCORE",
+    "FAIRLY", "INTENT", "STRAIN", "GATHER", "SNEEZE", "JACKET", "ENOUGH", "RIDDLE", "BOILER",
+    "PRIMAL", "RECALL", "DEPLOY", "FLIGHT", "ERRATA", "MAIDEN", "BEHIND", "NUMBER", "ATOMIC",
+    "THEORY", "MINUTE", "ATTEND", "MOVING", "DIVING", "CIPHER", "PLAQUE", "ENTIRE", "BUREAU",
+    "ISLAND", "BANDIT", "GUIDED", "PADDLE", "GALLON", "ADMIRE", "SUMMIT", "TIMELY", "CANYON",
+    "SISTER", "FAMOUS", "OYSTER", "IODINE", "OCELOT", "SLIGHT", "HELPER", "HUSTLE", "NOZZLE",
+    "EMBLEM", "FLOWER", "RECORD", "SILVER", "ATTIRE", "GOBLET", "AMOUNT", "DOODLE", "ORACLE",
+    "SOCCER", "COPPER", "FALLEN", "UPKEEP", "CLOSET", "INVENT", "GADGET", "KNIGHT", "PERIOD",
+    "ADVENT", "QUIVER", "FLINCH", "FROZEN", "STRAND", "COMPEL", "CLERGY", "EXPOSE", "BREEZE",
+    "BEETLE", "PROFIT", "PRANCE", "POETRY", "CATTLE", "FOSTER", "LEGEND", "ZIGZAG", "STICKY",
+    "MUSKET", "CHEESE", "RAISIN", "REWARD", "MOTHER", "GROCER", "EVOLVE", "JUGGLE", "SAMPLE",
+    "SPIRIT", "BECAME", "YOGURT", "NORMAL", "BEAGLE", "DECADE", "ATTACK", "CHOICE", "TEMPLE",
+    "ELDEST", "FACING", "OPAQUE", "COBALT", "IGNORE", "GENTRY", "PLENTY", "BEHAVE", "PISTOL",
+    "SUNSET", "MAGNET", "CHISEL", "DEFECT", "TRENCH", "EXCEPT", "HURDLE", "HARDLY", "ARRIVE",
+    "SIGNAL", "SOCKET", "PICKLE", "FROTHY", "SYSTEM", "VIOLIN", "GOLDEN", "DEFEAT", "PULPIT",
+    "STABLE", "AVENUE", "NATURE", "MUSEUM", "KINDLY", "COMPLY", "UPWARD", "HASSLE", "TERROR",
+    "NATIVE", "SCARCE", "COWARD", "WOBBLE", "VERSUS", "LESSEN", "GOLFER", "CANNON", "REBOOT",
+    "HONEST", "THRILL", "PHOBIA", "REFORM", "BELONG", "SUMMER", "DAGGER", "UNWRAP", "RAFFLE",
+    "KINGLY", "SNOBBY", "TENANT", "GUILTY", "MANNER", "BLIGHT", "SECTOR", "BUTTER", "REGARD",
+    "FATHER", "FIRMLY", "PARENT", "BATTLE", "DELUXE", "OPTION", "FILTER", "WONDER", "FLUENT",
+    "ENGINE", "GARISH", "WAITER", "HEIGHT", "COUSIN", "SUFFER", "PUDDLE", "WALRUS", "MAINLY",
+    "MEADOW", "GENIUS", "BOUNTY", "LOVELY", "SOLEMN", "DECREE", "FLYING", "LEADER", "STOLEN",
+    "LESSON", "SPOKEN", "JUNGLE", "ARTIST", "WINDOW", "WEASEL", "BRAINY", "MARKER", "STRIKE",
+    "TINKER", "LIKELY", "SUBMIT", "REMAIN", "SMUDGE", "GARDEN", "SWITCH", "HEROIC", "MENACE",
+    "SNOOZE", "UNLOCK", "SPROUT", "FLURRY", "ITSELF", "CITRUS", "PRISON", "SLOWLY", "ARMADA",
+    "THRONE", "CREAMY", "KITTEN", "HOPPER", "MARROW", "MYSELF", "DEEPLY", "ESTATE", "RADISH",
+    "TRIFLE", "EFFACE", "INTACT", "SURVEY", "ASSERT", "CLUMSY", "BUDGET", "CASUAL", "WORKER",
+    "PRAYER", "MOLTEN", "ARCHER", "ACTUAL", "YELLOW", "SEPTIC", "OFFEND", "PILLAR", "TENDON",
+    "SETTLE", "ACROSS", "HOBBIT", "UPROAR", "MIDWAY", "REPEAT", "SUMMON", "KETTLE", "MOSTLY",
+    "SLOGAN", "BUMPER", "MATRIX", "EIGHTY", "FABRIC", "PIRATE", "COOKIE", "MENTOR", "BRIDGE",
+    "MAGPIE", "FAMINE", "MARVEL", "PONDER", "STEADY", "TEMPER", "CHARGE", "FLAVOR", "ANNUAL",
+    "POLICY", "NIMBLE", "RECTOR", "DEPUTY", "RATTLE", "ONLINE", "CHANGE", "BINARY", "ASLEEP",
+    "CHANCE", "INCOME", "AFFORD", "STRUCK", "MUMBLE", "SURELY", "ALMOND", "PERISH", "DAZZLE",
+    "ACCEPT", "IGNITE", "BIGGER", "HIDDEN", "MEDIUM", "MYRIAD", "HUMBLY", "EXTEND", "RESCUE",
+    "HEALER", "CLIENT", "RESORT", "VECTOR", "VISION", "JOYFUL", "LAGOON", "EMPLOY", "GIGGLE",
+    "DOLLAR", "TAMALE", "MUSCLE", "FISCAL", "COUPLE", "EUREKA", "CANCER", "GLANCE", "SINGER",
+    "SIMPLY", "WEEKLY", "CIRCLE", "BALLET", "CRITIC", "HELMET", "BOUNCE", "BARBER", "PEPPER",
+    "MARKET", "NOBODY", "POODLE", "NUANCE", "TWENTY", "CIRCUS", "FRENZY", "DESERT", "BAKERY",
+    "PRETTY", "PALACE", "EXPERT", "CANVAS", "CASKET", "POTATO", "BUTTON", "ABSURD", "LEAGUE",
+    "ACTION", "FIERCE", "NEPHEW", "UNLESS", "HAGGLE", "INSANE", "CUSTOM", "POUNCE", "WALLET",
+    "PUBLIC", "METHOD", "QUARRY", "RESULT", "ORDEAL", "FIESTA", "FINISH", "RITUAL", "STRIPE",
+    "DUSTER", "REFUND", "RUNNER", "PERSON", "PASTEL", "DETECT", "GREASE", "MAMMAL", "SMOOTH",
+    "BEHOLD", "CHURCH", "TOMATO", "VERBAL", "RESIGN", "REMOVE", "OUTFIT", "BREWER", "LADDER",
+    "PACKET", "SUBURB", "HUNTER", "SALMON", "ABSORB", "BULLET", "FASTEN", "READER", "VALLEY",
+    "GLOSSY", "GENTLE", "PETITE", "WARDEN", "EXPIRE", "FALCON", "CRUISE", "FRENCH", "IMPACT",
+    "WISDOM", "UPDATE", "CANDLE", "HOCKEY", "REVEAL", "ACCESS", "SCRIPT", "BRANCH", "HANGAR",
+    "AFFAIR", "FATHOM", "DECENT", "MOMENT", "BEFORE", "REDUCE", "MIRROR", "SAVAGE", "ASPECT",
+    "FORMER", "COLUMN", "QUARTZ", "STANZA", "NUGGET", "CANOPY", "PARDON", "TURTLE", "CARROT",
+    "ROBUST", "MATTER", "SPRING", "ODDITY", "AGENCY", "URGENT", "INFORM", "FINGER", "LIABLE",
+    "UPSIDE", "TRANCE", "FAILED", "NIBBLE", "HURRAH", "HAPPEN", "INVITE", "MELODY", "RANDOM",
+    "GLIDER", "SHRINE", "CALLER", "BECOME", "STAPLE", "PASTOR", "COMMON", "REFUSE", "SLUDGE",
+    "EFFECT", "CURSOR", "TIMBER", "LUSTER", "SORBET", "CARPET", "JUNIOR", "CASINO", "TENNIS",
+    "MINGLE", "POLITE", "FORMAT", "STROKE", "BISHOP", "GUSHER", "VELVET", "NOTION", "BRIDAL",
+    "SPRUCE", "STRING", "CONVEY", "BARLEY", "COLLAR", "BEYOND", "ROTATE", "BACKED", "AFRAID",
+    "BITTER", "FUNNEL", "PASTRY", "DAMAGE", "MUSTER", "CAVERN", "DRIVER", "DESIGN", "EQUITY",
+    "BORING", "ICICLE", "OFFSET", "STRONG", "BABBLE", "THWART", "COUGAR", "DANGER", "BLONDE",
+    "MASCOT", "VERMIN", "POPPER", "ADVISE", "LAVISH", "FODDER", "PARADE", "MARBLE", "SANDAL",
+    "VIOLET", "PELLET", "ACTING", "TICKET", "TARGET", "GENDER", "LOCATE", "ENDURE", "DRAWER",
+    "CELLAR", "TEAPOT", "COURSE", "COSTLY", "TENDER", "CINEMA", "FORBID", "FORGET", "PENCIL",
+    "THREAD", "SINGLE", "DETAIL", "HATRED", "INSIST", "ENTITY", "DESIRE", "COMBED", "SPREAD",
+    "VACANT", "MATURE", "SPIDER", "MUFFIN", "WOMBAT", "CREATE", "BUBBLE", "WHEEZE", "SERVER",
+    "DIVINE", "REVIEW", "DANCER", "SUDDEN", "PLANET", "COFFEE", "BUGGED", "DECODE", "PLURAL",
+    "BUFFER", "MODEST", "RECENT", "CLEVER", "FELLOW", "EARNED", "EXOTIC", "DEPART", "BEHALF",
+    "HUMBLE", "NECTAR", "MERELY", "EMERGE", "DENTAL", "ASSUME", "DRAFTY", "THRIVE", "TIPTOE",
+    "ANKLET", "WALNUT", "HEARTY", "GROUND", "SNAPPY", "CORRAL", "ASSIST", "OFFICE", "RELIEF",
+    "REASON", "RUBBER", "IMPORT", "INSECT", "TEDIUM", "RUSTIC", "MANTLE", "TAILOR", "NEARBY",
+    "INVEST", "NICKEL", "WIGGLE", "OBLONG", "INDOOR", "BOTTLE", "TREATY", "CORDON", "SAFELY",
+    "INFANT", "TABLET", "REMIND", "REGION", "SUNDAE", "REALTY", "REGRET", "EXCEED", "HERMIT",
+    "DEBATE", "ANCHOR", "GAMBLE", "ANSWER", "CHORUS", "SHRIMP", "RUMBLE", "BEAKER", "JARGON",
+    "HOLDER", "ADJUST", "WIZARD", "DONKEY", "CAMERA", "INFUSE", "WITHIN", "CRAYON", "SUPPER",
+    "CLOSER", "ASSIGN", "ACCUSE", "BREATH", "URCHIN", "RETURN", "SAFETY", "POSTER", "BARREL",
+    "CHERRY", "TOFFEE", "CAUGHT", "BOVINE", "ACCENT", "AUTUMN", "DEGREE", "LOCKER", "WANDER",
+    "GENTLY", "ABRUPT", "BUSTLE", "DEMAND", "PUNDIT", "PARCEL", "CEREAL", "FRIEND", "RISING",
+    "BUCKET", "RAVINE", "SPONGE", "HAZARD", "GOBLIN", "FONDLY", "GROTTO", "RABBIT", "ALMOST",
+    "WINNER", "HUNGRY", "DINNER", "EXPECT", "BORDER", "SUBTLE", "SPHINX", "SHADOW", "NEEDLE",
+    "STROLL", "COYOTE", "BUTLER", "CLOSED", "THREAT", "FINALE", "WEAPON", "ASSESS", "ENSURE",
+    "TROWEL", "RENDER", "ZIPPER", "ACTIVE", "TALENT", "JIGSAW", "WAFFLE", "DOCTOR", "GASKET",
+    "DAINTY", "SIZZLE", "TICKLE", "BADGER", "LUXURY", "SLEIGH", "VOLUME", "CHUNKY", "ORIGIN",
+    "WOODEN", "PEWTER", "PAPAYA", "DRAGON", "BLEACH", "NOTICE", "MANAGE", "RIBBON", "GROOVE",
+    "AGREED", "LEGACY", "SEVERE", "TANGLE", "UNEVEN", "DEFEND", "SELDOM", "SALARY", "BELLOW",
+    "SACRED", "VISUAL", "HORROR", "SEASON", "SWIVEL", "PUZZLE", "MILDEW", "RODENT", "PREFER",
+    "HANDLE", "SOLELY", "PISTON", "MASTER", "SLEEPY", "SILENT", "HARBOR", "UPHILL", "NEARLY",
+    "GRAVEL", "UNLIKE", "GUITAR", "COTTON", "STRESS", "ANYWAY", "ANIMAL", "ELEVEN", "RESUME",
+    "FOSSIL", "CRUNCH", "SECRET", "REPAIR", "HOLLOW", "BURROW", "WHISKY", "TUMBLE", "CANCEL",
+    "WICKED", "POWDER", "HECTIC", "COBWEB", "LAWYER", "ROCKET", "LENDER", "DEVICE", "ERRAND",
+    "NESTLE", "NEATLY", "ENERGY", "SELECT", "MITTEN", "EXPAND", "GEYSER", "KIDNAP", "OBTAIN",
+    "CHOSEN", "SCENIC", "HAMMER", "TRAVEL", "FLORAL", "EMBARK", "DWELLS", "WEIGHT", "BONNET",
+    "ATTACH", "MIDDLE", "OUTPUT", "AROUND", "PURPLE", "INDEED", "REMEDY", "RIPPLE", "ENTICE",
+    "PERMIT", "ZOMBIE", "STREAM", "FUTURE", "PILLOW", "SPLINT", "ENGAGE", "GARLIC", "COSMIC",
+    "WEALTH", "LUNACY", "SECURE", "CRUTCH", "BESIDE", "STITCH", "THORNY", "PEBBLE", "SALUTE",
+    "CREEPY", "POCKET", "CAREER", "EXPORT", "TUNNEL", "PHRASE", "VOYAGE", "MUZZLE", "SHRINK",
+    "HICCUP", "CUDDLE", "ESCAPE", "BREACH", "LAPTOP", "ENABLE", "STATUE", "PEANUT", "ETHNIC",
+    "HYBRID", "PICNIC", "SCORCH", "STUDIO", "BANANA", "ARCADE", "DREARY", "PUFFIN", "JERSEY",
+    "ABSENT", "BRONZE", "SPLASH", "ROTTEN", "DOUBLE",
+];
+
+static SEVEN_ANSWERS: [&str; 891] = [
+    "EXACTLY", "ROUTINE", "POSTAGE", "PROMISE", "VINEGAR", "TRIUMPH", "LEISURE", "LULLABY",
+    "BOUQUET", "REALIZE", "SUNDIAL", "PLANTER", "SEVERAL", "HOLSTER", "CENTURY", "PROCESS",
+    "CHORTLE", "PELICAN", "CATFISH", "RAMPART", "ETERNAL", "CABINET", "PRIVACY", "FRAGILE",
+    "TYPICAL", "BALANCE", "TIDINGS", "MAGENTA", "RELEASE", "PATIENT", "MESSAGE", "JOURNEY",
+    "GALLERY", "NOTHING", "MACHINE", "COMMENT", "MUSTARD", "COVERED", "MAILBOX", "OFFENSE",
+    "CHAPTER", "EMBRACE", "PENDANT", "EPISODE", "MASSIVE", "OUTLOOK", "CRAFTED", "CASCADE",
+    "PADLOCK", "SPANIEL", "SWIMMER", "MIGRANT", "SORCERY", "GLITTER", "FLANNEL", "FIDDLER",
+    "BEDTIME", "QUARTER", "PRODUCE", "LICENSE", "CRYSTAL", "CHARITY", "NEITHER", "GETTING",
+    "FESTIVE", "EXTREME", "GLACIER", "REGATTA", "RUNNING", "LEGGING", "WHISTLE", "FINDING",
+    "PROMOTE", "EXPLODE", "MEASURE", "GRADUAL", "GESTURE", "CARRIER", "SATISFY", "THISTLE",
+    "LANDING", "EARLIER", "KETCHUP", "STARDOM", "ANYBODY", "FIGHTER", "FEATURE", "VARIOUS",
+    "SATCHEL", "ARTICLE", "TOWARDS", "CATALOG", "KITCHEN", "POLECAT", "EXCITED", "AIRPORT",
+    "CLASSIC", "HOWEVER", "ATTRACT", "MERMAID", "MISSION", "HUNDRED", "MYSTERY", "CAPTION",
+    "WOODCUT", "CONTROL", "NURSERY", "MALLARD", "PROTECT", "SWALLOW", "SNORKEL", "KINGDOM",
+    "NOWHERE", "MINERAL", "ORCHARD", "INQUIRY", "GRIMACE", "SURVIVE", "WELFARE", "BANKING",
+    "PERFECT", "UNKNOWN", "COMFORT", "DRIVING", "EARMUFF", "ROLLING", "BEARING", "GUMDROP",
+    "LEADING", "REALITY", "COBBLER", "REQUEST", "LADYBUG", "FLAVOUR", "PRETZEL", "ACQUIRE",
+    "REFLECT", "LANTERN", "ILLEGAL", "DIALECT", "INSTALL", "TADPOLE", "INFERNO", "CROWBAR",
+    "ASSAULT", "RESTORE", "WORKING", "FEELING", "PROVIDE", "WALKING", "PANCAKE", "INTENSE",
+    "WRANGLE", "PROGRAM", "SQUEAKY", "DRAWING", "RESCUER", "SCEPTER", "ENGLISH", "FAILURE",
+    "PASSIVE", "FREEDOM", "PAGEANT", "RHUBARB", "HEARING", "BESIDES", "ORATION", "ARRIVAL",
+    "WHISKER", "HELPFUL", "ENCHANT", "HOLIDAY", "SURGERY", "THEATRE", "LEATHER", "DOLPHIN",
+    "OUTLAST", "SHAMPOO", "NARRATE", "OBSCURE", "LEARNED", "ALLERGY", "SOLDIER", "ACCOUNT",
+    "GLIMPSE", "HANGING", "PACKAGE", "CAREFUL", "MAGICAL", "EXHIBIT", "BROTHER", "LAPWING",
+    "PICTURE", "HIMSELF", "THERAPY", "TRACTOR", "DESPITE", "CLARITY", "ZEALOUS", "QUALITY",
+    "CALDRON", "HOSTILE", "MAXIMUM", "DERRICK", "PILGRIM", "FANFARE", "SUSTAIN", "GRAPHIC",
+    "SEAWEED", "ELECTED", "WARBLER", "BETWEEN", "ENDLESS", "TERRAIN", "MOONLIT", "UNICORN",
+    "CONDUIT", "VIOLENT", "PERHAPS", "TOURISM", "CONNECT", "ELDERLY", "ELEMENT", "ALREADY",
+    "KEEPING", "JUSTICE", "RIPTIDE", "KNOWING", "DUNGEON", "EMOTION", "MOLLUSK", "BOULDER",
+    "CONCEPT", "INSPECT", "BUILDER", "INVOLVE", "ELEGANT", "BARGAIN", "THUNDER", "FURTHER",
+    "VENTURE", "DELIVER", "KAYAKER", "MARINER", "COMPLEX", "PASSAGE", "WAYWARD", "FLOTSAM",
+    "CONQUER", "ORGANIC", "RECEIVE", "PENALTY", "ADVISER", "LOGICAL", "MIDWIFE", "SERVICE",
+    "PERSIST", "HYDRANT", "SOCIETY", "JUGGLER", "SANDBAR", "CRUCIAL", "TRAVAIL", "MILLION",
+    "GLISTEN", "WITNESS", "SETTING", "LIBERTY", "MUSTANG", "SUPREME", "VEHICLE", "AGAINST",
+    "VISITOR", "UPRIGHT", "THOUGHT", "SEGMENT", "LETTUCE", "PEACOCK", "BECAUSE", "ACADEMY",
+    "PREVENT", "CONTAIN", "CLUSTER", "WILLING", "PROCEED", "HARPOON", "PERCENT", "LIBERAL",
+    "FIREFLY", "FROSTED", "OPULENT", "RECOVER", "COMPANY", "DEFENSE", "AMATEUR", "OSTRICH",
+    "DOORWAY", "HUSBAND", "QUILTED", "PARSLEY", "LOCKJAW", "PHANTOM", "BRAVADO", "PARTNER",
+    "CASHIER", "CHICKEN", "FOREVER", "DIPLOMA", "DENTIST", "CONSOLE", "SCALLOP", "GAZELLE",
+    "SHERBET", "TROUBLE", "PROFILE", "TOURIST", "RESPOND", "SUNBEAM", "BANQUET", "AUCTION",
+    "GEARBOX", "WASTREL", "DAYTIME", "PLASTIC", "HAMSTER", "VERANDA", "MANSION", "CLOTHES",
+    "DISTANT", "PROJECT", "COMPACT", "DEFAULT", "PANTHER", "CARAVAN", "HUNTING", "POVERTY",
+    "FASHION", "MEETING", "CORONER", "BILLION", "SUSPECT", "ENVIOUS", "GATEWAY", "WRINKLE",
+    "CITIZEN", "ILLNESS", "FEATHER", "REPTILE", "ADDRESS", "AMAZING", "HOTCAKE", "PROPHET",
+    "DIVIDED", "TEXTURE", "CHARMED", "MONTHLY", "GROCERY", "COPPICE", "CURIOUS", "MARQUEE",
+    "TUGBOAT", "DECLINE", "POTTERY", "APOLOGY", "EMBASSY", "PROBLEM", "ANYMORE", "INKWELL",
+    "VETERAN", "BIOLOGY", "BUTCHER", "NEGLECT", "JAVELIN", "ANXIETY", "ICEBERG", "LIMITED",
+    "PHOENIX", "STORAGE", "RAILWAY", "READILY", "ACHIEVE", "SCHOLAR", "SHELTER", "PRODUCT",
+    "ATTEMPT", "COMPASS", "LECTURE", "BARRIER", "CYCLONE", "POINTED", "MANAGER", "ADVANCE",
+    "COUNCIL", "SITTING", "IMITATE", "HELIPAD", "OVATION", "COASTER", "SPINACH", "BEATING",
+    "DEVELOP", "APRICOT", "MIRACLE", "INSTEAD", "REMOVAL", "HORIZON", "CAPTURE", "EXHAUST",
+    "SARDINE", "MUSICAL", "WITHOUT", "PARASOL", "PENSION", "COUNTER", "VAGRANT", "PLEASED",
+    "ANCIENT", "COUNTRY", "COSTUME", "WALLABY", "FURIOUS", "CONCERN", "HACKSAW", "BISCUIT",
+    "ARTWORK", "HARMONY", "CUSHION", "ECLIPSE", "HEADING", "DIAMOND", "ENTROPY", "SERPENT",
+    "OFFICER", "CAUTION", "FOREIGN", "THEREBY", "RELATED", "ADVERSE", "SERIOUS", "OUTSIDE",
+    "TOASTER", "DESKTOP", "BRISKET", "TRAFFIC", "FACTORY", "DISPLAY", "EXCLUDE", "HARVEST",
+    "FARMING", "OPENING", "CAVALRY", "COMPETE", "PROTEST", "MIXTURE", "ROSEBUD", "SAFFRON",
+    "RATTLER", "EXAMINE", "BELIEVE", "PAYMENT", "PARCHED", "FOUNDER", "STRANGE", "RESOLVE",
+    "CONTEST", "CAPITAL", "VERSION", "MISSING", "MEANING", "OUTCOME", "PRAIRIE", "ROOSTER",
+    "MEANDER", "STOMACH", "HANDBAG", "ANAGRAM", "MONITOR", "RAINBOW", "SUCCESS", "VOLCANO",
+    "TRUFFLE", "MONSTER", "AVERAGE", "DENSITY", "TORNADO", "EVENING", "WEALTHY", "GOLFING",
+    "PERFUME", "FINANCE", "WAITING", "FOREARM", "SURFACE", "CHEETAH", "CLEMENT", "NOSTRIL",
+    "MOCKERY", "SHORTLY", "COCKPIT", "PROWESS", "REPLACE", "CRUSADE", "CHARIOT", "TURMOIL",
+    "SPEAKER", "HALFWAY", "GENERAL", "PRIVATE", "HONESTY", "HANDFUL", "FISHING", "TRAINER",
+    "BULLDOG", "UNUSUAL", "RESPECT", "SPARROW", "DECIBEL", "SCIENCE", "CLOSING", "BATTERY",
+    "IMAGINE", "GRANARY", "SAWMILL", "BLANKET", "COMMAND", "CANDIED", "WELCOME", "GRAVITY",
+    "CONSIST", "SPONSOR", "CREATOR", "DIVERSE", "CUTTING", "JACKPOT", "EDUCATE", "REQUIRE",
+    "ANALYST", "TRUMPET", "VILLAGE", "PUDDING", "COOKING", "WESTERN", "PYRAMID", "VAMPIRE",
+    "SESSION", "OPERATE", "PAPRIKA", "AMBIENT", "IRKSOME", "PILLAGE", "MENTION", "VANILLA",
+    "THROUGH", "JUSTIFY", "FAILING", "GROWING", "BANDAGE", "FACULTY", "COOLANT", "SUNBURN",
+    "CRACKER", "VIRTUAL", "SUNRISE", "ALCOHOL", "ANOTHER", "CORRECT", "ANTIQUE", "WRITING",
+    "DEFICIT", "PLACARD", "ESQUIRE", "ISOTOPE", "SCOOTER", "FRECKLE", "CLIMBER", "CONTEXT",
+    "DISPUTE", "DEPOSIT", "TRIDENT", "MASCARA", "SHERIFF", "PREMIER", "VILLAIN", "MISTAKE",
+    "VIBRANT", "DYNAMIC", "STRETCH", "EXOTICA", "PLAYING", "CONSENT", "CERTAIN", "CHEMIST",
+    "DORMICE", "ABSENCE", "SUMMARY", "EXPENSE", "MORNING", "EVIDENT", "SOMEHOW", "CONCEAL",
+    "DWINDLE", "FICTION", "MIDTERM", "PAINFUL", "OBVIOUS", "REVERSE", "NEUTRAL", "EQUALLY",
+    "HEALTHY", "SPECIAL", "INITIAL", "APPLIED", "ARRANGE", "CRIMSON", "SWEATER", "CRUMPET",
+    "EXAMPLE", "FITNESS", "GRIDDLE", "GENETIC", "ESSENCE", "CRAVING", "MINIMUM", "TONIGHT",
+    "WHISPER", "WARRIOR", "TURBINE", "OPTICAL", "DISEASE", "PARTIAL", "VARIETY", "HEAVILY",
+    "CHECKED", "LARGELY", "DESPAIR", "APPLAUD", "WINNING", "CURTAIN", "SCARLET", "VISIBLE",
+    "SKYLINE", "OATMEAL", "UTILITY", "DESSERT", "STUDENT", "HISTORY", "PUMPKIN", "FUNERAL",
+    "TEXTILE", "CHAMBER", "LISTING", "SUPPORT", "SUBJECT", "SIMILAR", "SURGEON", "CONCERT",
+    "CONSULT", "PASTIME", "LOBSTER", "COMPOST", "OBELISK", "HABITAT", "GRIFFIN", "CUSTODY",
+    "PENGUIN", "FLUTTER", "EXPLORE", "CAPABLE", "APPAREL", "CROCHET", "PAINTER", "COCONUT",
+    "WEEKEND", "LAGGARD", "BELLHOP", "ABILITY", "COLLECT", "BICYCLE", "COLLIDE", "PLATEAU",
+    "COMPARE", "DESTROY", "RESERVE", "EXPRESS", "BURNING", "ENHANCE", "TRINKET", "PRIMARY",
+    "EARTHLY", "SUGGEST", "COMBINE", "PRECISE", "PRESENT", "MINARET", "PORTION", "AUTOPSY",
+    "QUARREL", "BINDING", "HAMMOCK", "FOOTAGE", "CUPCAKE", "UPGRADE", "CONTENT", "MARRIED",
+    "TWINKLE", "COWBELL", "PASSION", "WEATHER", "THIMBLE", "CURRENT", "ACCUSED", "INSIGHT",
+    "HIGHWAY", "EDITION", "CENTAUR", "SUPPOSE", "AIRLINE", "FORWARD", "FREIGHT", "FORTUNE",
+    "SPATULA", "DRESSER", "CENTRAL", "YARDARM", "FORMULA", "PERFORM", "HEIRESS", "OUTDOOR",
+    "PROTEIN", "LATTICE", "INCLUDE", "ACROBAT", "RECEIPT", "COURAGE", "IMPROVE", "WRITTEN",
+    "PRINTER", "REFEREE", "DIGITAL", "CEILING", "PIGMENT", "POPULAR", "PREPARE", "GRAMMAR",
+    "CONVERT", "RECLUSE", "FEDERAL", "UKULELE", "SYMPTOM", "CONDUCT", "COWGIRL", "DISMISS",
+    "OPINION", "SHADOWY", "ANXIOUS", "NUCLEAR", "PRALINE", "PIONEER", "MANDATE", "INSTANT",
+    "REVENUE", "HEADSET", "CALCIUM", "FERTILE", "LIGHTER", "KEYNOTE", "MUFFLER", "BRAVERY",
+    "BROUGHT", "GENTEEL", "JELLIED", "STATION", "HOUSING", "CHIMNEY", "CALIBER", "SERVANT",
+    "CAMPING", "SHOWMAN", "CHUCKLE", "OCTOPUS", "JASMINE", "NATURAL", "SEAFOOD", "BUFFALO",
+    "ARTISAN", "PLUMAGE", "CARTOON", "GORILLA", "BALCONY", "QUALIFY", "SURPLUS", "DEALING",
+    "NERVOUS", "PETUNIA", "DEVOTED", "VERDICT", "UNCLEAR", "TYPHOON", "WILDCAT", "CORSAGE",
+    "INTERIM", "ROMANCE", "MONSOON", "NETWORK", "TEACHER", "VINTAGE", "LAUNDRY", "BURGLAR",
+    "CELLING", "GIRAFFE", "RANCHER", "JUKEBOX", "EARNING", "SAUSAGE", "GRANITE", "LASTING",
+    "OVERALL", "SECTION", "TREMBLE", "SILENCE", "LOYALTY", "RADIANT", "READING", "WARNING",
+    "HATCHET", "THEATER", "EXPLAIN", "CUSTARD", "BRACKET", "BATHTUB", "BRIEFLY", "MACHETE",
+    "WORRIED", "CLIMATE", "PATTERN", "EMPEROR", "EMERALD", "TAPIOCA", "CANTEEN", "COLLEGE",
+    "ECONOMY", "HEATHEN", "STEAMER", "BAGPIPE", "LIBRARY", "BREADTH", "CONFIRM", "WHETHER",
+    "CULTURE", "PURPOSE", "JOURNAL", "COTTAGE", "ARSENAL", "BEDROOM", "FIFTEEN", "BENEFIT",
+    "PREMISE", "CASSOCK", "BUZZARD", "BREAKER", "WARTHOG", "DISTILL", "DRIZZLE", "FINALLY",
+    "CONTACT", "BEEHIVE", "TEMPEST", "BLOSSOM", "RADICAL", "ADMIRAL", "CHARTER", "GENUINE",
+    "CALLING", "PUBLISH", "REGULAR", "CHANNEL", "AVOCADO", "FANTASY", "TRAGEDY", "BLESSED",
+    "CARAMEL", "PARKING", "CIRCUIT", "WEDDING", "SUNROOF", "CAPTAIN", "BENEATH", "FIREMAN",
+    "PREDICT", "DANCING", "MEDICAL",
+];
+
+static FOUR_GUESSES: [&str; 2096] = [
     "DELL", "ZEAL", "LIEN", "ROVE", "BUNT", "YACK", "PUTS", "GILT", "HOES", "DIRT", "PSST", "GENE",
     "YEAR", "HEAR", "YIPE", "DOCK", "HACK", "NADA", "VOWS", "LAYS", "COVE", "FANG", "HECK", "SKEW",
     "MAYO", "BREW", "VISE", "PUNY", "BUNS", "KELP", "HIDE", "MIST", "DOJO", "BARB", "GAWK", "SKIT",
@@ -186,7 +835,7 @@ pub const FOUR: [&str; 2096] = [
     "GLOW", "SEER", "ECHO", "FINK", "GUMS", "SHOW", "VEEP", "LAVA",
 ];
 
-pub const FIVE: [&str; 2312] = [
+static FIVE_GUESSES: [&str; 4980] = [
     "RASPY", "JELLY", "CRIMP", "SPOKE", "LOCUS", "BASIL", "APART", "SCALD", "PAGAN", "FINCH",
     "MONEY", "TAPIR", "PENAL", "AGATE", "EVERY", "BUNCH", "BOXER", "MOIST", "RAYON", "FAIRY",
     "SPELL", "COURT", "HUNKY", "MANGA", "HALVE", "WORSE", "SMACK", "UNCUT", "AGILE", "BREAD",
@@ -418,10 +1067,276 @@ pub const FIVE: [&str; 2312] = [
     "SKATE", "NADIR", "MOURN", "CHAFF", "TRACK", "FAINT", "SWELL", "UNFIT", "SWEAT", "MIDST",
     "FELON", "FORTY", "SHALL", "SNORE", "FLICK", "REARM", "FLIRT", "FLOOR", "BIRCH", "SCANT",
     "MOODY", "ALLOW", "LATER", "GOURD", "CHASE", "KINKY", "ANODE", "GRACE", "BLEEP", "MOGUL",
-    "LOOKS", "GOODS",
+    "LOOKS", "GOODS", "CHIMP", "MIXER", "HIKER", "BIKER", "PROMO", "COSMO", "SANTA", "YOKEL",
+    "EBOOK", "WACKO", "GIZMO", "BIMBO", "AMIGO", "PAGER", "NACHO", "DROID", "ROMAN", "WIPER",
+    "MOMMY", "LASER", "LONER", "LIFER", "QUINT", "SABER", "LITER", "GLUTE", "MOMMA", "SHRED",
+    "PANDA", "GRUMP", "OODLE", "ABACI", "ABAFT", "ABASH", "ABEAM", "ABETS", "ABLER", "ABUTS",
+    "ABUZZ", "ACHED", "ACHES", "ACHOO", "ACIDS", "ACING", "ACMES", "ACRES", "ACTED", "ADDED",
+    "ADDER", "ADDLE", "ADIEU", "ADMAN", "ADMEN", "ADMIX", "ADZES", "AEGIS", "AEONS", "AERIE",
+    "AGAVE", "AGISM", "AIDED", "AIDES", "AILED", "AIMED", "AIRED", "AITCH", "ALACK", "ALDER",
+    "ALGAL", "ALIAS", "ALINE", "ALKYD", "ALOES", "ALOHA", "ALTHO", "ALTOS", "ALUMS", "AMBIT",
+    "AMEBA", "AMEER", "AMIDE", "AMINE", "AMINO", "AMIRS", "AMOUR", "AMPUL", "AMUCK", "ANENT",
+    "ANION", "ANISE", "ANKHS", "ANONS", "ANTED", "ANTES", "ANTIS", "APACE", "APISH", "APPAL",
+    "APSES", "APTER", "AQUAE", "AQUAS", "ARCED", "AREAL", "AREAS", "ARGON", "ARGOT", "ARIAS",
+    "ARMED", "ARRAS", "ASHED", "ASHES", "ASKED", "ASPEN", "ASPIC", "ASSES", "ASTER", "ASTIR",
+    "ATILT", "ATLAS", "ATOMS", "ATRIA", "ATTAR", "AUGER", "AUGHT", "AUNTS", "AURAE", "AURAL",
+    "AURAS", "AUTOS", "AUXIN", "AVAST", "AVERS", "AVOWS", "AWING", "AXING", "AXLES", "AXONS",
+    "BAAED", "BABEL", "BABES", "BACKS", "BAILS", "BAIRN", "BAITS", "BAIZE", "BAKED", "BAKES",
+    "BALDS", "BALDY", "BALED", "BALES", "BALKS", "BALKY", "BALLS", "BALLY", "BALMS", "BALSA",
+    "BANDS", "BANDY", "BANES", "BANGS", "BANKS", "BANNS", "BARBS", "BARDS", "BARED", "BARER",
+    "BARES", "BARFS", "BARKS", "BARMY", "BARNS", "BASED", "BASER", "BASES", "BASKS", "BASSI",
+    "BASSO", "BATED", "BATES", "BATHS", "BATIK", "BAUDS", "BAWLS", "BAYED", "BEADS", "BEAKS",
+    "BEAMS", "BEANS", "BEARS", "BEATS", "BEAUS", "BEAUX", "BEBOP", "BECKS", "BEDIM", "BEEFS",
+    "BEEPS", "BEERS", "BEERY", "BEETS", "BEFOG", "BEGOT", "BEGUM", "BEIGE", "BELAY", "BELLS",
+    "BELTS", "BENDS", "BENDY", "BENTS", "BERGS", "BERMS", "BERYL", "BESOM", "BESOT", "BESTS",
+    "BETAS", "BIDED", "BIDES", "BIDET", "BIERS", "BIGHT", "BIJOU", "BIKED", "BIKES", "BILKS",
+    "BILLS", "BINDS", "BIPED", "BIRDS", "BITCH", "BITER", "BITES", "BLABS", "BLASE", "BLEAR",
+    "BLENT", "BLEST", "BLIPS", "BLOBS", "BLOCS", "BLOGS", "BLOOP", "BLOTS", "BLOWS", "BLOWY",
+    "BLUED", "BLUES", "BLUET", "BLUEY", "BLURS", "BOARS", "BOATS", "BODED", "BODES", "BODGE",
+    "BOGEY", "BOGGY", "BOGIE", "BOGUS", "BOILS", "BOLAS", "BOLES", "BOLLS", "BOLTS", "BOLUS",
+    "BOMBS", "BONCE", "BONDS", "BONED", "BONER", "BONES", "BONGS", "BONNY", "BOOBS", "BOOED",
+    "BOOKS", "BOOMS", "BOONS", "BOORS", "BOOTS", "BORED", "BORER", "BORES", "BORON", "BOSUN",
+    "BOUTS", "BOWED", "BOWER", "BOWLS", "BOXED", "BOXES", "BOZOS", "BRACT", "BRADS", "BRAGS",
+    "BRATS", "BRAYS", "BRAZE", "BREAM", "BREVE", "BREWS", "BRIER", "BRIGS", "BRILL", "BRIMS",
+    "BRONC", "BROWS", "BRUIN", "BRUIT", "BRUSK", "BUCKS", "BUFFS", "BULBS", "BULGY", "BULKS",
+    "BULLS", "BUMPS", "BUMPY", "BUNGS", "BUNKS", "BUNTS", "BUOYS", "BURGH", "BURGS", "BURKA",
+    "BURNS", "BURPS", "BURRO", "BURRS", "BURSA", "BUSBY", "BUSES", "BUSTS", "BUTTS", "BUTTY",
+    "BYTES", "BYWAY", "CADGE", "CADRE", "CAGED", "CAGES", "CAKED", "CAKES", "CALFS", "CALIF",
+    "CALKS", "CALLS", "CALMS", "CALVE", "CALYX", "CAMPS", "CAMPY", "CANED", "CANER", "CANES",
+    "CANTO", "CANTS", "CAPED", "CAPES", "CAPON", "CARDS", "CARED", "CARER", "CARES", "CARET",
+    "CAROB", "CAROM", "CARPI", "CARPS", "CARTS", "CASED", "CASES", "CASKS", "CASTS", "CAVED",
+    "CAVES", "CAWED", "CEDED", "CEDER", "CEDES", "CELLI", "CELLS", "CENTS", "CHAPS", "CHAPT",
+    "CHARS", "CHARY", "CHATS", "CHEEP", "CHEFS", "CHERT", "CHEWS", "CHEWY", "CHILE", "CHINE",
+    "CHINO", "CHINS", "CHIPS", "CHITS", "CHIVE", "CHOMP", "CHOPS", "CHOWS", "CHUGS", "CHUMS",
+    "CHURL", "CHYME", "CILIA", "CIRRI", "CITED", "CITES", "CIVET", "CLAMS", "CLANS", "CLAPS",
+    "CLAWS", "CLEFS", "CLEWS", "CLIME", "CLIPS", "CLIPT", "CLODS", "CLOGS", "CLOMP", "CLOPS",
+    "CLOTS", "CLOYS", "CLUBS", "CLUES", "CLUNK", "COALS", "COATS", "COCCI", "COCKS", "COCKY",
+    "CODAS", "CODED", "CODER", "CODES", "CODEX", "CODON", "COEDS", "COIFS", "COILS", "COINS",
+    "COKED", "COKES", "COLAS", "COLDS", "COLIC", "COLTS", "COMAS", "COMBO", "COMBS", "COMER",
+    "COMES", "COMPO", "CONES", "CONGA", "CONKS", "COOED", "COOKS", "COOKY", "COOLS", "COOPS",
+    "COOTS", "COPED", "COPES", "COPRA", "CORDS", "CORED", "CORES", "CORGI", "CORKS", "CORMS",
+    "CORNS", "CORPS", "COSTS", "COTES", "COUPS", "COVES", "COVID", "COWED", "COWLS", "COYER",
+    "COYPU", "COZEN", "CRABS", "CRAGS", "CRAMS", "CRAPE", "CRAPS", "CRAWS", "CREEL", "CREWS",
+    "CRIBS", "CRIES", "CROCI", "CROFT", "CROON", "CROPS", "CROWS", "CRUET", "CRUSE", "CUBBY",
+    "CUBED", "CUBER", "CUBES", "CUBIT", "CUFFS", "CUING", "CULLS", "CULTS", "CUNTS", "CURBS",
+    "CURDS", "CURED", "CURER", "CURES", "CURIE", "CURLS", "CURST", "CUSHY", "CUSPS", "CUTER",
+    "CUTUP", "CYSTS", "CZARS", "DACHA", "DADOS", "DAFFY", "DALES", "DAMES", "DAMNS", "DAMPS",
+    "DARED", "DARER", "DARES", "DARNS", "DARTS", "DATED", "DATER", "DATES", "DAUBS", "DAVIT",
+    "DAWNS", "DAZED", "DAZES", "DEALS", "DEANS", "DEARS", "DEARY", "DEBTS", "DECAF", "DECAP",
+    "DECKS", "DEEDS", "DEEMS", "DEEPS", "DEERS", "DEICE", "DEIFY", "DEISM", "DEIST", "DELFT",
+    "DELIS", "DELLS", "DEMOS", "DENTS", "DESKS", "DHOTI", "DIALS", "DICED", "DICES", "DICKS",
+    "DICKY", "DICTA", "DIDST", "DIETS", "DIKED", "DIKES", "DILDO", "DILLS", "DIMER", "DIMES",
+    "DINAR", "DINED", "DINES", "DINGS", "DINKY", "DIRER", "DIRKS", "DISCS", "DISKS", "DIVAN",
+    "DIVAS", "DIVED", "DIVES", "DIVOT", "DIVVY", "DJINN", "DOCKS", "DODOS", "DOERS", "DOFFS",
+    "DOGGY", "DOGIE", "DOILY", "DOLED", "DOLES", "DOLLS", "DOLOR", "DOLTS", "DOMED", "DOMES",
+    "DOOMS", "DOORS", "DOPED", "DOPER", "DOPES", "DORKS", "DORKY", "DORMS", "DOSED", "DOSES",
+    "DOTED", "DOTER", "DOTES", "DOTTY", "DOUSE", "DOVES", "DOWER", "DOWNS", "DOWSE", "DOYEN",
+    "DOZED", "DOZES", "DRABS", "DRAGS", "DRAMS", "DRAWS", "DRAYS", "DREAR", "DREGS", "DRIES",
+    "DRILY", "DRIPS", "DROPS", "DRUBS", "DRUGS", "DRUMS", "DRUPE", "DRYAD", "DUCAL", "DUCAT",
+    "DUCKS", "DUCTS", "DUDED", "DUDES", "DUELS", "DUETS", "DUKES", "DULLS", "DUMPS", "DUNES",
+    "DUNGS", "DUNKS", "DUNNO", "DUPED", "DUPER", "DUPES", "DUPLE", "DURST", "DUSTS", "DWEEB",
+    "DYERS", "EARLS", "EARNS", "EASED", "EASES", "EAVES", "EBBED", "ECHOS", "EDEMA", "EDGED",
+    "EDGER", "EDGES", "EDITS", "EDUCE", "EGGED", "EIDER", "ELAND", "ELVER", "ELVES", "EMEND",
+    "EMERY", "EMIRS", "EMITS", "EMOTE", "ENDED", "ENDUE", "ENROL", "ENURE", "EPICS", "ERGOT",
+    "ERRED", "ERUCT", "ETHYL", "EUROS", "EVENS", "EVILS", "EWERS", "EXAMS", "EXECS", "EXITS",
+    "EXPOS", "EXUDE", "EYRIE", "FACED", "FACES", "FACTS", "FADDY", "FADED", "FADES", "FAILS",
+    "FAIRS", "FAKED", "FAKER", "FAKES", "FAKIR", "FALLS", "FAMED", "FANGS", "FARAD", "FARED",
+    "FARES", "FARMS", "FARTS", "FASTS", "FATED", "FATES", "FAUNS", "FAWNS", "FAXED", "FAXES",
+    "FAZED", "FAZES", "FEARS", "FEATS", "FECES", "FEEDS", "FEELS", "FEINT", "FELLS", "FELTS",
+    "FENDS", "FERNS", "FERNY", "FESTS", "FETED", "FEUDS", "FEZES", "FIATS", "FICHE", "FICHU",
+    "FIEFS", "FIFER", "FIFES", "FILCH", "FILED", "FILES", "FILLS", "FILMS", "FINDS", "FINED",
+    "FINES", "FINIS", "FINKS", "FINNY", "FIORD", "FIRED", "FIRER", "FIRES", "FIRMS", "FIRTH",
+    "FISTS", "FITLY", "FIVER", "FIVES", "FIXED", "FIXES", "FLAGS", "FLAPS", "FLATS", "FLAWS",
+    "FLAYS", "FLEAS", "FLEES", "FLIED", "FLIES", "FLIPS", "FLITS", "FLOES", "FLOGS", "FLOPS",
+    "FLOWS", "FLUBS", "FLUES", "FLUKY", "FLYBY", "FOALS", "FOAMS", "FOGEY", "FOILS", "FOLDS",
+    "FOLKS", "FONDU", "FONTS", "FOODS", "FOOLS", "FOOTS", "FOOTY", "FORDS", "FORES", "FORKS",
+    "FORMS", "FORTS", "FOSSA", "FOULS", "FOUNT", "FOURS", "FOWLS", "FOXED", "FOXES", "FRACK",
+    "FRANC", "FRATS", "FRAYS", "FREES", "FRETS", "FRIER", "FRIES", "FRIZZ", "FROGS", "FROSH",
+    "FRUMP", "FRYER", "FUCKS", "FUELS", "FUGAL", "FUGGY", "FULLS", "FUMED", "FUMES", "FUNDS",
+    "FUNKS", "FURLS", "FURZE", "FUSED", "FUSEE", "FUSES", "FUSTY", "FUTON", "FUZED", "FUZES",
+    "GABBY", "GABLE", "GAFFS", "GAGED", "GAGES", "GAINS", "GAITS", "GALAS", "GALES", "GALLS",
+    "GAMED", "GAMES", "GAMEY", "GAMIN", "GAMMY", "GANGS", "GANJA", "GAPED", "GAPES", "GARBS",
+    "GASES", "GASPS", "GATED", "GATES", "GATOR", "GAUZY", "GAWKS", "GAZED", "GAZES", "GEARS",
+    "GEEKS", "GELDS", "GELID", "GENES", "GENII", "GENTS", "GENUS", "GEODE", "GERMS", "GETUP",
+    "GIBED", "GIBES", "GIFTS", "GILDS", "GILLS", "GILTS", "GIMME", "GIMPY", "GIRDS", "GIRLS",
+    "GIRTS", "GISMO", "GIVES", "GLACE", "GLADS", "GLANS", "GLENS", "GLITZ", "GLOBS", "GLOWS",
+    "GLUED", "GLUES", "GLUEY", "GLUTS", "GNARL", "GNATS", "GNAWN", "GNAWS", "GOADS", "GOALS",
+    "GOATS", "GOFER", "GOLDS", "GOLFS", "GONGS", "GONNA", "GOOFS", "GOONS", "GOOSY", "GORED",
+    "GORES", "GORSE", "GOTTA", "GOUTY", "GOWNS", "GRABS", "GRADS", "GRAMS", "GRAYS", "GREBE",
+    "GREYS", "GRIDS", "GRIFT", "GRINS", "GRIPS", "GRIST", "GRITS", "GROAT", "GROWS", "GRUBS",
+    "GUANO", "GULAG", "GULFS", "GULLS", "GULPS", "GUNGE", "GUNNY", "GURUS", "GUSHY", "GUSTS",
+    "GUTSY", "GUTTY", "GUYED", "GYBED", "GYBES", "GYROS", "HACKS", "HACKY", "HAFTS", "HAIKU",
+    "HAILS", "HAIRS", "HAKES", "HALAL", "HALED", "HALER", "HALES", "HALLS", "HALON", "HALOS",
+    "HALTS", "HAMMY", "HANDS", "HANGS", "HANKS", "HANKY", "HARED", "HARES", "HARKS", "HARMS",
+    "HARPS", "HARTS", "HASPS", "HATED", "HATES", "HAULS", "HAVES", "HAWED", "HAWKS", "HAYED",
+    "HAZED", "HAZER", "HAZES", "HEADS", "HEALS", "HEAPS", "HEARS", "HEATS", "HEEDS", "HEELS",
+    "HEFTS", "HEIRS", "HELMS", "HELOT", "HELPS", "HELVE", "HENNA", "HERBS", "HERDS", "HEROS",
+    "HERTZ", "HEWED", "HEWER", "HEXED", "HEXES", "HICKS", "HIDED", "HIDER", "HIDES", "HIGHS",
+    "HIKED", "HIKES", "HILLS", "HILTS", "HINDS", "HINTS", "HIRED", "HIRES", "HIVED", "HIVES",
+    "HOAGY", "HOARY", "HOBOS", "HOCKS", "HOGAN", "HOICK", "HOKEY", "HOKUM", "HOLDS", "HOLED",
+    "HOLES", "HOLEY", "HOMED", "HOMES", "HOMEY", "HOMIE", "HONED", "HONES", "HONKS", "HOOCH",
+    "HOODS", "HOOEY", "HOOFS", "HOOKS", "HOOKY", "HOOPS", "HOOTS", "HOPED", "HOPES", "HORNS",
+    "HORSY", "HOSED", "HOSES", "HOSTS", "HOURI", "HOURS", "HOWLS", "HUBBY", "HUFFS", "HUFFY",
+    "HUGER", "HULAS", "HULKS", "HULLS", "HUMPS", "HUNKS", "HUNTS", "HURLS", "HURTS", "HUSKS",
+    "HYDRA", "HYING", "HYMNS", "HYPED", "HYPES", "HYPOS", "IAMBI", "IAMBS", "ICIER", "ICONS",
+    "ICTUS", "IDEAS", "IDLED", "IDLES", "IDOLS", "IDYLS", "IKONS", "ILEUM", "ILIUM", "IMAGO",
+    "IMAMS", "IMBED", "INAPT", "INDUE", "INFIX", "INFRA", "INKED", "INSET", "INURE", "IOTAS",
+    "IRKED", "IRONS", "ISLES", "ITEMS", "IVIES", "JABOT", "JACKS", "JADED", "JADES", "JAILS",
+    "JAMBS", "JAMMY", "JAPAN", "JAPED", "JAPES", "JAWED", "JEANS", "JEEPS", "JEERS", "JEHAD",
+    "JELLO", "JELLS", "JEMMY", "JENNY", "JERKS", "JESTS", "JIBED", "JIBES", "JIHAD", "JILTS",
+    "JIMMY", "JINNI", "JINNS", "JIVED", "JIVES", "JOCKS", "JOHNS", "JOINS", "JOKED", "JOKES",
+    "JOLTS", "JOULE", "JOWLS", "JOWLY", "JOYED", "JULEP", "JUMPS", "JUNCO", "JUNKS", "JUNKY",
+    "KABOB", "KAPOK", "KAPUT", "KARAT", "KAZOO", "KEBOB", "KEELS", "KEENS", "KEEPS", "KEFIR",
+    "KETCH", "KEYED", "KHANS", "KICKS", "KICKY", "KIDDO", "KIDDY", "KILLS", "KILNS", "KILOS",
+    "KILTS", "KINDA", "KINDS", "KINGS", "KINKS", "KITED", "KITES", "KIWIS", "KLUTZ", "KNEES",
+    "KNELL", "KNITS", "KNOBS", "KNOTS", "KNOWS", "KNURL", "KOOKS", "KOOKY", "KOPEK", "KRAAL",
+    "KRAUT", "KRONA", "KRONE", "KUDOS", "KUDZU", "LABIA", "LACED", "LACES", "LACKS", "LADED",
+    "LADES", "LAIRD", "LAIRS", "LAITY", "LAKES", "LAMAS", "LAMBS", "LAMED", "LAMER", "LAMES",
+    "LAMPS", "LANDS", "LANES", "LARCH", "LARDS", "LARDY", "LARGO", "LARKS", "LASTS", "LATEX",
+    "LATHS", "LAUDS", "LAWNS", "LAXER", "LAXLY", "LAZED", "LAZES", "LEADS", "LEAFS", "LEAKS",
+    "LEANS", "LEAPS", "LEEKS", "LEERS", "LEFTS", "LEGIT", "LEMMA", "LEMME", "LENDS", "LENTO",
+    "LETUP", "LEVEE", "LIARS", "LICIT", "LICKS", "LIENS", "LIFTS", "LIKED", "LIKER", "LIKES",
+    "LILTS", "LIMBS", "LIMED", "LIMES", "LIMEY", "LIMNS", "LIMOS", "LIMPS", "LINED", "LINES",
+    "LINKS", "LINTY", "LIONS", "LIPPY", "LIRAS", "LISLE", "LISPS", "LISTS", "LIVED", "LIVEN",
+    "LIVES", "LLANO", "LOADS", "LOAFS", "LOANS", "LOBAR", "LOBED", "LOBES", "LOCKS", "LOCUM",
+    "LODES", "LOFTS", "LOGES", "LOGOS", "LOINS", "LOLLS", "LOLLY", "LONGS", "LOOMS", "LOONS",
+    "LOONY", "LOOPS", "LOOTS", "LOPED", "LOPES", "LORDS", "LORIS", "LOSES", "LOTTO", "LOTUS",
+    "LOUGH", "LOUTS", "LOVED", "LOVES", "LOWED", "LOXES", "LUAUS", "LUBED", "LUBES", "LUCKS",
+    "LUCRE", "LULLS", "LUMPS", "LUNGS", "LUPIN", "LURED", "LURES", "LURKS", "LUSTS", "LUTES",
+    "LYNCH", "LYRES", "MACED", "MACES", "MAIDS", "MAILS", "MAIMS", "MAINS", "MAKES", "MALES",
+    "MALLS", "MALTS", "MALTY", "MAMAS", "MAMBA", "MANES", "MANNA", "MANSE", "MANTA", "MARES",
+    "MARGE", "MARIA", "MARKS", "MARTS", "MASKS", "MASTS", "MATED", "MATES", "MATTE", "MATTS",
+    "MATZO", "MAULS", "MAVEN", "MAVIN", "MAXED", "MAXES", "MAZES", "MEALS", "MEANS", "MEATS",
+    "MEETS", "MELDS", "MELTS", "MEMOS", "MENDS", "MENUS", "MEOWS", "MERCH", "MERES", "MESAS",
+    "MESON", "MESSY", "METED", "METES", "MEWED", "MEWLS", "MEZZO", "MIAOW", "MICRA", "MIDDY",
+    "MIENS", "MIFFS", "MIKED", "MIKES", "MILCH", "MILER", "MILES", "MILKS", "MILLS", "MIMED",
+    "MIMES", "MINDS", "MINED", "MINES", "MINGY", "MINIS", "MINKS", "MINTS", "MIRED", "MIRES",
+    "MISDO", "MISTS", "MISTY", "MITER", "MITES", "MITRE", "MITTS", "MIXED", "MIXES", "MOANS",
+    "MOATS", "MOCKS", "MODES", "MOGGY", "MOIRE", "MOLDS", "MOLES", "MOLLS", "MOLLY", "MOLTS",
+    "MONAD", "MONKS", "MOOCH", "MOODS", "MOOED", "MOONS", "MOORS", "MOOTS", "MOPED", "MOPER",
+    "MOPES", "MORAY", "MOREL", "MORES", "MORNS", "MOSEY", "MOTES", "MOTET", "MOTHS", "MOULD",
+    "MOUSY", "MOVED", "MOVES", "MOWED", "MUCKS", "MUFFS", "MUFTI", "MUGGY", "MULCT", "MULES",
+    "MULLS", "MUMPS", "MURKS", "MUSED", "MUSES", "MUSSY", "MUSTS", "MUTED", "MUTER", "MUTES",
+    "MUTTS", "MUZZY", "MYNAH", "MYNAS", "MYTHS", "NABOB", "NACRE", "NAIAD", "NAILS", "NAKED",
+    "NAMED", "NAMES", "NAPES", "NAPPY", "NARCS", "NARKS", "NARKY", "NATCH", "NATTY", "NAVES",
+    "NAVVY", "NEARS", "NEATH", "NECKS", "NEEDS", "NERDS", "NERVY", "NESTS", "NEVUS", "NEWEL",
+    "NEWSY", "NEWTS", "NEXUS", "NICKS", "NIFTY", "NIMBI", "NINES", "NIPPY", "NISEI", "NITER",
+    "NITES", "NITRO", "NIXED", "NIXES", "NODAL", "NODDY", "NODES", "NOELS", "NOHOW", "NONCE",
+    "NOOKS", "NOOKY", "NORMS", "NOSED", "NOSES", "NOTED", "NOTES", "NOUNS", "NOVAE", "NOVAS",
+    "NOWAY", "NUBBY", "NUDER", "NUDES", "NUKED", "NUKES", "NULLS", "NUMBS", "OAKUM", "OARED",
+    "OASES", "OASIS", "OATEN", "OATHS", "OBEYS", "OBITS", "OBOES", "OCHER", "OCHRE", "ODIUM",
+    "ODORS", "OECUS", "OFFED", "OGLED", "OGLER", "OGLES", "OGRES", "OILED", "OILER", "OINKS",
+    "OKAPI", "OKAYS", "OKRAS", "OLDIE", "OMENS", "OMITS", "OOZED", "OOZES", "OPALS", "OPENS",
+    "OPTED", "ORALS", "ORATE", "ORIEL", "ORRIS", "OSIER", "OUSTS", "OUTED", "OUTRE", "OVALS",
+    "OVENS", "OVERS", "OVULE", "OWLET", "OWNED", "OXBOW", "PACED", "PACER", "PACES", "PACKS",
+    "PACTS", "PADRE", "PAEAN", "PAGED", "PAGES", "PAILS", "PAINS", "PAIRS", "PALED", "PALES",
+    "PALLS", "PALMS", "PALMY", "PANES", "PANGS", "PANTO", "PANTS", "PANTY", "PAPAS", "PAPAW",
+    "PAPPY", "PARAM", "PARCH", "PARED", "PAREN", "PARES", "PARKS", "PARKY", "PARTS", "PASHA",
+    "PASSE", "PASTS", "PATES", "PATHS", "PAVED", "PAVES", "PAWED", "PAWLS", "PAWNS", "PAYED",
+    "PEAKS", "PEAKY", "PEALS", "PEARS", "PEASE", "PEATY", "PECKS", "PEEKS", "PEELS", "PEEPS",
+    "PEERS", "PEEVE", "PEKOE", "PELTS", "PENDS", "PENES", "PENIS", "PEONS", "PEONY", "PEPPY",
+    "PERKS", "PERMS", "PESOS", "PESTS", "PETER", "PEWEE", "PEWIT", "PHAGE", "PHIAL", "PHISH",
+    "PHLOX", "PHYLA", "PICKS", "PICOT", "PIERS", "PIEZO", "PIGMY", "PIING", "PIKED", "PIKER",
+    "PIKES", "PILAF", "PILAU", "PILAW", "PILED", "PILES", "PILLS", "PIMPS", "PINED", "PINES",
+    "PINGS", "PINKS", "PINNY", "PINON", "PINTS", "PINUP", "PIOUS", "PIPED", "PIPES", "PIPIT",
+    "PITON", "PLANS", "PLASH", "PLATY", "PLAYS", "PLEAS", "PLEBE", "PLIES", "PLODS", "PLOPS",
+    "PLOTS", "PLOWS", "PLOYS", "PLUGS", "PLUMS", "PLUMY", "POACH", "POCKS", "PODIA", "POEMS",
+    "POETS", "POKED", "POKES", "POKEY", "POLED", "POLES", "POLIO", "POLLS", "POMMY", "PONCE",
+    "PONDS", "PONES", "POOHS", "POOLS", "POOPS", "POPES", "POPPA", "PORED", "PORES", "PORGY",
+    "PORKY", "PORNO", "PORTS", "POSED", "POSES", "POSTS", "POTTY", "POURS", "POUTS", "POXES",
+    "PRATE", "PRAYS", "PREPS", "PREYS", "PRICY", "PRIER", "PRIES", "PRIGS", "PRIMP", "PRION",
+    "PRODS", "PROFS", "PROMS", "PROPS", "PROSY", "PROWS", "PSHAW", "PSYCH", "PUBES", "PUBIS",
+    "PUCKS", "PUFFS", "PUKED", "PUKES", "PULLS", "PULPS", "PUMAS", "PUNKS", "PUNTS", "PUPAE",
+    "PUPAL", "PUPAS", "PURLS", "PURRS", "PUSSY", "PUTTS", "PYLON", "PYRES", "PYXES", "QUADS",
+    "QUAFF", "QUAKY", "QUANT", "QUAYS", "QUIDS", "QUIFF", "QUIPS", "QUIRE", "QUIRT", "QUITS",
+    "QUOIN", "QUOIT", "RACED", "RACES", "RACKS", "RADIX", "RADON", "RAFTS", "RAGAS", "RAGED",
+    "RAGES", "RAIDS", "RAILS", "RAINS", "RAJAS", "RAKED", "RAKES", "RAMIE", "RAMPS", "RANGY",
+    "RANKS", "RANTS", "RAPED", "RAPER", "RAPES", "RARED", "RARES", "RASPS", "RATED", "RATER",
+    "RATES", "RAVED", "RAVEL", "RAVES", "RAWER", "RAZED", "RAZES", "READS", "REALS", "REAMS",
+    "REAPS", "REARS", "REBID", "REBOX", "RECCE", "RECON", "RECTA", "RECTO", "REDID", "REDYE",
+    "REEDS", "REEFS", "REEKS", "REELS", "REEVE", "REIFY", "REINS", "REMIX", "RENDS", "RENTS",
+    "RERAN", "RESTS", "RHEAS", "RHEUM", "RICED", "RICER", "RICES", "RICKS", "RIDES", "RIDGY",
+    "RIFER", "RIFFS", "RIFTS", "RILED", "RILES", "RILLS", "RIMED", "RIMES", "RINDS", "RINGS",
+    "RINKS", "RIOTS", "RISES", "RISKS", "RITES", "RITZY", "RIVEN", "RIYAL", "ROADS", "ROAMS",
+    "ROANS", "ROARS", "ROBED", "ROBES", "ROCKS", "ROILS", "ROLES", "ROLLS", "ROMPS", "RONDO",
+    "ROODS", "ROOFS", "ROOKS", "ROOMS", "ROOTS", "ROPED", "ROPER", "ROPES", "ROSES", "ROSIN",
+    "ROUST", "ROUTS", "ROVED", "ROVES", "ROWAN", "ROWED", "ROWEL", "RUBES", "RUBLE", "RUFFS",
+    "RUING", "RUINS", "RULED", "RULES", "RUMMY", "RUMPS", "RUNES", "RUNGS", "RUNIC", "RUNNY",
+    "RUNTS", "RUNTY", "RUSES", "RUSHY", "RUSKS", "RUSTS", "RUTTY", "SABLE", "SABOT", "SABRA",
+    "SABRE", "SACKS", "SACRA", "SADES", "SADHU", "SAFES", "SAGAS", "SAGER", "SAGES", "SAGGY",
+    "SAHIB", "SAILS", "SAITH", "SALES", "SALTS", "SAMBA", "SAMES", "SANDS", "SANGS", "SAREE",
+    "SARIS", "SATED", "SATES", "SAVED", "SAVER", "SAVES", "SAWED", "SAXES", "SCABS", "SCADS",
+    "SCAMS", "SCANS", "SCARP", "SCARS", "SCATS", "SCHWA", "SCOOT", "SCOWS", "SCRAG", "SCRIM",
+    "SCRIP", "SCROD", "SCROG", "SCUDS", "SCUFF", "SCULL", "SCUMS", "SCURF", "SEALS", "SEAMS",
+    "SEAMY", "SEARS", "SEATS", "SEBUM", "SECTS", "SEDGE", "SEDGY", "SEEDS", "SEEKS", "SEEMS",
+    "SEEPS", "SEERS", "SEINE", "SELLS", "SEMIS", "SENDS", "SENNA", "SEPAL", "SEPTA", "SERER",
+    "SERFS", "SERGE", "SERVO", "SEWED", "SEXED", "SEXES", "SHADS", "SHAGS", "SHAHS", "SHAMS",
+    "SHEAF", "SHEDS", "SHERD", "SHIES", "SHILL", "SHIMS", "SHINS", "SHIPS", "SHIRR", "SHITS",
+    "SHLEP", "SHOAT", "SHOED", "SHOES", "SHOON", "SHOOS", "SHOPS", "SHOTS", "SHOWS", "SHTIK",
+    "SHUNS", "SHUTS", "SHYER", "SIBYL", "SICKS", "SIDED", "SIDES", "SIDLE", "SIFTS", "SIGHS",
+    "SIGIL", "SIGNS", "SILKS", "SILLS", "SILOS", "SILTS", "SILTY", "SINGS", "SINKS", "SINUS",
+    "SIRED", "SIRES", "SIRUP", "SISAL", "SISES", "SITAR", "SITED", "SITES", "SIXES", "SIZED",
+    "SIZER", "SIZES", "SKEET", "SKEIN", "SKEWS", "SKIDS", "SKIED", "SKIES", "SKIMS", "SKINS",
+    "SKIPS", "SKITS", "SKIVE", "SKOAL", "SKYED", "SLABS", "SLAGS", "SLAKE", "SLAMS", "SLAPS",
+    "SLATS", "SLAVE", "SLAYS", "SLEDS", "SLEWS", "SLIER", "SLILY", "SLIMS", "SLIPS", "SLITS",
+    "SLOBS", "SLOES", "SLOGS", "SLOPS", "SLOTS", "SLOWS", "SLUED", "SLUES", "SLUGS", "SLUMS",
+    "SLURS", "SLUTS", "SLYER", "SMUTS", "SNAFU", "SNAGS", "SNAPS", "SNARK", "SNICK", "SNIPS",
+    "SNITS", "SNOBS", "SNOOD", "SNOOT", "SNOTS", "SNOWS", "SNUBS", "SNUGS", "SOAKS", "SOAPS",
+    "SOARS", "SOCKS", "SODAS", "SOFAS", "SOFTY", "SOILS", "SOLED", "SOLES", "SOLOS", "SONGS",
+    "SONNY", "SOPPY", "SORER", "SORES", "SORTA", "SORTS", "SOUGH", "SOULS", "SOUPS", "SOUPY",
+    "SOURS", "SOUSE", "SOWED", "SPACY", "SPAKE", "SPANS", "SPARS", "SPATE", "SPATS", "SPAYS",
+    "SPECS", "SPEWS", "SPIES", "SPIFF", "SPINS", "SPIRY", "SPITS", "SPLAY", "SPOOR", "SPOTS",
+    "SPRAT", "SPUDS", "SPUME", "SPUMY", "SPURS", "SPUTA", "SQUAB", "SQUAW", "SQUID", "STABS",
+    "STAGS", "STAGY", "STAPH", "STARS", "STATS", "STAYS", "STEMS", "STENO", "STENT", "STEPS",
+    "STEWS", "STIES", "STILE", "STIRS", "STOAT", "STOPS", "STOUP", "STOWS", "STREP", "STREW",
+    "STRIA", "STROP", "STRUM", "STUBS", "STUDS", "STUNS", "STUPA", "STYES", "STYLI", "SUCKS",
+    "SUDSY", "SUEDE", "SUETY", "SUITS", "SULFA", "SULKS", "SUMPS", "SUNUP", "SURFS", "SWABS",
+    "SWAGS", "SWAIN", "SWANK", "SWANS", "SWAPS", "SWARD", "SWARF", "SWATS", "SWAYS", "SWIGS",
+    "SWIMS", "SWIPE", "SWOPS", "SYLPH", "SYNCH", "SYNCS", "TABLA", "TABOR", "TABUS", "TACKS",
+    "TACOS", "TAIGA", "TAILS", "TAKES", "TALES", "TALKS", "TALKY", "TALUS", "TAMED", "TAMES",
+    "TAMPS", "TANGS", "TANKS", "TANSY", "TAPAS", "TAPED", "TAPES", "TARED", "TARES", "TAROS",
+    "TARPS", "TARRY", "TARSI", "TARTS", "TASKS", "TATER", "TAUPE", "TAXED", "TAXER", "TAXES",
+    "TAXIS", "TAXON", "TEAKS", "TEALS", "TEAMS", "TEARS", "TEATS", "TECHS", "TEEMS", "TEENS",
+    "TEENY", "TELEX", "TELLS", "TEMPI", "TEMPS", "TEMPT", "TENCH", "TENDS", "TENON", "TENTS",
+    "TERMS", "TERNS", "TERRY", "TESTS", "TETRA", "TEXTS", "THANE", "THAWS", "THEES", "THERM",
+    "THINE", "THINS", "THOLE", "THOUS", "THROE", "THUDS", "THUGS", "THYMI", "TICKS", "TIDED",
+    "TIDES", "TIERS", "TIFFS", "TIKES", "TILED", "TILER", "TILES", "TILLS", "TILTS", "TIMED",
+    "TIMES", "TINES", "TINGE", "TINGS", "TINNY", "TINTS", "TIPIS", "TIRED", "TIRES", "TIROS",
+    "TITTY", "TIZZY", "TOADS", "TOADY", "TOFFY", "TOGAE", "TOGAS", "TOILS", "TOKED", "TOKES",
+    "TOLLS", "TOMBS", "TOMES", "TONED", "TONER", "TONES", "TONGS", "TONNE", "TOOLS", "TOOTS",
+    "TOPEE", "TOQUE", "TORSI", "TORTE", "TORTS", "TOTED", "TOTES", "TOURS", "TOUTS", "TOWED",
+    "TOWNS", "TOYED", "TRAMS", "TRAPS", "TRAYS", "TREED", "TREES", "TREKS", "TRESS", "TREWS",
+    "TRIER", "TRIES", "TRIKE", "TRILL", "TRIMS", "TRIOS", "TRIPS", "TROMP", "TROTH", "TROTS",
+    "TROYS", "TRUED", "TRUES", "TSARS", "TUBAS", "TUBBY", "TUBED", "TUBES", "TUCKS", "TUFTS",
+    "TUMID", "TUMMY", "TUNAS", "TUNED", "TUNER", "TUNES", "TUNNY", "TUQUE", "TURDS", "TURFS",
+    "TURFY", "TURNS", "TURPS", "TUSKS", "TUTTI", "TUTUS", "TUXES", "TWAIN", "TWEEN", "TWERP",
+    "TWIGS", "TWILL", "TWINK", "TWINS", "TWITS", "TYKES", "TYPED", "TYPES", "TYPOS", "TYROS",
+    "TZARS", "UKASE", "ULNAE", "ULNAR", "ULNAS", "UMBEL", "UMBER", "UMIAK", "UMPED", "UNARY",
+    "UNBAR", "UNBID", "UNBOX", "UNCAP", "UNFIX", "UNITS", "UNMAN", "UNPIN", "UNSAY", "UNSEE",
+    "UNTAR", "UPEND", "UPPED", "URGED", "URGES", "USERS", "USURY", "UTERI", "UVULA", "VACUA",
+    "VAGUS", "VALES", "VAMPS", "VANES", "VASES", "VASTS", "VEEPS", "VEERS", "VEILS", "VEINS",
+    "VELAR", "VELDS", "VELDT", "VELUM", "VENAL", "VENDS", "VENTS", "VERBS", "VESTS", "VETCH",
+    "VEXED", "VEXES", "VIALS", "VIAND", "VIBES", "VICED", "VICES", "VIEWS", "VILER", "VINES",
+    "VIOLS", "VIREO", "VISAS", "VISED", "VISES", "VIVAS", "VIZOR", "VOIDS", "VOILE", "VOLES",
+    "VOLTS", "VOTED", "VOTES", "VOWED", "VULVA", "WADED", "WADER", "WADES", "WADIS", "WAFTS",
+    "WAGED", "WAGES", "WAIFS", "WAILS", "WAITS", "WAKED", "WAKEN", "WAKES", "WALED", "WALES",
+    "WALKS", "WALLS", "WALLY", "WANDS", "WANED", "WANES", "WANLY", "WANNA", "WANTS", "WARDS",
+    "WARES", "WARMS", "WARNS", "WARPS", "WARTS", "WASHY", "WASPS", "WATTS", "WAVED", "WAVES",
+    "WAXED", "WAXES", "WEALS", "WEANS", "WEARS", "WEEDS", "WEEKS", "WEENY", "WEEPS", "WEEPY",
+    "WEEST", "WEFTS", "WEIRS", "WELDS", "WELLS", "WELLY", "WELTS", "WENCH", "WENDS", "WETLY",
+    "WHAMS", "WHATS", "WHEAL", "WHELK", "WHELM", "WHENS", "WHETS", "WHIMS", "WHIPS", "WHIRR",
+    "WHIRS", "WHIST", "WHITS", "WHIZZ", "WHORE", "WHORL", "WICKS", "WIKIS", "WILDS", "WILED",
+    "WILES", "WILLS", "WILTS", "WIMPS", "WINDS", "WINED", "WINES", "WINGS", "WINKS", "WINOS",
+    "WIPED", "WIPES", "WIRED", "WIRES", "WISES", "WISPS", "WITHE", "WIVES", "WIZES", "WODGE",
+    "WOLFS", "WOMBS", "WONKY", "WOODS", "WOOED", "WOOFS", "WORDS", "WORKS", "WORMS", "WORMY",
+    "WOWED", "WRAPS", "WRAPT", "WRENS", "WRIER", "WRITS", "WROTH", "WRYER", "XENON", "XYLEM",
+    "YACKS", "YAHOO", "YANKS", "YARDS", "YARNS", "YAWED", "YAWLS", "YAWNS", "YEAHS", "YEARS",
+    "YELLS", "YELPS", "YESES", "YOCKS", "YODEL", "YOGIN", "YOGIS", "YOKED", "YOKES", "YOLKS",
+    "YOURS", "YOWIE", "YOWLS", "YUCCA", "YUCKS", "YUCKY", "YUMMY", "YUPPY", "ZEBUS", "ZEROS",
+    "ZESTS", "ZILCH", "ZINCS", "ZINGS", "ZIPPY", "ZLOTY", "ZOMBI", "ZONED", "ZONES", "ZOOMS",
 ];
 
-pub const SIX: [&str; 5128] = [
+static SIX_GUESSES: [&str; 5128] = [
     "REALER", "BUMMED", "KLUTZY", "MANURE", "REFORM", "SLAYED", "LASHER", "SNOWED", "NOISES",
     "EFFECT", "CAHOOT", "ENDEAR", "TOYING", "HOTAIR", "FINISH", "RAISED", "INVOKE", "ARISES",
     "APPEND", "VENEER", "SILVER", "DECENT", "EVENLY", "SUBMIT", "PASTRY", "KISSER", "WAGGLE",
@@ -994,7 +1909,7 @@ pub const SIX: [&str; 5128] = [
     "NEVERS", "DIMMER", "SINNED", "IMPART", "HUMANE", "BOGART", "ISSUES",
 ];
 
-pub const SEVEN: [&str; 26842] = [
+static SEVEN_GUESSES: [&str; 26842] = [
     "ABODING", "ZELKOVA", "PULLING", "PLUCKED", "GANGLIA", "SAUCIER", "PREBEND", "PULINGS",
     "PRECUTS", "SCHOLIA", "FORGAVE", "REPRESS", "POLICES", "BARYTAS", "PRERIOT", "BIONOMY",
     "SPRAINS", "FURRILY", "ACEROSE", "RIMPLED", "SWOPPED", "CATBIRD", "ARKOSES", "FRESHET",
@@ -4360,10 +5275,28 @@ mod test {
 
     #[test]
     fn validate_words() {
-        check_words(&FOUR, 4);
-        check_words(&FIVE, 5);
-        check_words(&SIX, 6);
-        check_words(&SEVEN, 7);
+        for size in WORD_SIZES {
+            check_words(answers_for_size(size).unwrap(), size);
+            check_words(guesses_for_size(size).unwrap(), size);
+        }
+        assert!(answers_for_size(3).is_none());
+        assert!(!is_word(8, "ABSOLUTE"));
+    }
+
+    #[test]
+    fn answers_are_guesses() {
+        for size in WORD_SIZES {
+            let guesses: HashSet<&str> = guesses_for_size(size).unwrap().iter().copied().collect();
+            let invalid: Vec<&str> = answers_for_size(size)
+                .unwrap()
+                .iter()
+                .filter(|word| !guesses.contains(*word))
+                .copied()
+                .collect();
+            if !invalid.is_empty() {
+                panic!("Answers that can't be guessed: {invalid:?}")
+            }
+        }
     }
 
     fn check_words(words: &[&str], expected: usize) {
@@ -0,0 +1,106 @@
+//! Local calendar date, used to pick the daily puzzle
+
+use std::time::{SystemTime, UNIX_EPOCH};
+use wordle_engine::daily::days_from_civil;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Current local time
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct LocalTime {
+    /// Days since 1970-01-01
+    pub day: i64,
+    /// Seconds since midnight
+    pub seconds: i64,
+}
+
+/// Seconds since 1970-01-01 UTC
+pub fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+impl LocalTime {
+    pub fn now() -> Self {
+        LocalTime::at(unix_time())
+    }
+
+    /// Local time for seconds since 1970-01-01 UTC
+    pub fn at(timestamp: u64) -> Self {
+        let timestamp = timestamp as i64;
+        local_time(timestamp).unwrap_or(LocalTime {
+            day: timestamp.div_euclid(SECONDS_PER_DAY),
+            seconds: timestamp.rem_euclid(SECONDS_PER_DAY),
+        })
+    }
+
+    pub fn seconds_until_tomorrow(&self) -> i64 {
+        (SECONDS_PER_DAY - self.seconds).max(0)
+    }
+}
+
+/// Formats seconds as HH:MM:SS
+pub fn format_countdown(seconds: i64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
+}
+
+/// How long ago `day` was, e.g. `Today` or `3 days ago`
+pub fn format_days_ago(day: i64, today: i64) -> String {
+    match today - day {
+        ..=0 => String::from("Today"),
+        1 => String::from("Yesterday"),
+        days => format!("{days} days ago"),
+    }
+}
+
+#[cfg(any(unix, windows))]
+fn from_tm(tm: &libc::tm) -> LocalTime {
+    LocalTime {
+        day: days_from_civil(
+            tm.tm_year as i64 + 1900,
+            tm.tm_mon as u32 + 1,
+            tm.tm_mday as u32,
+        ),
+        seconds: (tm.tm_hour * 3600 + tm.tm_min * 60 + tm.tm_sec.min(59)) as i64,
+    }
+}
+
+#[cfg(unix)]
+fn local_time(timestamp: i64) -> Option<LocalTime> {
+    let time = timestamp as libc::time_t;
+    // SAFETY: tm is plain data and both pointers are valid for the call
+    unsafe {
+        let mut tm: libc::tm = std::mem::zeroed();
+        if libc::localtime_r(&time, &mut tm).is_null() {
+            None
+        } else {
+            Some(from_tm(&tm))
+        }
+    }
+}
+
+#[cfg(windows)]
+fn local_time(timestamp: i64) -> Option<LocalTime> {
+    let time = timestamp as libc::time_t;
+    // SAFETY: tm is plain data and both pointers are valid for the call
+    unsafe {
+        let mut tm: libc::tm = std::mem::zeroed();
+        if libc::localtime_s(&mut tm, &time) == 0 {
+            Some(from_tm(&tm))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn local_time(_: i64) -> Option<LocalTime> {
+    None
+}
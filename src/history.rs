@@ -0,0 +1,47 @@
+//! Every finished game, appended to a file in the prefs directory
+//!
+//! Multi board games aren't recorded, their boards share guesses so they don't fit a [Record]
+
+use crate::GameMode;
+use log::warn;
+use pixels_graphics_lib::prelude::get_pref_dir;
+use std::path::PathBuf;
+use wordle_engine::history::{append, load, GameRecord};
+
+pub const HISTORY_FILE: &str = "history.jsonl";
+
+pub type Record = GameRecord<GameMode>;
+
+fn history_path() -> Option<PathBuf> {
+    match get_pref_dir("app", "emmabritton", "wordle") {
+        Ok(mut path) => {
+            path.push(HISTORY_FILE);
+            Some(path)
+        }
+        Err(err) => {
+            warn!("Unable to find prefs dir for history: {err:?}");
+            None
+        }
+    }
+}
+
+/// Adds `record` to the history, failures are logged as the game can carry on without it
+pub fn add_game(record: &Record) {
+    let Some(path) = history_path() else {
+        return;
+    };
+    if let Err(err) = append(&path, record) {
+        warn!("Unable to write {path:?}: {err:?}");
+    }
+}
+
+/// Every game in the history that can be read, oldest first
+pub fn load_games() -> Vec<Record> {
+    let Some(path) = history_path() else {
+        return vec![];
+    };
+    load(&path).unwrap_or_else(|err| {
+        warn!("Unable to read {path:?}: {err:?}");
+        vec![]
+    })
+}
@@ -1,17 +1,31 @@
 #![windows_subsystem = "windows"]
 
-mod engine;
+mod date;
+mod history;
 mod scenes;
+mod share;
 mod ui;
-mod word_list;
 
+use crate::date::unix_time;
+use crate::scenes::analysis::AnalysisScene;
+use crate::scenes::code::CodeScene;
 use crate::scenes::game::GameScene;
 use crate::scenes::menu::MenuScene;
+use crate::scenes::modes::ModesScene;
+use crate::scenes::multi::MultiGameScene;
+use crate::scenes::replay::ReplayScene;
+use crate::scenes::stats::StatsScene;
+use crate::scenes::two_player::TwoPlayerScene;
 use anyhow::Result;
 use log::LevelFilter;
 use pixels_graphics_lib::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use wordle_engine::endless::Run;
+use wordle_engine::share::Palette;
+use wordle_engine::stats::Stats;
+use wordle_engine::time_attack::TimeAttackBest;
+use wordle_engine::{Difficulty, MultiEngine, WordOrder, WordProgress, WordleEngine};
 
 const WIDTH: usize = 260;
 const HEIGHT: usize = 300;
@@ -24,18 +38,172 @@ pub enum Input {
     Left,
     Right,
     Escape,
+    Secondary,
+    Tertiary,
+}
+
+/// How the word for a game is picked
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum GameMode {
+    /// Next word in the list for the size
+    Classic,
+    /// Same word for everyone on the same day, one attempt per day
+    Daily,
+    /// Random word, doesn't affect progress
+    Practice,
+    /// No word is picked, each guess gets the feedback that rules out the fewest words
+    Absurdle,
+    /// Random words one after another until a game is lost
+    Endless,
+    /// Random words one after another until the clock runs out
+    TimeAttack,
+    /// Word picked by another player on the same machine
+    TwoPlayer,
+    /// Word from a puzzle code, doesn't affect progress
+    Custom,
+    /// Next classic word that was failed or skipped
+    Retry,
+}
+
+impl GameMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "Classic",
+            GameMode::Daily => "Daily",
+            GameMode::Practice => "Practice",
+            GameMode::Absurdle => "Absurdle",
+            GameMode::Endless => "Endless",
+            GameMode::TimeAttack => "Time attack",
+            GameMode::TwoPlayer => "Two player",
+            GameMode::Custom => "Puzzle codes",
+            GameMode::Retry => "Retry failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DailyGame {
+    /// Days since 1970-01-01
+    pub day: i64,
+    pub engine: WordleEngine,
+    #[serde(default)]
+    pub time: PlayTime,
+}
+
+/// When a game was started and how long it's been played for, kept with unfinished games
+#[derive(Debug, Copy, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct PlayTime {
+    /// Seconds since 1970-01-01 UTC, 0 for games saved before this was kept
+    pub started: u64,
+    /// Seconds spent playing, not counting time the game was closed
+    pub seconds: f64,
+}
+
+impl PlayTime {
+    /// For a game starting now
+    pub fn now() -> Self {
+        PlayTime {
+            started: unix_time(),
+            seconds: 0.0,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Settings {
+    /// word size to number of words played from the start of the original word list,
+    /// replaced by `progress` and only read to migrate
+    #[serde(default)]
     pub word_idx: HashMap<usize, usize>,
+    /// word size to the result of each classic word
+    #[serde(default)]
+    pub progress: HashMap<usize, WordProgress>,
+    /// word size to the last word played in retry mode
+    #[serde(default)]
+    pub last_retry: HashMap<usize, String>,
+    /// used to shuffle the word lists, 0 if not set yet
+    #[serde(default)]
+    pub seed: u64,
+    /// word size to order the answers are played in
+    #[serde(default)]
+    pub word_order: HashMap<usize, WordOrder>,
+    /// word size to difficulty
+    #[serde(default)]
+    pub difficulty: HashMap<usize, Difficulty>,
+    /// word size to number of wins at each difficulty
+    #[serde(default)]
+    pub wins: HashMap<usize, HashMap<Difficulty, usize>>,
+    /// word size to number of wins where hints were used, these are also counted in `wins`
+    #[serde(default)]
+    pub hinted_wins: HashMap<usize, HashMap<Difficulty, usize>>,
+    /// word size to unfinished game
+    #[serde(default)]
+    pub saved_games: HashMap<usize, WordleEngine>,
+    /// word size to when the unfinished game was started and how long it's been played
+    #[serde(default)]
+    pub saved_game_times: HashMap<usize, PlayTime>,
+    /// word size to the latest daily puzzle, finished or not
+    #[serde(default)]
+    pub daily: HashMap<usize, DailyGame>,
+    /// show best guesses while playing
+    #[serde(default)]
+    pub show_advisor: bool,
+    /// word size to best endless run
+    #[serde(default)]
+    pub best_runs: HashMap<usize, Run>,
+    /// word size to best time attack results
+    #[serde(default)]
+    pub best_times: HashMap<usize, TimeAttackBest>,
+    /// palette used the last time a result was shared
+    #[serde(default)]
+    pub share_palette: Palette,
+    /// word size to results for each mode
+    #[serde(default)]
+    pub stats: HashMap<usize, HashMap<GameMode, Stats>>,
 }
 
 fn settings() -> AppPrefs<Settings> {
-    AppPrefs::new("app", "emmabritton", "wordle", || Settings {
+    let mut prefs = AppPrefs::new("app", "emmabritton", "wordle", || Settings {
         word_idx: HashMap::new(),
+        progress: HashMap::new(),
+        last_retry: HashMap::new(),
+        seed: 0,
+        word_order: HashMap::new(),
+        difficulty: HashMap::new(),
+        wins: HashMap::new(),
+        hinted_wins: HashMap::new(),
+        saved_games: HashMap::new(),
+        saved_game_times: HashMap::new(),
+        daily: HashMap::new(),
+        show_advisor: false,
+        best_runs: HashMap::new(),
+        best_times: HashMap::new(),
+        share_palette: Palette::default(),
+        stats: HashMap::new(),
     })
-    .expect("Unable to create prefs file")
+    .expect("Unable to create prefs file");
+    if prefs.data.seed == 0 {
+        prefs.data.seed = fastrand::u64(1..);
+        prefs.save();
+    }
+    if !prefs.data.word_idx.is_empty() {
+        migrate_word_idx(&mut prefs.data);
+        prefs.save();
+    }
+    prefs
+}
+
+/// Words played before the saved count are marked as skipped, as whether they were solved
+/// wasn't kept
+fn migrate_word_idx(settings: &mut Settings) {
+    for (word_size, played) in settings.word_idx.drain() {
+        if settings.progress.contains_key(&word_size) {
+            continue;
+        }
+        if let Some(progress) = WordProgress::from_played_count(word_size, played) {
+            settings.progress.insert(word_size, progress);
+        }
+    }
 }
 
 fn main() -> Result<()> {
@@ -55,7 +223,22 @@ fn setup_logger() {
 
 fn start_menu() -> Result<(), GraphicsError> {
     let switcher: SceneSwitcher<SceneResult, SceneName> = |_, list, name| match name {
-        SceneName::Game(word_size) => list.push(GameScene::new(word_size, settings())),
+        SceneName::Game(word_size, mode) => list.push(GameScene::new(word_size, mode, settings())),
+        SceneName::GameWith(engine, mode) => {
+            list.push(GameScene::with_engine(engine, mode, settings()))
+        }
+        SceneName::Analysis(engine) => list.push(AnalysisScene::new(engine)),
+        SceneName::Modes(word_size) => list.push(ModesScene::new(word_size, settings())),
+        SceneName::Multi(word_size, boards) => {
+            list.push(MultiGameScene::new(word_size, boards, settings()))
+        }
+        SceneName::TwoPlayer(word_size) => list.push(TwoPlayerScene::new(word_size, settings())),
+        SceneName::Codes => list.push(CodeScene::new()),
+        SceneName::Stats(word_size, mode, last_game) => {
+            list.push(StatsScene::new(word_size, mode, last_game, settings()))
+        }
+        SceneName::History => list.push(ReplayScene::new()),
+        SceneName::Puzzle(code) => list.push(GameScene::for_code(&code, settings())),
     };
 
     let menu = MenuScene::new(settings());
@@ -74,8 +257,31 @@ fn start_menu() -> Result<(), GraphicsError> {
 
 #[derive(Debug, Clone, PartialEq)]
 enum SceneName {
-    Game(usize),
+    Game(usize, GameMode),
+    /// Game for a word that's already been picked
+    GameWith(WordleEngine, GameMode),
+    /// Finished game to analyse
+    Analysis(WordleEngine),
+    /// List of other modes for the word size
+    Modes(usize),
+    /// Word size and number of boards
+    Multi(usize, usize),
+    /// Players take turns to pick a word of this size for each other
+    TwoPlayer(usize),
+    /// Enter a puzzle code or make one for a word
+    Codes,
+    /// Puzzle for a code, which must already have been checked
+    Puzzle(String),
+    /// Results for a word size, starting with the mode, and the game that just finished
+    Stats(usize, GameMode, Option<WordleEngine>),
+    /// Finished games from the history, to replay
+    History,
 }
 
 #[derive(Debug, Clone, PartialEq)]
-enum SceneResult {}
+enum SceneResult {
+    /// Game that was won or lost before it was closed, and its mode
+    GameOver(WordleEngine, GameMode),
+    /// Latest game with several boards that was won or lost before they were closed
+    MultiGameOver(MultiEngine),
+}
@@ -0,0 +1,167 @@
+use crate::scenes::{key_to_input, poll_controller};
+use crate::ui::button_bar::{ButtonBar, ButtonDef, BAR_HEIGHT};
+use crate::ui::theme::colors;
+use crate::ui::wordle_renderer::{render_small_row, small_row_width};
+use crate::{Input, SceneName, SceneResult, HEIGHT, WIDTH};
+use pixels_graphics_lib::prelude::*;
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
+use wordle_engine::analysis::{analyse_row, GuessAnalysis};
+use wordle_engine::WordleEngine;
+
+const ROWS_START: usize = 40;
+const ROW_HEIGHT: usize = 29;
+const ROW_PADDING: usize = 6;
+/// Rows that fit above the button bar, longer games scroll
+const VISIBLE_ROWS: usize = (HEIGHT - BAR_HEIGHT - ROWS_START) / ROW_HEIGHT;
+
+/// Rates each guess of a finished game
+pub struct AnalysisScene {
+    result: Option<SceneUpdateResult<SceneResult, SceneName>>,
+    engine: WordleEngine,
+    rows: Vec<GuessAnalysis>,
+    /// Rows are analysed on another thread as each one can take a while
+    receiver: Receiver<GuessAnalysis>,
+    /// First row shown
+    scroll: usize,
+    button_bar: ButtonBar,
+    input_timer: Timer,
+}
+
+impl AnalysisScene {
+    pub fn new(engine: WordleEngine) -> Box<Self> {
+        let (sender, receiver) = channel();
+        let job_engine = engine.clone();
+        thread::spawn(move || {
+            for row in 0..job_engine.guesses.len() {
+                if let Some(analysis) = analyse_row(&job_engine, row) {
+                    if sender.send(analysis).is_err() {
+                        //scene was closed
+                        return;
+                    }
+                }
+            }
+        });
+        let buttons: &[(&str, ButtonDef)] = if engine.guesses.len() > VISIBLE_ROWS {
+            &[("SCROLL", ButtonDef::Vert), ("CLOSE", ButtonDef::Escape)]
+        } else {
+            &[("CLOSE", ButtonDef::Escape)]
+        };
+        Box::new(AnalysisScene {
+            result: None,
+            engine,
+            rows: vec![],
+            receiver,
+            scroll: 0,
+            button_bar: ButtonBar::new(coord!(0, HEIGHT - BAR_HEIGHT), WIDTH, buttons),
+            input_timer: Timer::new_once(0.3),
+        })
+    }
+}
+
+impl AnalysisScene {
+    fn handle_input(&mut self, input: Input) {
+        match input {
+            Input::Escape => self.result = Some(SceneUpdateResult::Pop(None)),
+            Input::Up => self.scroll_to(self.scroll.saturating_sub(1)),
+            Input::Down => self.scroll_to(self.scroll + 1),
+            Input::Left => self.scroll_to(self.scroll.saturating_sub(VISIBLE_ROWS)),
+            Input::Right => self.scroll_to(self.scroll + VISIBLE_ROWS),
+            Input::Action | Input::Secondary | Input::Tertiary => {}
+        }
+    }
+
+    /// Scrolls so `row` is the first shown, stopping once the last row is on screen
+    fn scroll_to(&mut self, row: usize) {
+        self.scroll = row.min(self.engine.guesses.len().saturating_sub(VISIBLE_ROWS));
+    }
+
+    /// Draws guess `idx` at `row` on screen
+    fn draw_row(&self, graphics: &mut Graphics, row: usize, idx: usize) {
+        let y = ROWS_START + row * ROW_HEIGHT;
+        let style = (colors::MENU_SELECTED, PixelFont::Standard4x5);
+        let detail_style = (colors::HINT, PixelFont::Standard4x5);
+        render_small_row(graphics, coord!(ROW_PADDING, y), &self.engine.guesses[idx]);
+        let text_x = ROW_PADDING * 2 + small_row_width(self.engine.word_size);
+        match self.rows.get(idx) {
+            Some(row) => {
+                graphics.draw_text(
+                    &format!("{} > {} words", row.candidates_before, row.candidates_after),
+                    TextPos::px(coord!(text_x, y + 2)),
+                    style,
+                );
+                graphics.draw_text(
+                    &format!(
+                        "SKILL {}  LUCK {:+.1}  BEST {}",
+                        row.skill(),
+                        row.luck(),
+                        row.best_guess
+                    ),
+                    TextPos::px(coord!(ROW_PADDING, y + 13)),
+                    detail_style,
+                );
+            }
+            None => graphics.draw_text("...", TextPos::px(coord!(text_x, y + 2)), style),
+        }
+    }
+}
+
+impl Scene<SceneResult, SceneName> for AnalysisScene {
+    fn render(
+        &self,
+        graphics: &mut Graphics,
+        _: &MouseData,
+        _: &FxHashSet<KeyCode>,
+        controller: &GameController,
+    ) {
+        graphics.clear(colors::BACKGROUND);
+        graphics.draw_text(
+            "Analysis",
+            TextPos::px(coord!(WIDTH / 2, 12)),
+            (
+                colors::KEYBOARD_LETTER,
+                PixelFont::Standard8x10,
+                Positioning::Center,
+            ),
+        );
+        let count = self.engine.guesses.len();
+        let mut header = format!("Answer: {}", self.engine.word);
+        if count > VISIBLE_ROWS {
+            let last = (self.scroll + VISIBLE_ROWS).min(count);
+            header.push_str(&format!("  Guesses {}-{last} of {count}", self.scroll + 1));
+        }
+        graphics.draw_text(
+            &header,
+            TextPos::px(coord!(WIDTH / 2, 26)),
+            (colors::HINT, PixelFont::Standard4x5, Positioning::Center),
+        );
+        for (row, idx) in (self.scroll..count).take(VISIBLE_ROWS).enumerate() {
+            self.draw_row(graphics, row, idx);
+        }
+        self.button_bar
+            .render(graphics, controller.get_controller_type());
+    }
+
+    fn on_key_down(&mut self, key: KeyCode, _: &MouseData, _: &FxHashSet<KeyCode>) {
+        if let Some(input) = key_to_input(key) {
+            self.handle_input(input);
+        }
+    }
+
+    fn update(
+        &mut self,
+        timing: &Timing,
+        _: &MouseData,
+        _: &FxHashSet<KeyCode>,
+        controller: &GameController,
+        _: &Window,
+    ) -> SceneUpdateResult<SceneResult, SceneName> {
+        if let Some(input) = poll_controller(&mut self.input_timer, timing, controller) {
+            self.handle_input(input);
+        }
+        while let Ok(row) = self.receiver.try_recv() {
+            self.rows.push(row);
+        }
+        self.result.clone().unwrap_or(SceneUpdateResult::Nothing)
+    }
+}
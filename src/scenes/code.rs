@@ -0,0 +1,326 @@
+use crate::scenes::game::keyboard_pos;
+use crate::scenes::{key_to_input, poll_controller, typed_key};
+use crate::share::paste;
+use crate::ui::button_bar::{ButtonBar, ButtonDef, BAR_HEIGHT};
+use crate::ui::keyboard::{Key, Keyboard};
+use crate::ui::text_entry::TextEntry;
+use crate::ui::theme::colors;
+use crate::{Input, SceneName, SceneResult, HEIGHT, WIDTH};
+use pixels_graphics_lib::prelude::*;
+use std::sync::mpsc::{channel, Receiver, TryRecvError};
+use std::thread;
+use wordle_engine::puzzle_code::{decode, encode, normalise};
+use wordle_engine::word_list::WORD_SIZES;
+use wordle_engine::CodeError;
+
+/// Longest code, for a 7 letter word
+const MAX_CODE_LEN: usize = 10;
+const TAB_SIZE: (usize, usize) = (100, 16);
+const TAB_Y: usize = 40;
+const TEXT_Y: usize = 100;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Tab {
+    /// Type a code to play it
+    Play,
+    /// Type a word to get its code
+    Create,
+}
+
+/// Enter a puzzle code to play it, or make a code for a word to send to someone else
+pub struct CodeScene {
+    result: Option<SceneUpdateResult<SceneResult, SceneName>>,
+    tab: Tab,
+    entry: TextEntry,
+    /// Code made for the entered word on the create tab
+    code: Option<String>,
+    keyboard: Keyboard,
+    button_bar: ButtonBar,
+    input_timer: Timer,
+    /// Latest finished puzzle, passed on to the menu when this closes
+    last_game: Option<SceneResult>,
+    /// Clipboard being read after Ctrl+V
+    paste_job: Option<Receiver<Option<String>>>,
+}
+
+impl CodeScene {
+    pub fn new() -> Box<Self> {
+        Box::new(CodeScene {
+            result: None,
+            tab: Tab::Play,
+            entry: TextEntry::new(MAX_CODE_LEN),
+            code: None,
+            keyboard: Keyboard::new(keyboard_pos()),
+            button_bar: ButtonBar::new(
+                coord!(0, HEIGHT - BAR_HEIGHT),
+                WIDTH,
+                &[
+                    ("CURSOR", ButtonDef::Cursor),
+                    ("SELECT", ButtonDef::Select),
+                    ("SWITCH", ButtonDef::Secondary),
+                    ("BACK", ButtonDef::Escape),
+                ],
+            ),
+            input_timer: Timer::new_once(0.3),
+            last_game: None,
+            paste_job: None,
+        })
+    }
+}
+
+impl CodeScene {
+    fn tab_rect(tab: Tab) -> Rect {
+        let x = match tab {
+            Tab::Play => WIDTH / 2 - TAB_SIZE.0,
+            Tab::Create => WIDTH / 2,
+        };
+        Rect::new_with_size(coord!(x, TAB_Y), TAB_SIZE.0, TAB_SIZE.1)
+    }
+
+    fn switch_tab(&mut self, tab: Tab) {
+        if self.tab != tab {
+            self.tab = tab;
+            self.entry.clear();
+            self.entry.max_len = match tab {
+                Tab::Play => MAX_CODE_LEN,
+                Tab::Create => *WORD_SIZES.end(),
+            };
+            self.code = None;
+        }
+    }
+
+    fn press_key(&mut self, key: Key) {
+        let len = self.entry.letters.len();
+        if self.entry.press_key(key) {
+            self.submit();
+        } else if self.entry.letters.len() != len {
+            self.code = None;
+        }
+    }
+
+    fn submit(&mut self) {
+        let text = self.entry.text();
+        match self.tab {
+            Tab::Play => match decode(&text) {
+                Ok(_) => {
+                    self.entry.clear();
+                    self.result = Some(SceneUpdateResult::Push(false, SceneName::Puzzle(text)));
+                }
+                Err(err) => self.entry.error = Some(error_message(err)),
+            },
+            Tab::Create => match encode(&text) {
+                Ok(code) => self.code = Some(code),
+                Err(err) => self.entry.error = Some(error_message(err)),
+            },
+        }
+    }
+
+    fn start_paste(&mut self) {
+        if self.paste_job.is_some() {
+            return;
+        }
+        //clipboard tools can take a while to start so they're run on another thread
+        let (sender, receiver) = channel();
+        thread::spawn(move || {
+            //the scene may have been closed
+            let _ = sender.send(paste());
+        });
+        self.paste_job = Some(receiver);
+    }
+
+    /// Replaces the entered text with the clipboard once it's been read, anything that
+    /// can't be typed on the keyboard is left out
+    fn update_paste(&mut self) {
+        if let Some(job) = &self.paste_job {
+            let pasted = match job.try_recv() {
+                Ok(pasted) => pasted,
+                Err(TryRecvError::Disconnected) => None,
+                Err(TryRecvError::Empty) => return,
+            };
+            self.paste_job = None;
+            let letters: Vec<char> = pasted
+                .map(|text| normalise(&text))
+                .unwrap_or_default()
+                .chars()
+                .filter(|chr| chr.is_ascii_uppercase())
+                .collect();
+            self.entry.clear();
+            self.code = None;
+            if letters.is_empty() {
+                self.entry.error = Some(String::from("Nothing to paste"));
+                return;
+            }
+            for chr in letters {
+                self.entry.press_key(Key::Letter(chr));
+            }
+        }
+    }
+
+    fn handle_input(&mut self, input: Input) {
+        match input {
+            Input::Escape => self.result = Some(SceneUpdateResult::Pop(self.last_game.clone())),
+            Input::Secondary => match self.tab {
+                Tab::Play => self.switch_tab(Tab::Create),
+                Tab::Create => self.switch_tab(Tab::Play),
+            },
+            _ => {
+                if let Some(key) = self.keyboard.key_press(input) {
+                    self.press_key(key);
+                }
+            }
+        }
+    }
+
+    fn draw_tabs(&self, graphics: &mut Graphics) {
+        for (tab, label) in [(Tab::Play, "PLAY A CODE"), (Tab::Create, "MAKE A CODE")] {
+            let rect = CodeScene::tab_rect(tab);
+            let color = if self.tab == tab {
+                colors::MENU_SELECTED
+            } else {
+                colors::MENU_DEFAULT
+            };
+            graphics.draw_text(
+                label,
+                TextPos::px(rect.center() + (1, 1)),
+                (color, PixelFont::Standard6x7, Positioning::Center),
+            );
+            if self.tab == tab {
+                graphics.draw_line(rect.bottom_left(), rect.bottom_right(), color);
+            }
+        }
+    }
+}
+
+impl Scene<SceneResult, SceneName> for CodeScene {
+    fn render(
+        &self,
+        graphics: &mut Graphics,
+        _: &MouseData,
+        _: &FxHashSet<KeyCode>,
+        controller: &GameController,
+    ) {
+        graphics.clear(colors::BACKGROUND);
+        graphics.draw_text(
+            "Puzzle codes",
+            TextPos::px(coord!(WIDTH / 2, 20)),
+            (
+                colors::KEYBOARD_LETTER,
+                PixelFont::Standard8x10,
+                Positioning::Center,
+            ),
+        );
+        self.draw_tabs(graphics);
+        let prompt = match self.tab {
+            Tab::Play => "Type the code you were sent",
+            Tab::Create => "Type a word to get a code for it",
+        };
+        graphics.draw_text(
+            prompt,
+            TextPos::px(coord!(WIDTH / 2, 76)),
+            (colors::HINT, PixelFont::Standard6x7, Positioning::Center),
+        );
+        let text = self.entry.text();
+        graphics.draw_text(
+            &format!("{text}_"),
+            TextPos::px(coord!(WIDTH / 2, TEXT_Y)),
+            (
+                colors::KEYBOARD_LETTER,
+                PixelFont::Standard8x10,
+                Positioning::Center,
+            ),
+        );
+        if let Some(code) = &self.code {
+            graphics.draw_text(
+                "Code",
+                TextPos::px(coord!(WIDTH / 2, TEXT_Y + 24)),
+                (colors::HINT, PixelFont::Standard6x7, Positioning::Center),
+            );
+            graphics.draw_text(
+                code,
+                TextPos::px(coord!(WIDTH / 2, TEXT_Y + 38)),
+                (
+                    colors::WIN_BANNER,
+                    PixelFont::Standard8x10,
+                    Positioning::Center,
+                ),
+            );
+        }
+        self.entry.render_error(graphics);
+        self.keyboard.render(graphics);
+        self.button_bar
+            .render(graphics, controller.get_controller_type());
+    }
+
+    fn on_mouse_click(
+        &mut self,
+        down_at: Coord,
+        mouse: &MouseData,
+        mouse_button: MouseButton,
+        _: &FxHashSet<KeyCode>,
+    ) {
+        if mouse_button != MouseButton::Left {
+            return;
+        }
+        for tab in [Tab::Play, Tab::Create] {
+            let rect = CodeScene::tab_rect(tab);
+            if rect.contains(down_at) && rect.contains(mouse.xy) {
+                self.switch_tab(tab);
+            }
+        }
+        if let Some(key) = self.keyboard.mouse_click(down_at, mouse.xy) {
+            self.press_key(key);
+        }
+    }
+
+    fn on_key_down(&mut self, key: KeyCode, _: &MouseData, held_keys: &FxHashSet<KeyCode>) {
+        let modifier = [
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+            KeyCode::SuperLeft,
+            KeyCode::SuperRight,
+        ];
+        if key == KeyCode::KeyV && modifier.iter().any(|key| held_keys.contains(key)) {
+            self.start_paste();
+        } else if let Some(key) = typed_key(key) {
+            self.keyboard.flash(key);
+            self.press_key(key);
+        } else if let Some(input) = key_to_input(key) {
+            self.handle_input(input);
+        }
+    }
+
+    fn update(
+        &mut self,
+        timing: &Timing,
+        mouse: &MouseData,
+        _: &FxHashSet<KeyCode>,
+        controller: &GameController,
+        _: &Window,
+    ) -> SceneUpdateResult<SceneResult, SceneName> {
+        self.keyboard.update(timing);
+        self.update_paste();
+        if let Some(input) = poll_controller(&mut self.input_timer, timing, controller) {
+            self.handle_input(input);
+        }
+        self.keyboard.mouse_move(mouse.xy);
+        self.result.clone().unwrap_or(SceneUpdateResult::Nothing)
+    }
+
+    fn resuming(&mut self, result: Option<SceneResult>) {
+        if result.is_some() {
+            self.last_game = result;
+        }
+        self.result = None;
+        self.input_timer.reset();
+    }
+}
+
+fn error_message(err: CodeError) -> String {
+    match err {
+        CodeError::UnsupportedWordSize(_) => String::from("Words must be 4 to 7 letters"),
+        CodeError::NotAWord => String::from("Unknown word"),
+        CodeError::WrongLength(_) => String::from("Codes are 7 to 10 letters"),
+        CodeError::InvalidCharacter(_) => String::from("Invalid letter"),
+        CodeError::Invalid => String::from("Code isn't valid, check for typos"),
+    }
+}
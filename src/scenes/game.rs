@@ -1,16 +1,37 @@
-use crate::engine::{EngineState, SubmittedGuessInfo, WordleEngine};
-use crate::scenes::keys_to_input;
+use crate::date::{unix_time, LocalTime};
+use crate::history::{add_game, Record};
+use crate::scenes::{key_to_input, poll_controller, typed_key};
+use crate::share::{share, Shared, SHARE_FILE};
 use crate::ui::button_bar::{ButtonBar, ButtonDef, BAR_HEIGHT};
 use crate::ui::keyboard::{Key, Keyboard};
+use crate::ui::text_entry::draw_message;
 use crate::ui::theme::colors;
-use crate::ui::wordle_renderer::{render_field, render_guess_field};
-use crate::word_list::word_count_for_size;
-use crate::{Input, SceneName, SceneResult, Settings, HEIGHT, WIDTH};
+use crate::ui::wordle_renderer::{field_width, render_field, render_guess_field};
+use crate::{
+    DailyGame, GameMode, Input, PlayTime, SceneName, SceneResult, Settings, HEIGHT, WIDTH,
+};
 use pixels_graphics_lib::prelude::*;
+use std::sync::mpsc::{channel, Receiver, TryRecvError};
+use std::thread;
+use wordle_engine::advisor::{rank_guesses, RankedGuess};
+use wordle_engine::daily::{daily_answer_idx, puzzle_number};
+use wordle_engine::endless::Run;
+use wordle_engine::puzzle_code::{decode, encode};
+use wordle_engine::share::{share_text, Palette};
+use wordle_engine::solver::candidates;
+use wordle_engine::time_attack::{TimeAttack, TIME_LIMIT};
+use wordle_engine::word_list::{answers_for_size, word_count_for_size};
+use wordle_engine::{
+    Difficulty, EngineState, GuessError, SubmittedGuessInfo, WordOrder, WordOutcome, WordleEngine,
+};
 
-const ANIM_UPDATE_RATE: f64 = 0.05;
-const ANIM_GUESS_STEP: f64 = ANIM_UPDATE_RATE / 5.0;
-const ANIM_ENDGAME_STEP: f64 = ANIM_UPDATE_RATE / 1.3;
+pub(super) const ANIM_UPDATE_RATE: f64 = 0.05;
+pub(super) const ANIM_GUESS_STEP: f64 = ANIM_UPDATE_RATE / 5.0;
+pub(super) const ANIM_ENDGAME_STEP: f64 = ANIM_UPDATE_RATE / 1.3;
+const ADVICE_COUNT: usize = 5;
+const ADVICE_LINE_HEIGHT: usize = 10;
+/// Seconds left when the clock turns red
+const CLOCK_WARNING: usize = 10;
 
 #[derive(Debug)]
 enum GameState {
@@ -21,69 +42,160 @@ enum GameState {
 }
 
 pub struct GameScene {
+    result: Option<SceneUpdateResult<SceneResult, SceneName>>,
     engine: WordleEngine,
+    mode: GameMode,
+    /// Day the game was started, only used for daily puzzles
+    day: i64,
+    /// Words found so far, only used in endless mode
+    run: Run,
+    /// Only used in time attack mode, paused while guesses are animated
+    clock: TimeAttack,
     keyboard: Keyboard,
     button_bar: ButtonBar,
     end_button_bar: ButtonBar,
     input_timer: Timer,
-    show_error: bool,
+    error: Option<String>,
+    hint: Option<String>,
+    /// Next hint to give for the current row, see [GameScene::use_hint]
+    hint_level: usize,
+    /// Best guesses and their entropy for `advice_row`
+    advice: Vec<(&'static str, f64)>,
+    /// Number of guesses that `advice` was made for
+    advice_row: Option<usize>,
+    /// Advice being worked out on another thread, for the given number of guesses
+    advice_job: Option<(usize, Receiver<Vec<RankedGuess<'static>>>)>,
     anim_timer: Timer,
     state: GameState,
     anim_perc: f64,
     #[allow(unused)] //needed to play sound
     audio_engine: Option<AudioEngine>,
     win_sound: Option<SoundEffect>,
+    /// Palette the result was last shared with, sharing again uses the next one
+    shared: Option<Palette>,
+    share_message: Option<String>,
+    /// Result being copied on another thread, with the description of its palette
+    share_job: Option<(&'static str, Receiver<Option<Shared>>)>,
+    /// When the current word was started and how long it's been played, for the history
+    time: PlayTime,
+    settings: AppPrefs<Settings>,
 }
 
 impl GameScene {
-    pub fn new(word_size: usize, mut settings: AppPrefs<Settings>) -> Box<Self> {
-        let (audio_engine, sound) = if let Ok(engine) = AudioEngine::new() {
-            if let Ok(win_sound) =
-                engine.load_from_bytes(include_bytes!("../../assets/win.wav"), 1.75)
-            {
-                (Some(engine), Some(win_sound))
-            } else {
-                (None, None)
-            }
-        } else {
-            (None, None)
-        };
-        let keyboard_pos = coord!(WIDTH / 2, HEIGHT)
-            - (Keyboard::size().0 / 2, Keyboard::size().1)
-            - (0_usize, BAR_HEIGHT);
-        if settings
+    pub fn new(word_size: usize, mode: GameMode, mut settings: AppPrefs<Settings>) -> Box<Self> {
+        let difficulty = settings
             .data
-            .word_idx
+            .difficulty
             .get(&word_size)
             .copied()
-            .unwrap_or_default()
-            == word_count_for_size(word_size)
-        {
-            settings.data.word_idx.insert(word_size, 0);
-        }
-        let engine = WordleEngine::new(
-            word_size,
-            settings
+            .unwrap_or_default();
+        let day = LocalTime::now().day;
+        //resumed games carry on from when they were started
+        let time = match mode {
+            GameMode::Classic if settings.data.saved_games.contains_key(&word_size) => {
+                settings.data.saved_game_times.get(&word_size).copied()
+            }
+            GameMode::Daily => settings
                 .data
-                .word_idx
+                .daily
                 .get(&word_size)
-                .copied()
-                .unwrap_or_default(),
-        );
-        settings
+                .filter(|daily| daily.day == day)
+                .map(|daily| daily.time),
+            _ => None,
+        }
+        .filter(|time| time.started != 0);
+        let picked = match mode {
+            GameMode::Classic => classic_engine(word_size, difficulty, &mut settings),
+            GameMode::Retry => retry_engine(word_size, difficulty, &mut settings),
+            GameMode::Daily => Some(daily_engine(word_size, difficulty, day, &settings)),
+            GameMode::Practice | GameMode::Endless | GameMode::TimeAttack => {
+                Some(practice_engine(word_size, difficulty))
+            }
+            GameMode::Absurdle => Some(
+                WordleEngine::new_adversarial(word_size, difficulty)
+                    .expect("word size is from menu"),
+            ),
+            //these words are picked before the game is opened, see [GameScene::with_engine]
+            GameMode::TwoPlayer | GameMode::Custom => None,
+        };
+        //no word to play, so a random word is played without changing any results
+        let (engine, mode) = match picked {
+            Some(engine) => (engine, mode),
+            None => (practice_engine(word_size, difficulty), GameMode::Practice),
+        };
+        GameScene::build(
+            engine,
+            mode,
+            day,
+            time.unwrap_or_else(PlayTime::now),
+            settings,
+        )
+    }
+
+    /// Game for a word that's already been picked, e.g. by another player
+    pub fn with_engine(
+        engine: WordleEngine,
+        mode: GameMode,
+        settings: AppPrefs<Settings>,
+    ) -> Box<Self> {
+        GameScene::build(
+            engine,
+            mode,
+            LocalTime::now().day,
+            PlayTime::now(),
+            settings,
+        )
+    }
+
+    /// Puzzle for `code`, see [decode]
+    pub fn for_code(code: &str, settings: AppPrefs<Settings>) -> Box<Self> {
+        let word = decode(code).expect("code is checked before the puzzle is opened");
+        let difficulty = settings
             .data
-            .word_idx
-            .entry(word_size)
-            .and_modify(|v| *v += 1)
-            .or_insert(1);
-        settings.save();
+            .difficulty
+            .get(&word.len())
+            .copied()
+            .unwrap_or_default();
+        let engine = WordleEngine::with_word(&word, difficulty)
+            .expect("decoded words are in the dictionary");
+        GameScene::with_engine(engine, GameMode::Custom, settings)
+    }
+
+    fn build(
+        engine: WordleEngine,
+        mode: GameMode,
+        day: i64,
+        time: PlayTime,
+        settings: AppPrefs<Settings>,
+    ) -> Box<Self> {
+        let (audio_engine, sound) = load_win_sound();
+        let mut keyboard = Keyboard::new(keyboard_pos());
+        for row in &engine.guesses {
+            keyboard.add_guess(&SubmittedGuessInfo::from_row(row));
+        }
+        //a finished daily is shown as it ended
+        let (state, anim_perc) = if engine.state == EngineState::Guessing {
+            (GameState::Input, 0.0)
+        } else {
+            (GameState::GameOver, 1.0)
+        };
         Box::new(GameScene {
+            result: None,
             engine,
-            anim_perc: 0.0,
+            mode,
+            day,
+            run: Run::default(),
+            clock: TimeAttack::new(TIME_LIMIT),
+            anim_perc,
             audio_engine,
-            state: GameState::Input,
-            show_error: false,
-            keyboard: Keyboard::new(keyboard_pos),
+            state,
+            error: None,
+            hint: None,
+            hint_level: 0,
+            advice: vec![],
+            advice_row: None,
+            advice_job: None,
+            keyboard,
             anim_timer: Timer::new(ANIM_UPDATE_RATE),
             button_bar: ButtonBar::new(
                 coord!(0, HEIGHT - BAR_HEIGHT),
@@ -91,43 +203,512 @@ impl GameScene {
                 &[
                     ("CURSOR", ButtonDef::Cursor),
                     ("SELECT", ButtonDef::Select),
+                    ("HINT", ButtonDef::Secondary),
+                    ("ADVISE", ButtonDef::Tertiary),
                     ("CLOSE", ButtonDef::Escape),
                 ],
             ),
             end_button_bar: ButtonBar::new(
                 coord!(0, HEIGHT - BAR_HEIGHT),
                 WIDTH,
-                &[("CLOSE", ButtonDef::Escape)],
+                &[
+                    match mode {
+                        GameMode::Endless => ("NEW RUN", ButtonDef::Select),
+                        GameMode::TimeAttack => ("AGAIN", ButtonDef::Select),
+                        GameMode::TwoPlayer => ("NEXT ROUND", ButtonDef::Select),
+                        _ => ("ANALYSE", ButtonDef::Select),
+                    },
+                    ("SHARE", ButtonDef::Tertiary),
+                    ("CLOSE", ButtonDef::Escape),
+                ],
             ),
             input_timer: Timer::new_once(0.3),
             win_sound: sound,
+            shared: None,
+            share_message: None,
+            share_job: None,
+            time,
+            settings,
         })
     }
 }
 
 impl GameScene {
+    fn press_key(&mut self, key: Key) {
+        match key {
+            Key::Letter(chr) => {
+                if let Err(err) = self.engine.add_letter(chr) {
+                    self.error = Some(error_message(err));
+                }
+            }
+            Key::Enter => self.submit(),
+            Key::Backspace => self.engine.backspace(),
+        }
+        if self.engine.state == EngineState::Guessing {
+            self.save_progress();
+        }
+    }
+
+    /// Store the board so it can be resumed if the game is closed
+    fn save_progress(&mut self) {
+        match self.mode {
+            GameMode::Classic => {
+                self.settings
+                    .data
+                    .saved_games
+                    .insert(self.engine.word_size, self.engine.clone());
+                self.settings
+                    .data
+                    .saved_game_times
+                    .insert(self.engine.word_size, self.time);
+            }
+            GameMode::Daily => self.save_daily(),
+            GameMode::Practice
+            | GameMode::Absurdle
+            | GameMode::Endless
+            | GameMode::TimeAttack
+            | GameMode::TwoPlayer
+            | GameMode::Custom
+            | GameMode::Retry => return,
+        }
+        self.settings.save();
+    }
+
+    fn save_daily(&mut self) {
+        self.settings.data.daily.insert(
+            self.engine.word_size,
+            DailyGame {
+                day: self.day,
+                engine: self.engine.clone(),
+                time: self.time,
+            },
+        );
+    }
+
+    /// Clear the saved board and move on to the next word,
+    /// daily puzzles are kept so they can't be played again
+    fn finish_game(&mut self) {
+        self.add_stats();
+        self.add_history();
+        match self.mode {
+            GameMode::Classic => {
+                self.settings
+                    .data
+                    .saved_games
+                    .remove(&self.engine.word_size);
+                self.settings
+                    .data
+                    .saved_game_times
+                    .remove(&self.engine.word_size);
+                self.add_progress();
+                self.add_win();
+            }
+            GameMode::Retry => self.add_progress(),
+            GameMode::Daily => {
+                self.save_daily();
+                self.add_win();
+            }
+            GameMode::Endless => self.add_to_run(),
+            GameMode::TimeAttack => {
+                if self.engine.state == EngineState::Found {
+                    self.clock.word_found();
+                    self.update_best_times();
+                } else {
+                    self.clock.word_lost();
+                }
+            }
+            GameMode::Practice | GameMode::Absurdle | GameMode::TwoPlayer | GameMode::Custom => {}
+        }
+        self.settings.save();
+    }
+
+    /// Classic and daily wins are counted for each difficulty
+    fn add_win(&mut self) {
+        let word_size = self.engine.word_size;
+        if self.engine.state == EngineState::Found {
+            *self
+                .settings
+                .data
+                .wins
+                .entry(word_size)
+                .or_default()
+                .entry(self.engine.difficulty)
+                .or_default() += 1;
+            if self.engine.hints > 0 {
+                *self
+                    .settings
+                    .data
+                    .hinted_wins
+                    .entry(word_size)
+                    .or_default()
+                    .entry(self.engine.difficulty)
+                    .or_default() += 1;
+            }
+        }
+    }
+
+    /// Every mode keeps stats
+    fn add_stats(&mut self) {
+        self.settings
+            .data
+            .stats
+            .entry(self.engine.word_size)
+            .or_default()
+            .entry(self.mode)
+            .or_default()
+            .add_game(&self.engine);
+    }
+
+    fn add_progress(&mut self) {
+        self.settings
+            .data
+            .progress
+            .entry(self.engine.word_size)
+            .or_default()
+            .add_game(&self.engine);
+    }
+
+    fn add_history(&self) {
+        if let Some(record) = Record::new(
+            &self.engine,
+            self.mode,
+            self.time.started,
+            unix_time(),
+            self.time.seconds,
+        ) {
+            add_game(&record);
+        }
+    }
+
+    /// Endless mode keeps the best run for each word size, it's updated as soon as it's beaten
+    /// so closing the game part way through a run still counts
+    fn add_to_run(&mut self) {
+        if self.run.add_game(&self.engine).is_none() {
+            return;
+        }
+        let word_size = self.engine.word_size;
+        let best = self.settings.data.best_runs.get(&word_size);
+        if best.map(|best| self.run.beats(best)).unwrap_or(true) {
+            self.settings
+                .data
+                .best_runs
+                .insert(word_size, self.run.clone());
+        }
+    }
+
+    /// Quickest word is updated as soon as it's found, the number of words when the clock runs out,
+    /// returns true if either changed
+    fn update_best_times(&mut self) -> bool {
+        self.settings
+            .data
+            .best_times
+            .entry(self.engine.word_size)
+            .or_default()
+            .update(&self.clock)
+    }
+
+    fn times_up(&mut self) {
+        self.anim_perc = 0.0;
+        self.state = GameState::AnimEndGame;
+        self.error = None;
+        self.hint = None;
+        if self.update_best_times() {
+            self.settings.save();
+        }
+    }
+
+    /// Endless and time attack move straight on to a new word once the end game banner has shown
+    fn chains_games(&self) -> bool {
+        match self.mode {
+            GameMode::Endless => self.engine.state == EngineState::Found,
+            GameMode::TimeAttack => !self.clock.is_over(),
+            _ => false,
+        }
+    }
+
+    /// Endless mode moves straight on to a new word, the run carries on
+    fn next_word(&mut self) {
+        self.engine = practice_engine(self.engine.word_size, self.engine.difficulty);
+        self.keyboard = Keyboard::new(keyboard_pos());
+        self.state = GameState::Input;
+        self.anim_perc = 0.0;
+        self.error = None;
+        self.hint = None;
+        self.hint_level = 0;
+        self.advice.clear();
+        self.advice_row = None;
+        self.advice_job = None;
+        self.shared = None;
+        self.share_message = None;
+        self.share_job = None;
+        self.time = PlayTime::now();
+    }
+
+    fn game_over_action(&mut self) {
+        match self.mode {
+            GameMode::Endless => {
+                self.run = Run::default();
+                self.next_word();
+            }
+            GameMode::TimeAttack => {
+                self.clock = TimeAttack::new(TIME_LIMIT);
+                self.next_word();
+            }
+            GameMode::TwoPlayer => self.close(),
+            _ => self.open_analysis(),
+        }
+    }
+
+    /// Finished games are sent back to the previous scene, e.g. to score a two player round
+    fn close(&mut self) {
+        //keeps the time played since the last key press
+        if self.engine.state == EngineState::Guessing {
+            self.save_progress();
+        }
+        let finished = (self.engine.state != EngineState::Guessing)
+            .then(|| SceneResult::GameOver(self.engine.clone(), self.mode));
+        self.result = Some(SceneUpdateResult::Pop(finished));
+    }
+
+    /// Each use in the same row gives more help:
+    /// 1. Number of words that are still possible
+    /// 2. Whether the most common unknown letter in those words is in the answer
+    ///    (skipped in absurdle, as the answer isn't known yet)
+    /// 3. Fills in one of the possible words
+    fn use_hint(&mut self) {
+        if !matches!(self.state, GameState::Input) || self.engine.state != EngineState::Guessing {
+            return;
+        }
+        self.error = None;
+        let words = candidates(self.engine.word_size, &self.engine.guesses);
+        let given = match self.hint_level {
+            0 => {
+                self.hint = Some(match words.len() {
+                    1 => String::from("1 possible word"),
+                    count => format!("{count} possible words"),
+                });
+                true
+            }
+            1 if !self.engine.adversarial => {
+                self.reveal_letter(&words) || self.suggest_word(&words)
+            }
+            _ => self.suggest_word(&words),
+        };
+        if !given {
+            return;
+        }
+        self.hint_level = (self.hint_level + 1).min(2);
+        self.engine.hints += 1;
+        self.save_progress();
+    }
+
+    /// Returns false if every letter is already known
+    fn reveal_letter(&mut self, words: &[&str]) -> bool {
+        let letter = ('A'..='Z')
+            .filter(|chr| !self.keyboard.is_known(*chr))
+            .max_by_key(|chr| {
+                //reversed so ties go to the earliest letter
+                let count = words.iter().filter(|word| word.contains(*chr)).count();
+                (count, std::cmp::Reverse(*chr))
+            });
+        match letter {
+            Some(chr) if self.engine.word.contains(chr) => {
+                self.keyboard.add_mismatch(chr);
+                self.hint = Some(format!("{chr} is in the word"));
+                true
+            }
+            Some(chr) => {
+                self.keyboard.add_no_match(chr);
+                self.hint = Some(format!("{chr} is not in the word"));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns false if the suggestion has already been entered
+    fn suggest_word(&mut self, words: &[&str]) -> bool {
+        let answers = answers_for_size(self.engine.word_size).unwrap_or_default();
+        let suggestion = words
+            .iter()
+            .find(|word| answers.contains(word))
+            .or(words.first());
+        match suggestion {
+            Some(word) => {
+                let guess: Vec<char> = word.chars().collect();
+                if self.engine.current_guess == guess {
+                    return false;
+                }
+                self.engine.current_guess = guess;
+                self.hint = Some(String::from("Try this word"));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Ranking the whole dictionary can take a while so it's done on another thread
+    fn update_advice(&mut self) {
+        if let Some((row, job)) = &self.advice_job {
+            match job.try_recv() {
+                Ok(ranked) => {
+                    self.advice = ranked
+                        .iter()
+                        .take(ADVICE_COUNT)
+                        .map(|guess| (guess.word, guess.entropy))
+                        .collect();
+                    let row = *row;
+                    self.advice_row = Some(row);
+                    self.advice_job = None;
+                    //seeing the best guesses is help, so it counts as a hint for the row
+                    if self.settings.data.show_advisor
+                        && row == self.engine.guesses.len()
+                        && self.engine.state == EngineState::Guessing
+                    {
+                        self.engine.hints += 1;
+                        self.save_progress();
+                    }
+                }
+                Err(TryRecvError::Disconnected) => self.advice_job = None,
+                Err(TryRecvError::Empty) => {}
+            }
+        }
+        let row = self.engine.guesses.len();
+        if self.settings.data.show_advisor
+            && self.engine.state == EngineState::Guessing
+            && self.advice_row != Some(row)
+            && self.advice_job.as_ref().map(|(job_row, _)| *job_row) != Some(row)
+        {
+            let (sender, receiver) = channel();
+            let engine = self.engine.clone();
+            thread::spawn(move || {
+                //the game may have been closed
+                let _ = sender.send(rank_guesses(&engine));
+            });
+            self.advice_job = Some((row, receiver));
+        }
+    }
+
+    /// Copies the result grid, each share in the same game uses the next palette
+    fn share(&mut self) {
+        if self.share_job.is_some() {
+            return;
+        }
+        let palette = match self.shared {
+            Some(palette) => palette.next(),
+            None => self.settings.data.share_palette,
+        };
+        let text = share_text(&self.share_title(), &self.engine, palette);
+        let style = match palette {
+            Palette::Standard => "",
+            Palette::Colourblind => " (colourblind)",
+            Palette::Ascii => " (text)",
+        };
+        //clipboard tools can take a while to start so they're run on another thread
+        let (sender, receiver) = channel();
+        thread::spawn(move || {
+            //the game may have been closed
+            let _ = sender.send(share(&text));
+        });
+        self.share_job = Some((style, receiver));
+        self.share_message = Some(String::from("Copying..."));
+        self.shared = Some(palette);
+        self.settings.data.share_palette = palette;
+        self.settings.save();
+    }
+
+    fn update_share(&mut self) {
+        if let Some((style, job)) = &self.share_job {
+            let shared = match job.try_recv() {
+                Ok(shared) => shared,
+                Err(TryRecvError::Disconnected) => None,
+                Err(TryRecvError::Empty) => return,
+            };
+            self.share_message = Some(match shared {
+                Some(Shared::Clipboard) => format!("Copied{style}, share again to change"),
+                Some(Shared::File(_)) => format!("Saved to {SHARE_FILE}{style}"),
+                None => String::from("Unable to share"),
+            });
+            self.share_job = None;
+        }
+    }
+
+    /// Mode, word size and puzzle number, the score is added after
+    fn share_title(&self) -> String {
+        let size = format!("{} letters", self.engine.word_size);
+        match self.mode {
+            GameMode::Classic => format!("Wordle {size}"),
+            GameMode::Daily => format!("Wordle Daily #{} {size}", puzzle_number(self.day)),
+            GameMode::Practice => format!("Wordle Practice {size}"),
+            GameMode::Absurdle => format!("Absurdle {size}"),
+            GameMode::Endless => {
+                //a lost word isn't part of the streak
+                let word = if self.engine.state == EngineState::Found {
+                    self.run.streak
+                } else {
+                    self.run.streak + 1
+                };
+                format!("Wordle Endless #{word} {size}")
+            }
+            GameMode::TimeAttack => format!("Wordle Time attack #{} {size}", self.clock.words),
+            GameMode::TwoPlayer => format!("Wordle Two player {size}"),
+            GameMode::Retry => format!("Wordle Retry {size}"),
+            GameMode::Custom => match encode(&self.engine.word) {
+                Ok(code) => format!("Wordle Puzzle {code} {size}"),
+                Err(_) => format!("Wordle Puzzle {size}"),
+            },
+        }
+    }
+
+    fn open_analysis(&mut self) {
+        self.result = Some(SceneUpdateResult::Push(
+            false,
+            SceneName::Analysis(self.engine.clone()),
+        ));
+    }
+
+    fn handle_input(&mut self, input: Input) {
+        if input == Input::Escape {
+            self.close();
+        } else if input == Input::Action && matches!(self.state, GameState::GameOver) {
+            self.game_over_action();
+        } else if input == Input::Secondary {
+            self.use_hint();
+        } else if input == Input::Tertiary && self.end_game().is_some() {
+            self.share();
+        } else if input == Input::Tertiary {
+            self.settings.data.show_advisor = !self.settings.data.show_advisor;
+            if !self.settings.data.show_advisor {
+                //advice that arrives while hidden isn't counted as a hint, so it's worked out again
+                self.advice_job = None;
+            }
+            self.settings.save();
+        } else if matches!(self.state, GameState::Input) {
+            self.error = None;
+            if let Some(key) = self.keyboard.key_press(input) {
+                self.press_key(key);
+            }
+        }
+    }
+
     fn submit(&mut self) {
-        if let Ok(result) = self.engine.submit() {
-            if let Some(info) = result {
+        match self.engine.submit() {
+            Ok(info) => {
+                self.hint = None;
+                self.hint_level = 0;
                 self.anim_perc = 0.0;
                 self.state = GameState::AnimGuess(info);
+                if self.engine.state != EngineState::Guessing {
+                    self.finish_game();
+                }
             }
-        } else {
-            self.show_error = true;
+            Err(err) => self.error = Some(error_message(err)),
         }
     }
 
     fn update_keyboard(&mut self) {
         if let GameState::AnimGuess(info) = &self.state {
-            for c in &info.matches {
-                self.keyboard.add_match(*c);
-            }
-            for c in &info.mismatches {
-                self.keyboard.add_mismatch(*c);
-            }
-            for c in &info.no_matches {
-                self.keyboard.add_no_match(*c);
-            }
+            self.keyboard.add_guess(info);
             if self.engine.state == EngineState::Guessing {
                 self.state = GameState::Input;
             } else {
@@ -143,6 +724,105 @@ impl GameScene {
     }
 }
 
+impl GameScene {
+    /// Progress through the run, only shown in endless and time attack modes
+    fn run_status(&self) -> Option<String> {
+        if self.mode == GameMode::TimeAttack {
+            return Some(self.time_attack_status());
+        }
+        if self.mode != GameMode::Endless {
+            return None;
+        }
+        let best = self
+            .settings
+            .data
+            .best_runs
+            .get(&self.engine.word_size)
+            .map(|best| best.streak)
+            .unwrap_or_default();
+        Some(match self.engine.state {
+            EngineState::OutOfGuesses => format!(
+                "Run over: {} words, {} points",
+                self.run.streak, self.run.score
+            ),
+            _ => format!(
+                "Streak {}  Score {}  Best {best}",
+                self.run.streak, self.run.score
+            ),
+        })
+    }
+
+    fn time_attack_status(&self) -> String {
+        if self.clock.is_over() {
+            return format!(
+                "{} words, {:.1} per minute",
+                self.clock.words,
+                self.clock.words_per_minute()
+            );
+        }
+        let best = self.settings.data.best_times.get(&self.engine.word_size);
+        match best.and_then(|best| best.fastest) {
+            Some(fastest) => format!(
+                "Words {}  Best {}  Fastest {fastest:.1}s",
+                self.clock.words,
+                best.map(|best| best.words).unwrap_or_default()
+            ),
+            None => format!("Words {}", self.clock.words),
+        }
+    }
+
+    /// Time left in the top right corner, red for the last few seconds
+    fn draw_clock(&self, graphics: &mut Graphics) {
+        let remaining = self.clock.remaining().ceil() as usize;
+        let color = if remaining <= CLOCK_WARNING {
+            colors::ERROR
+        } else {
+            colors::KEYBOARD_LETTER
+        };
+        graphics.draw_text(
+            &format!("{}:{:02}", remaining / 60, remaining % 60),
+            TextPos::px(coord!(WIDTH - 4, 4)),
+            (color, PixelFont::Standard6x7, Positioning::RightTop),
+        );
+    }
+
+    /// How the game ended, `None` if it's still going
+    fn end_game(&self) -> Option<EndGame> {
+        if self.mode == GameMode::TimeAttack && self.clock.is_over() {
+            return Some(EndGame::TimeUp(self.clock.words));
+        }
+        match self.engine.state {
+            EngineState::Found => Some(EndGame::Win(
+                self.engine.guesses.len(),
+                self.engine.max_guess_count,
+            )),
+            EngineState::OutOfGuesses => Some(EndGame::Lose),
+            EngineState::Guessing => None,
+        }
+    }
+
+    /// Best guesses on the left of the board and their entropy on the right
+    fn draw_advice(&self, graphics: &mut Graphics) {
+        let margin = (WIDTH - field_width(self.engine.word_size)) / 2;
+        let style = (colors::HINT, PixelFont::Standard4x5);
+        graphics.draw_text("BEST", TextPos::px(coord!(4, 8)), style);
+        graphics.draw_text("BITS", TextPos::px(coord!(WIDTH - margin + 6, 8)), style);
+        if self.advice_row != Some(self.engine.guesses.len()) {
+            graphics.draw_text("...", TextPos::px(coord!(4, 8 + ADVICE_LINE_HEIGHT)), style);
+            return;
+        }
+        for (i, (word, entropy)) in self.advice.iter().enumerate() {
+            let y = 8 + (i + 1) * ADVICE_LINE_HEIGHT;
+            graphics.draw_text(word, TextPos::px(coord!(4, y)), style);
+            graphics.draw_text(
+                &format!("{entropy:.1}"),
+                TextPos::px(coord!(WIDTH - margin + 6, y)),
+                style,
+            );
+        }
+    }
+}
+
 impl Scene<SceneResult, SceneName> for GameScene {
     fn render(
         &self,
@@ -165,34 +845,37 @@ impl Scene<SceneResult, SceneName> for GameScene {
             render_field(graphics, coord!(0, 0), &self.engine);
         }
 
-        if self.show_error {
-            graphics.draw_text(
-                "Unknown word",
-                TextPos::px(coord!(WIDTH / 2, HEIGHT - Keyboard::size().1 - 26)),
-                (colors::ERROR, PixelFont::Standard6x7, Positioning::Center),
-            );
+        if self.settings.data.show_advisor && self.engine.state == EngineState::Guessing {
+            self.draw_advice(graphics);
         }
 
-        match self.engine.state {
-            EngineState::Found => {
+        let run_status = self.run_status();
+        let message = match (&self.error, &self.hint, &run_status) {
+            (Some(error), _, _) => Some((error, colors::ERROR)),
+            (None, Some(hint), _) => Some((hint, colors::HINT)),
+            (None, None, Some(status)) => Some((status, colors::HINT)),
+            (None, None, None) => None,
+        };
+        if let Some((text, color)) = message {
+            draw_message(graphics, text, color);
+        }
+
+        if self.mode == GameMode::TimeAttack {
+            self.draw_clock(graphics);
+        }
+
+        match self.end_game() {
+            Some(end_game) => {
                 if matches!(self.state, GameState::GameOver | GameState::AnimEndGame) {
-                    draw_end_game(
-                        graphics,
-                        self.anim_perc.clamp(0.0, 1.0),
-                        EndGame::Win(self.engine.guesses.len(), self.engine.max_guess_count),
-                    );
+                    draw_end_game(graphics, self.anim_perc.clamp(0.0, 1.0), end_game);
                 }
-                self.end_button_bar
-                    .render(graphics, controller.get_controller_type());
-            }
-            EngineState::OutOfGuesses => {
-                if matches!(self.state, GameState::GameOver | GameState::AnimEndGame) {
-                    draw_end_game(graphics, self.anim_perc.clamp(0.0, 1.0), EndGame::Lose);
+                if let Some(message) = &self.share_message {
+                    draw_message(graphics, message, colors::KEYBOARD_LETTER);
                 }
                 self.end_button_bar
                     .render(graphics, controller.get_controller_type());
             }
-            EngineState::Guessing => self
+            None => self
                 .button_bar
                 .render(graphics, controller.get_controller_type()),
         }
@@ -205,47 +888,54 @@ impl Scene<SceneResult, SceneName> for GameScene {
         mouse_button: MouseButton,
         _: &FxHashSet<KeyCode>,
     ) {
-        if matches!(self.state, GameState::Input) && mouse_button == MouseButton::Left {
-            self.show_error = false;
+        if matches!(self.state, GameState::GameOver) && mouse_button == MouseButton::Left {
+            self.game_over_action();
+        } else if matches!(self.state, GameState::GameOver) && mouse_button == MouseButton::Right {
+            self.share();
+        } else if matches!(self.state, GameState::Input) && mouse_button == MouseButton::Left {
+            self.error = None;
             if let Some(key) = self.keyboard.mouse_click(down_at, mouse.xy) {
-                match key {
-                    Key::Letter(chr) => self.engine.add_letter(chr),
-                    Key::Enter => self.submit(),
-                    Key::Backspace => self.engine.backspace(),
-                }
+                self.press_key(key);
             }
         }
     }
 
+    fn on_key_down(&mut self, key: KeyCode, _: &MouseData, _: &FxHashSet<KeyCode>) {
+        if let Some(key) = typed_key(key) {
+            if matches!(self.state, GameState::Input) {
+                self.error = None;
+                self.keyboard.flash(key);
+                self.press_key(key);
+            }
+        } else if let Some(input) = key_to_input(key) {
+            self.handle_input(input);
+        }
+    }
+
     fn update(
         &mut self,
         timing: &Timing,
         mouse: &MouseData,
-        held_keys: &FxHashSet<KeyCode>,
+        _: &FxHashSet<KeyCode>,
         controller: &GameController,
         _: &Window,
     ) -> SceneUpdateResult<SceneResult, SceneName> {
         if let Some(sound) = &mut self.win_sound {
             sound.update(timing);
         }
-        if self.input_timer.update(timing) {
-            let input = keys_to_input(held_keys, controller);
-            if matches!(self.state, GameState::Input) {
-                if let Some(input) = input {
-                    self.show_error = false;
-                    self.input_timer.reset();
-                    if let Some(key) = self.keyboard.key_press(input) {
-                        match key {
-                            Key::Letter(chr) => self.engine.add_letter(chr),
-                            Key::Enter => self.submit(),
-                            Key::Backspace => self.engine.backspace(),
-                        }
-                    }
-                }
-            }
-            if input == Some(Input::Escape) {
-                return SceneUpdateResult::Pop(None);
-            }
+        self.keyboard.update(timing);
+        if let Some(input) = poll_controller(&mut self.input_timer, timing, controller) {
+            self.handle_input(input);
+        }
+        if self.engine.state == EngineState::Guessing {
+            self.time.seconds += timing.fixed_time_step;
+        }
+        //the clock only runs while the player can type
+        if self.mode == GameMode::TimeAttack
+            && matches!(self.state, GameState::Input)
+            && self.clock.tick(timing.fixed_time_step)
+        {
+            self.times_up();
         }
         if self.anim_timer.update(timing) {
             match self.state {
@@ -259,43 +949,159 @@ impl Scene<SceneResult, SceneName> for GameScene {
                 GameState::AnimEndGame => {
                     self.anim_perc += ANIM_ENDGAME_STEP;
                     if self.anim_perc >= 1.0 {
-                        self.state = GameState::GameOver
+                        if self.chains_games() {
+                            self.next_word();
+                        } else {
+                            self.state = GameState::GameOver
+                        }
                     }
                 }
                 GameState::GameOver => {}
             }
         }
 
+        self.update_advice();
+        self.update_share();
         self.keyboard.mouse_move(mouse.xy);
-        SceneUpdateResult::Nothing
+        self.result.clone().unwrap_or(SceneUpdateResult::Nothing)
+    }
+
+    fn resuming(&mut self, _: Option<SceneResult>) {
+        //returning from analysis
+        self.result = None;
+        self.input_timer.reset();
+    }
+}
+
+fn classic_engine(
+    word_size: usize,
+    difficulty: Difficulty,
+    settings: &mut AppPrefs<Settings>,
+) -> Option<WordleEngine> {
+    if let Some(engine) = settings.data.saved_games.get(&word_size) {
+        return Some(engine.clone());
+    }
+    let seed = settings.data.seed;
+    let order = settings
+        .data
+        .word_order
+        .entry(word_size)
+        .or_insert_with(|| WordOrder::new(seed, word_size).expect("word size is from menu"));
+    //results are kept by word so they still apply if the order is reshuffled
+    order.update();
+    let order = order.clone();
+    settings.save();
+    let progress = settings.data.progress.entry(word_size).or_default();
+    progress
+        .next(&order, &[WordOutcome::Unplayed])
+        .map(|idx| WordleEngine::new(word_size, idx, difficulty).expect("index is from word order"))
+}
+
+/// Next failed or skipped word in the classic order after the last one retried,
+/// `None` if there aren't any
+fn retry_engine(
+    word_size: usize,
+    difficulty: Difficulty,
+    settings: &mut AppPrefs<Settings>,
+) -> Option<WordleEngine> {
+    let order = settings.data.word_order.get_mut(&word_size)?;
+    //the answer list may have changed since the last classic game
+    order.update();
+    let order = order.clone();
+    let progress = settings.data.progress.get(&word_size)?;
+    let outcomes = [WordOutcome::Failed, WordOutcome::Skipped];
+    let idx = match settings.data.last_retry.get(&word_size) {
+        Some(last) => progress.next_after(&order, &outcomes, last),
+        None => progress.next(&order, &outcomes),
+    }?;
+    let engine = WordleEngine::new(word_size, idx, difficulty).expect("index is from word order");
+    settings
+        .data
+        .last_retry
+        .insert(word_size, engine.word.clone());
+    settings.save();
+    Some(engine)
+}
+
+fn practice_engine(word_size: usize, difficulty: Difficulty) -> WordleEngine {
+    let idx = fastrand::usize(..word_count_for_size(word_size));
+    WordleEngine::new(word_size, idx, difficulty).expect("index is in range")
+}
+
+/// Today's puzzle, or the board from earlier today if it was started
+fn daily_engine(
+    word_size: usize,
+    difficulty: Difficulty,
+    day: i64,
+    settings: &AppPrefs<Settings>,
+) -> WordleEngine {
+    match settings.data.daily.get(&word_size) {
+        Some(daily) if daily.day == day => daily.engine.clone(),
+        _ => {
+            let idx = daily_answer_idx(word_size, day).expect("word size is from menu");
+            WordleEngine::new(word_size, idx, difficulty).expect("daily index is in range")
+        }
+    }
+}
+
+pub(super) fn keyboard_pos() -> Coord {
+    coord!(WIDTH / 2, HEIGHT) - (Keyboard::size().0 / 2, Keyboard::size().1) - (0_usize, BAR_HEIGHT)
+}
+
+/// The audio engine has to be kept alive for the sound to play
+pub(super) fn load_win_sound() -> (Option<AudioEngine>, Option<SoundEffect>) {
+    if let Ok(engine) = AudioEngine::new() {
+        if let Ok(win_sound) = engine.load_from_bytes(include_bytes!("../../assets/win.wav"), 1.75)
+        {
+            (Some(engine), Some(win_sound))
+        } else {
+            (None, None)
+        }
+    } else {
+        (None, None)
+    }
+}
+
+pub(super) fn error_message(err: GuessError) -> String {
+    match err {
+        GuessError::WrongLength { expected, actual } if actual < expected => {
+            String::from("Not enough letters")
+        }
+        GuessError::WrongLength { .. } => String::from("Too many letters"),
+        GuessError::InvalidCharacter(_) => String::from("Invalid letter"),
+        GuessError::NotAWord => String::from("Unknown word"),
+        GuessError::GameFinished => String::from("Game over"),
+        GuessError::HardMode(violation) => violation.to_string(),
     }
 }
 
 #[derive(Debug, Clone)]
-enum EndGame {
+pub(super) enum EndGame {
     //number of guesses made, max guesses
     Win(usize, usize),
     Lose,
+    //number of words found
+    TimeUp(usize),
 }
 
 impl EndGame {
     pub fn back(&self) -> Color {
         match self {
-            EndGame::Win(_, _) => colors::WIN_BACK,
+            EndGame::Win(_, _) | EndGame::TimeUp(_) => colors::WIN_BACK,
             EndGame::Lose => colors::LOSE_BACK,
         }
     }
 
     pub fn banner(&self) -> Color {
         match self {
-            EndGame::Win(_, _) => colors::WIN_BANNER,
+            EndGame::Win(_, _) | EndGame::TimeUp(_) => colors::WIN_BANNER,
             EndGame::Lose => colors::LOSE_BANNER,
         }
     }
 
     pub fn text(&self) -> Color {
         match self {
-            EndGame::Win(_, _) => colors::WIN_TEXT,
+            EndGame::Win(_, _) | EndGame::TimeUp(_) => colors::WIN_TEXT,
             EndGame::Lose => colors::LOSE_TEXT,
         }
     }
@@ -304,6 +1110,7 @@ impl EndGame {
         match self {
             EndGame::Win(_, _) => "Congratulations!",
             EndGame::Lose => "Out of guesses!",
+            EndGame::TimeUp(_) => "Time's up!",
         }
     }
 
@@ -322,11 +1129,14 @@ impl EndGame {
                 }
             }
             EndGame::Lose => "Better luck next time",
+            EndGame::TimeUp(0) => "No words found",
+            EndGame::TimeUp(1) => "1 word found",
+            EndGame::TimeUp(_) => "Well played",
         }
     }
 }
 
-fn draw_end_game(graphics: &mut Graphics, perc: f64, end_game: EndGame) {
+pub(super) fn draw_end_game(graphics: &mut Graphics, perc: f64, end_game: EndGame) {
     let anim_offset = coord!(WIDTH.lerp(0, perc as f32), 0);
     let text_offset = (WIDTH * 2).lerp(0, perc as f32);
     let banner_edge_height = 10;
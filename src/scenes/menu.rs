@@ -1,13 +1,39 @@
+use crate::date::{format_countdown, LocalTime};
 use crate::scenes::keys_to_input;
 use crate::ui::button_bar::{ButtonBar, ButtonDef, BAR_HEIGHT};
 use crate::ui::theme::colors;
-use crate::word_list::{FIVE, FOUR, SEVEN, SIX};
-use crate::{Input, SceneName, SceneResult, Settings, HEIGHT, WIDTH};
+use crate::{DailyGame, GameMode, Input, SceneName, SceneResult, Settings, HEIGHT, WIDTH};
 use pixels_graphics_lib::prelude::*;
+use wordle_engine::daily::puzzle_number;
+use wordle_engine::share::score_text;
+use wordle_engine::word_list::word_count_for_size;
+use wordle_engine::{Difficulty, EngineState};
 
 const BUTTON_START: Coord =
-    Coord::new((WIDTH / 2 - 60) as isize, ((HEIGHT as f32) * 0.52) as isize);
-const BUTTON_SIZE: (usize, usize) = (120, 20);
+    Coord::new((WIDTH / 2 - 60) as isize, ((HEIGHT as f32) * 0.49) as isize);
+const BUTTON_SIZE: (usize, usize) = (120, 18);
+const BUTTON_SPACING: usize = 19;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum MenuButton {
+    Play,
+    Daily,
+    Modes,
+    Codes,
+    Stats,
+    Difficulty,
+    Exit,
+}
+
+const BUTTONS: [MenuButton; 7] = [
+    MenuButton::Play,
+    MenuButton::Daily,
+    MenuButton::Modes,
+    MenuButton::Codes,
+    MenuButton::Stats,
+    MenuButton::Difficulty,
+    MenuButton::Exit,
+];
 
 const SIZE_BUTTON_SIZE: usize = 20;
 const SIZE_BUTTON_START: Coord = Coord::new(
@@ -17,6 +43,8 @@ const SIZE_BUTTON_START: Coord = Coord::new(
 
 const SIZE_REMAINING_POS: Coord =
     Coord::new((WIDTH / 2) as isize, ((HEIGHT as f32) * 0.3) as isize + 32);
+const DAILY_POS: Coord = Coord::new(SIZE_REMAINING_POS.x, SIZE_REMAINING_POS.y + 14);
+const LAST_GAME_POS: Coord = Coord::new((WIDTH / 2) as isize, 60);
 
 pub struct MenuScene {
     result: Option<SceneUpdateResult<SceneResult, SceneName>>,
@@ -24,7 +52,9 @@ pub struct MenuScene {
     button_idx: usize,
     button_bar: ButtonBar,
     input_timer: Timer,
-    size_idxs: [usize; 4],
+    /// Latest game closed after it was won or lost
+    last_game: Option<SceneResult>,
+    settings: AppPrefs<Settings>,
 }
 
 impl MenuScene {
@@ -44,12 +74,8 @@ impl MenuScene {
             ),
             input_timer: Timer::new_once(0.3),
             result: None,
-            size_idxs: [
-                settings.data.word_idx.get(&4).copied().unwrap_or_default(),
-                settings.data.word_idx.get(&5).copied().unwrap_or_default(),
-                settings.data.word_idx.get(&6).copied().unwrap_or_default(),
-                settings.data.word_idx.get(&7).copied().unwrap_or_default(),
-            ],
+            last_game: None,
+            settings,
         })
     }
 }
@@ -74,19 +100,26 @@ impl MenuScene {
             graphics.draw_rect(rect, stroke(colors::MENU_DEFAULT));
         }
 
-        let max = match self.size_idx {
-            0 => FOUR.len(),
-            1 => FIVE.len(),
-            2 => SIX.len(),
-            3 => SEVEN.len(),
-            _ => panic!("invalid size_idx {}", self.size_idx),
-        };
-        let count = self.size_idxs[self.size_idx];
+        let max = word_count_for_size(self.size_idx + 4);
+        let counts = self
+            .settings
+            .data
+            .progress
+            .get(&(self.size_idx + 4))
+            .map(|progress| progress.counts(self.size_idx + 4))
+            .unwrap_or_default();
 
-        let (text, color) = if max == count {
+        let (text, color) = if counts.solved >= max {
             ("All done!".to_string(), colors::WIN_BANNER)
         } else {
-            (format!("{}/{}", count, max), colors::MENU_DEFAULT)
+            let mut text = format!("{}/{} solved", counts.solved, max);
+            if counts.failed > 0 {
+                text.push_str(&format!(", {} failed", counts.failed));
+            }
+            if counts.skipped > 0 {
+                text.push_str(&format!(", {} skipped", counts.skipped));
+            }
+            (text, colors::MENU_DEFAULT)
         };
         graphics.draw_text(
             &text,
@@ -94,6 +127,8 @@ impl MenuScene {
             (color, PixelFont::Standard6x7, Positioning::Center),
         );
 
+        self.draw_daily_status(graphics);
+
         let rect = Rect::new_with_size(
             SIZE_BUTTON_START + (self.size_idx * SIZE_BUTTON_SIZE, 0) - (1, 0),
             SIZE_BUTTON_SIZE,
@@ -102,16 +137,218 @@ impl MenuScene {
         graphics.draw_rect(rect, stroke(colors::MENU_SELECTED));
     }
 
+    fn draw_daily_status(&self, graphics: &mut Graphics) {
+        let now = LocalTime::now();
+        let countdown = format_countdown(now.seconds_until_tomorrow());
+        let text = match self.todays_daily(now.day).map(|daily| &daily.engine) {
+            Some(engine) if engine.state == EngineState::Found => {
+                let hinted = if engine.hints > 0 { " with hints" } else { "" };
+                format!(
+                    "Daily solved {}/{}{hinted}, next in {countdown}",
+                    engine.guesses.len(),
+                    engine.max_guess_count
+                )
+            }
+            Some(engine) if engine.state == EngineState::OutOfGuesses => {
+                format!("Daily failed, next in {countdown}")
+            }
+            _ => format!("Daily #{} ends in {countdown}", puzzle_number(now.day)),
+        };
+        graphics.draw_text(
+            &text,
+            TextPos::px(DAILY_POS),
+            (
+                colors::MENU_DEFAULT,
+                PixelFont::Standard4x5,
+                Positioning::Center,
+            ),
+        );
+    }
+
+    /// Mode, size and result of the latest game, with the streak for that size and mode
+    fn draw_last_game(&self, graphics: &mut Graphics) {
+        let lines = match &self.last_game {
+            None => return,
+            Some(SceneResult::GameOver(engine, mode)) => {
+                let streak = self
+                    .settings
+                    .data
+                    .stats
+                    .get(&engine.word_size)
+                    .and_then(|modes| modes.get(mode))
+                    .map(|stats| stats.current_streak)
+                    .unwrap_or_default();
+                let result = if engine.state == EngineState::Found {
+                    format!("Solved {}, streak {streak}", score_text(engine))
+                } else {
+                    format!("Missed {}, streak {streak}", engine.word)
+                };
+                [
+                    format!("Last game: {}, {} letters", mode.name(), engine.word_size),
+                    result,
+                ]
+            }
+            //multi board games don't have stats so there's no streak
+            Some(SceneResult::MultiGameOver(engine)) => {
+                let solved = engine
+                    .boards
+                    .iter()
+                    .filter(|board| board.state == EngineState::Found)
+                    .count();
+                let result = if engine.state == EngineState::Found {
+                    format!(
+                        "Solved all in {}/{}",
+                        engine.guess_count(),
+                        engine.max_guess_count
+                    )
+                } else {
+                    format!("Solved {solved} of {}", engine.boards.len())
+                };
+                [
+                    format!(
+                        "Last game: {} boards, {} letters",
+                        engine.boards.len(),
+                        engine.word_size
+                    ),
+                    result,
+                ]
+            }
+        };
+        for (i, line) in lines.iter().enumerate() {
+            graphics.draw_text(
+                line,
+                TextPos::px(LAST_GAME_POS + (0, i * 8)),
+                (
+                    colors::MENU_DEFAULT,
+                    PixelFont::Standard4x5,
+                    Positioning::Center,
+                ),
+            );
+        }
+    }
+
+    /// Today's daily puzzle for the selected size, if it's been started
+    fn todays_daily(&self, day: i64) -> Option<&DailyGame> {
+        self.settings
+            .data
+            .daily
+            .get(&(self.size_idx + 4))
+            .filter(|daily| daily.day == day)
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        self.settings
+            .data
+            .difficulty
+            .get(&(self.size_idx + 4))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn change_difficulty(&mut self) {
+        let next = match self.difficulty() {
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::UltraHard,
+            Difficulty::UltraHard => Difficulty::Normal,
+        };
+        //the game scene may have changed the settings
+        self.settings.reload();
+        self.settings
+            .data
+            .difficulty
+            .insert(self.size_idx + 4, next);
+        self.settings.save();
+    }
+
+    fn button_label(&self, button: MenuButton) -> &'static str {
+        match button {
+            MenuButton::Play => {
+                if self
+                    .settings
+                    .data
+                    .saved_games
+                    .contains_key(&(self.size_idx + 4))
+                {
+                    "RESUME"
+                } else {
+                    "PLAY"
+                }
+            }
+            MenuButton::Daily => match self
+                .todays_daily(LocalTime::now().day)
+                .map(|daily| daily.engine.state)
+            {
+                None => "DAILY",
+                Some(EngineState::Guessing) => "RESUME DAILY",
+                Some(_) => "DAILY DONE",
+            },
+            MenuButton::Modes => "MODES",
+            MenuButton::Codes => "PUZZLE CODES",
+            MenuButton::Stats => "STATS",
+            MenuButton::Difficulty => match self.difficulty() {
+                Difficulty::Normal => "NORMAL",
+                Difficulty::Hard => "HARD",
+                Difficulty::UltraHard => "ULTRA HARD",
+            },
+            MenuButton::Exit => "EXIT",
+        }
+    }
+
+    fn button_rect(idx: usize) -> Rect {
+        Rect::new_with_size(
+            BUTTON_START + (0, idx * BUTTON_SPACING),
+            BUTTON_SIZE.0,
+            BUTTON_SIZE.1,
+        )
+    }
+
+    fn press_button(
+        &mut self,
+        button: MenuButton,
+    ) -> Option<SceneUpdateResult<SceneResult, SceneName>> {
+        match button {
+            MenuButton::Play => Some(SceneUpdateResult::Push(
+                false,
+                SceneName::Game(self.size_idx + 4, GameMode::Classic),
+            )),
+            MenuButton::Daily => Some(SceneUpdateResult::Push(
+                false,
+                SceneName::Game(self.size_idx + 4, GameMode::Daily),
+            )),
+            MenuButton::Modes => Some(SceneUpdateResult::Push(
+                false,
+                SceneName::Modes(self.size_idx + 4),
+            )),
+            MenuButton::Codes => Some(SceneUpdateResult::Push(false, SceneName::Codes)),
+            MenuButton::Stats => {
+                //stats open on the mode that was just played
+                let (engine, mode) = match &self.last_game {
+                    Some(SceneResult::GameOver(engine, mode)) => (Some(engine.clone()), *mode),
+                    Some(SceneResult::MultiGameOver(_)) | None => (None, GameMode::Classic),
+                };
+                Some(SceneUpdateResult::Push(
+                    false,
+                    SceneName::Stats(self.size_idx + 4, mode, engine),
+                ))
+            }
+            MenuButton::Difficulty => {
+                self.change_difficulty();
+                None
+            }
+            MenuButton::Exit => Some(SceneUpdateResult::Pop(None)),
+        }
+    }
+
     fn draw_buttons(&self, graphics: &mut Graphics) {
-        let draw_button = |g: &mut Graphics, text: &str, pos: Coord, selected: bool| {
-            let rect = Rect::new_with_size(pos, BUTTON_SIZE.0, BUTTON_SIZE.1);
-            let back = if selected {
+        for (i, button) in BUTTONS.iter().enumerate() {
+            let rect = MenuScene::button_rect(i);
+            let back = if self.button_idx == i {
                 colors::MENU_SELECTED
             } else {
                 colors::MENU_DEFAULT
             };
-            g.draw_text(
-                text,
+            graphics.draw_text(
+                self.button_label(*button),
                 TextPos::px(rect.center() + (1, 1)),
                 (
                     colors::MENU_SELECTED,
@@ -119,16 +356,8 @@ impl MenuScene {
                     Positioning::Center,
                 ),
             );
-            g.draw_rect(rect, stroke(back));
-        };
-
-        draw_button(graphics, "PLAY", BUTTON_START, self.button_idx == 0);
-        draw_button(
-            graphics,
-            "EXIT",
-            BUTTON_START + (0, 30),
-            self.button_idx == 1,
-        );
+            graphics.draw_rect(rect, stroke(back));
+        }
     }
 }
 
@@ -153,6 +382,7 @@ impl Scene<SceneResult, SceneName> for MenuScene {
         self.button_bar
             .render(graphics, controller.get_controller_type());
 
+        self.draw_last_game(graphics);
         self.draw_size_buttons(graphics);
         self.draw_buttons(graphics);
     }
@@ -174,17 +404,12 @@ impl Scene<SceneResult, SceneName> for MenuScene {
                     self.size_idx = down;
                 }
             }
-            let play_button = Rect::new_with_size(BUTTON_START, BUTTON_SIZE.0, BUTTON_SIZE.1);
-            if play_button.contains(down_at) && play_button.contains(mouse.xy) {
-                self.result = Some(SceneUpdateResult::Push(
-                    false,
-                    SceneName::Game(self.size_idx + 4),
-                ));
-            }
-            let exit_button =
-                Rect::new_with_size(BUTTON_START + (0, 30), BUTTON_SIZE.0, BUTTON_SIZE.1);
-            if exit_button.contains(down_at) && exit_button.contains(mouse.xy) {
-                self.result = Some(SceneUpdateResult::Pop(None));
+            for (i, button) in BUTTONS.iter().enumerate() {
+                let rect = MenuScene::button_rect(i);
+                if rect.contains(down_at) && rect.contains(mouse.xy) {
+                    self.button_idx = i;
+                    self.result = self.press_button(*button);
+                }
             }
         }
     }
@@ -201,25 +426,20 @@ impl Scene<SceneResult, SceneName> for MenuScene {
             if let Some(input) = keys_to_input(held_keys, controller) {
                 self.input_timer.reset();
                 match input {
-                    Input::Action => match self.button_idx {
-                        0 => {
-                            return SceneUpdateResult::Push(
-                                false,
-                                SceneName::Game(self.size_idx + 4),
-                            )
+                    Input::Action => {
+                        if let Some(result) = self.press_button(BUTTONS[self.button_idx]) {
+                            return result;
                         }
-                        1 => return SceneUpdateResult::Pop(None),
-                        _ => {}
-                    },
+                    }
                     Input::Up => {
                         if self.button_idx == 0 {
-                            self.button_idx = 1;
+                            self.button_idx = BUTTONS.len() - 1;
                         } else {
                             self.button_idx -= 1;
                         }
                     }
                     Input::Down => {
-                        if self.button_idx == 1 {
+                        if self.button_idx == BUTTONS.len() - 1 {
                             self.button_idx = 0;
                         } else {
                             self.button_idx += 1;
@@ -240,6 +460,7 @@ impl Scene<SceneResult, SceneName> for MenuScene {
                         }
                     }
                     Input::Escape => return SceneUpdateResult::Pop(None),
+                    Input::Secondary | Input::Tertiary => {}
                 }
             }
         }
@@ -254,7 +475,12 @@ impl Scene<SceneResult, SceneName> for MenuScene {
         self.result.clone().unwrap_or(SceneUpdateResult::Nothing)
     }
 
-    fn resuming(&mut self, _: Option<SceneResult>) {
+    fn resuming(&mut self, result: Option<SceneResult>) {
+        //the game may have saved or finished a board, the progress and stats are read from the settings
+        self.settings.reload();
+        if result.is_some() {
+            self.last_game = result;
+        }
         self.result = None;
         self.input_timer.reset();
     }
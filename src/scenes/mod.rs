@@ -1,8 +1,16 @@
+use crate::ui::keyboard::Key;
 use crate::Input;
 use pixels_graphics_lib::prelude::*;
 
+pub mod analysis;
+pub mod code;
 pub mod game;
 pub mod menu;
+pub mod modes;
+pub mod multi;
+pub mod replay;
+pub mod stats;
+pub mod two_player;
 
 fn keys_to_input(keys: &FxHashSet<KeyCode>, controller: &GameController) -> Option<Input> {
     if controller.direction.up || keys.contains(&KeyCode::ArrowUp) {
@@ -17,7 +25,85 @@ fn keys_to_input(keys: &FxHashSet<KeyCode>, controller: &GameController) -> Opti
         Some(Input::Action)
     } else if controller.action.east || keys.contains(&KeyCode::Escape) {
         Some(Input::Escape)
+    } else if controller.action.north || keys.contains(&KeyCode::Tab) {
+        Some(Input::Secondary)
+    } else if controller.action.west || keys.contains(&KeyCode::F1) {
+        Some(Input::Tertiary)
     } else {
         None
     }
 }
+
+fn controller_to_input(controller: &GameController) -> Option<Input> {
+    keys_to_input(&FxHashSet::default(), controller)
+}
+
+/// Controller input once `input_timer` allows it, for scenes that take key presses
+///
+/// Controllers don't send press events so are polled instead, the timer is reset on input
+fn poll_controller(
+    input_timer: &mut Timer,
+    timing: &Timing,
+    controller: &GameController,
+) -> Option<Input> {
+    if !input_timer.update(timing) {
+        return None;
+    }
+    let input = controller_to_input(controller);
+    if input.is_some() {
+        input_timer.reset();
+    }
+    input
+}
+
+/// Converts a single key press into cursor input
+/// Enter is not included as it's used for submitting, see [typed_key]
+fn key_to_input(key: KeyCode) -> Option<Input> {
+    match key {
+        KeyCode::ArrowUp => Some(Input::Up),
+        KeyCode::ArrowDown => Some(Input::Down),
+        KeyCode::ArrowLeft => Some(Input::Left),
+        KeyCode::ArrowRight => Some(Input::Right),
+        KeyCode::Space => Some(Input::Action),
+        KeyCode::Escape => Some(Input::Escape),
+        KeyCode::Tab => Some(Input::Secondary),
+        KeyCode::F1 => Some(Input::Tertiary),
+        _ => None,
+    }
+}
+
+/// Converts a physical key press into the on screen key it represents
+fn typed_key(key: KeyCode) -> Option<Key> {
+    let chr = match key {
+        KeyCode::Enter | KeyCode::NumpadEnter => return Some(Key::Enter),
+        KeyCode::Backspace | KeyCode::Delete => return Some(Key::Backspace),
+        KeyCode::KeyA => 'A',
+        KeyCode::KeyB => 'B',
+        KeyCode::KeyC => 'C',
+        KeyCode::KeyD => 'D',
+        KeyCode::KeyE => 'E',
+        KeyCode::KeyF => 'F',
+        KeyCode::KeyG => 'G',
+        KeyCode::KeyH => 'H',
+        KeyCode::KeyI => 'I',
+        KeyCode::KeyJ => 'J',
+        KeyCode::KeyK => 'K',
+        KeyCode::KeyL => 'L',
+        KeyCode::KeyM => 'M',
+        KeyCode::KeyN => 'N',
+        KeyCode::KeyO => 'O',
+        KeyCode::KeyP => 'P',
+        KeyCode::KeyQ => 'Q',
+        KeyCode::KeyR => 'R',
+        KeyCode::KeyS => 'S',
+        KeyCode::KeyT => 'T',
+        KeyCode::KeyU => 'U',
+        KeyCode::KeyV => 'V',
+        KeyCode::KeyW => 'W',
+        KeyCode::KeyX => 'X',
+        KeyCode::KeyY => 'Y',
+        KeyCode::KeyZ => 'Z',
+        _ => return None,
+    };
+    Some(Key::Letter(chr))
+}
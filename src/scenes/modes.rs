@@ -0,0 +1,267 @@
+use crate::scenes::keys_to_input;
+use crate::ui::button_bar::{ButtonBar, ButtonDef, BAR_HEIGHT};
+use crate::ui::theme::colors;
+use crate::{GameMode, Input, SceneName, SceneResult, Settings, HEIGHT, WIDTH};
+use pixels_graphics_lib::prelude::*;
+use wordle_engine::time_attack::TIME_LIMIT;
+
+const BUTTON_START: Coord = Coord::new((WIDTH / 2 - 60) as isize, 66);
+const BUTTON_SIZE: (usize, usize) = (120, 20);
+const BUTTON_SPACING: usize = 21;
+const DESCRIPTION_POS: Coord =
+    Coord::new((WIDTH / 2) as isize, (HEIGHT - BAR_HEIGHT - 14) as isize);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ModeButton {
+    Practice,
+    Retry,
+    Absurdle,
+    Endless,
+    TimeAttack,
+    TwoPlayer,
+    /// Number of boards
+    Multi(usize),
+}
+
+const BUTTONS: [ModeButton; 9] = [
+    ModeButton::Practice,
+    ModeButton::Retry,
+    ModeButton::Absurdle,
+    ModeButton::Endless,
+    ModeButton::TimeAttack,
+    ModeButton::TwoPlayer,
+    ModeButton::Multi(2),
+    ModeButton::Multi(4),
+    ModeButton::Multi(8),
+];
+
+impl ModeButton {
+    /// `retry_words` is the number of failed or skipped words
+    fn label(&self, retry_words: usize) -> String {
+        match self {
+            ModeButton::Practice => String::from("PRACTICE"),
+            ModeButton::Retry => format!("RETRY FAILED ({retry_words})"),
+            ModeButton::Absurdle => String::from("ABSURDLE"),
+            ModeButton::Endless => String::from("ENDLESS"),
+            ModeButton::TimeAttack => String::from("TIME ATTACK"),
+            ModeButton::TwoPlayer => String::from("TWO PLAYER"),
+            ModeButton::Multi(boards) => format!("{boards} BOARDS"),
+        }
+    }
+
+    fn description(&self, word_size: usize) -> String {
+        match self {
+            ModeButton::Practice => String::from("Random word, doesn't affect progress"),
+            ModeButton::Retry => String::from("Play failed and skipped words again"),
+            ModeButton::Absurdle => String::from("The word changes to dodge your guesses"),
+            ModeButton::Endless => String::from("Keep solving words until you lose one"),
+            ModeButton::TimeAttack => format!(
+                "Solve as many words as you can in {} minutes",
+                TIME_LIMIT as usize / 60
+            ),
+            ModeButton::TwoPlayer => String::from("Take turns picking words for each other"),
+            ModeButton::Multi(boards) => format!(
+                "Solve {boards} words at once in {} guesses",
+                word_size + boards
+            ),
+        }
+    }
+
+    fn scene(&self, word_size: usize) -> SceneName {
+        match self {
+            ModeButton::Practice => SceneName::Game(word_size, GameMode::Practice),
+            ModeButton::Retry => SceneName::Game(word_size, GameMode::Retry),
+            ModeButton::Absurdle => SceneName::Game(word_size, GameMode::Absurdle),
+            ModeButton::Endless => SceneName::Game(word_size, GameMode::Endless),
+            ModeButton::TimeAttack => SceneName::Game(word_size, GameMode::TimeAttack),
+            ModeButton::TwoPlayer => SceneName::TwoPlayer(word_size),
+            ModeButton::Multi(boards) => SceneName::Multi(word_size, *boards),
+        }
+    }
+}
+
+/// Other ways to play, for the word size picked on the menu
+pub struct ModesScene {
+    result: Option<SceneUpdateResult<SceneResult, SceneName>>,
+    word_size: usize,
+    button_idx: usize,
+    button_bar: ButtonBar,
+    input_timer: Timer,
+    /// Latest finished game, passed on to the menu when this closes
+    last_game: Option<SceneResult>,
+    settings: AppPrefs<Settings>,
+}
+
+impl ModesScene {
+    pub fn new(word_size: usize, settings: AppPrefs<Settings>) -> Box<Self> {
+        Box::new(ModesScene {
+            result: None,
+            word_size,
+            button_idx: 0,
+            button_bar: ButtonBar::new(
+                coord!(0, HEIGHT - BAR_HEIGHT),
+                WIDTH,
+                &[
+                    ("SELECT", ButtonDef::Select),
+                    ("BUTTON", ButtonDef::Vert),
+                    ("BACK", ButtonDef::Escape),
+                ],
+            ),
+            input_timer: Timer::new_once(0.3),
+            last_game: None,
+            settings,
+        })
+    }
+}
+
+impl ModesScene {
+    fn button_rect(idx: usize) -> Rect {
+        Rect::new_with_size(
+            BUTTON_START + (0, idx * BUTTON_SPACING),
+            BUTTON_SIZE.0,
+            BUTTON_SIZE.1,
+        )
+    }
+
+    /// Number of classic words that can be retried
+    fn retry_words(&self) -> usize {
+        self.settings
+            .data
+            .progress
+            .get(&self.word_size)
+            .map(|progress| {
+                let counts = progress.counts(self.word_size);
+                counts.failed + counts.skipped
+            })
+            .unwrap_or_default()
+    }
+
+    fn press_button(&mut self, button: ModeButton) {
+        if button == ModeButton::Retry && self.retry_words() == 0 {
+            return;
+        }
+        self.result = Some(SceneUpdateResult::Push(false, button.scene(self.word_size)));
+    }
+
+    fn draw_buttons(&self, graphics: &mut Graphics) {
+        for (i, button) in BUTTONS.iter().enumerate() {
+            let rect = ModesScene::button_rect(i);
+            let back = if self.button_idx == i {
+                colors::MENU_SELECTED
+            } else {
+                colors::MENU_DEFAULT
+            };
+            graphics.draw_text(
+                &button.label(self.retry_words()),
+                TextPos::px(rect.center() + (1, 1)),
+                (
+                    colors::MENU_SELECTED,
+                    PixelFont::Standard6x7,
+                    Positioning::Center,
+                ),
+            );
+            graphics.draw_rect(rect, stroke(back));
+        }
+    }
+}
+
+impl Scene<SceneResult, SceneName> for ModesScene {
+    fn render(
+        &self,
+        graphics: &mut Graphics,
+        _: &MouseData,
+        _: &FxHashSet<KeyCode>,
+        controller: &GameController,
+    ) {
+        graphics.clear(colors::BACKGROUND);
+        graphics.draw_text(
+            "Modes",
+            TextPos::px(coord!(WIDTH / 2, 30)),
+            (
+                colors::KEYBOARD_LETTER,
+                PixelFont::Standard8x10,
+                Positioning::Center,
+            ),
+        );
+        graphics.draw_text(
+            &format!("{} letters", self.word_size),
+            TextPos::px(coord!(WIDTH / 2, 48)),
+            (
+                colors::MENU_DEFAULT,
+                PixelFont::Standard6x7,
+                Positioning::Center,
+            ),
+        );
+        self.draw_buttons(graphics);
+        graphics.draw_text(
+            &BUTTONS[self.button_idx].description(self.word_size),
+            TextPos::px(DESCRIPTION_POS),
+            (colors::HINT, PixelFont::Standard4x5, Positioning::Center),
+        );
+        self.button_bar
+            .render(graphics, controller.get_controller_type());
+    }
+
+    fn on_mouse_click(
+        &mut self,
+        down_at: Coord,
+        mouse: &MouseData,
+        mouse_button: MouseButton,
+        _: &FxHashSet<KeyCode>,
+    ) {
+        if mouse_button == MouseButton::Left {
+            for (i, button) in BUTTONS.iter().enumerate() {
+                let rect = ModesScene::button_rect(i);
+                if rect.contains(down_at) && rect.contains(mouse.xy) {
+                    self.button_idx = i;
+                    self.press_button(*button);
+                }
+            }
+        }
+    }
+
+    fn update(
+        &mut self,
+        timing: &Timing,
+        _: &MouseData,
+        held_keys: &FxHashSet<KeyCode>,
+        controller: &GameController,
+        _: &Window,
+    ) -> SceneUpdateResult<SceneResult, SceneName> {
+        if self.input_timer.update(timing) {
+            if let Some(input) = keys_to_input(held_keys, controller) {
+                self.input_timer.reset();
+                match input {
+                    Input::Action => self.press_button(BUTTONS[self.button_idx]),
+                    Input::Up => {
+                        if self.button_idx == 0 {
+                            self.button_idx = BUTTONS.len() - 1;
+                        } else {
+                            self.button_idx -= 1;
+                        }
+                    }
+                    Input::Down => {
+                        if self.button_idx == BUTTONS.len() - 1 {
+                            self.button_idx = 0;
+                        } else {
+                            self.button_idx += 1;
+                        }
+                    }
+                    Input::Escape => return SceneUpdateResult::Pop(self.last_game.clone()),
+                    Input::Left | Input::Right | Input::Secondary | Input::Tertiary => {}
+                }
+            }
+        }
+        self.result.clone().unwrap_or(SceneUpdateResult::Nothing)
+    }
+
+    fn resuming(&mut self, result: Option<SceneResult>) {
+        if result.is_some() {
+            self.last_game = result;
+        }
+        //retried words may have been solved
+        self.settings.reload();
+        self.result = None;
+        self.input_timer.reset();
+    }
+}
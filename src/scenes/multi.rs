@@ -0,0 +1,259 @@
+use crate::scenes::game::{
+    draw_end_game, error_message, keyboard_pos, load_win_sound, EndGame, ANIM_ENDGAME_STEP,
+    ANIM_UPDATE_RATE,
+};
+use crate::scenes::{key_to_input, poll_controller, typed_key};
+use crate::ui::button_bar::{ButtonBar, ButtonDef, BAR_HEIGHT};
+use crate::ui::keyboard::{Key, Keyboard};
+use crate::ui::theme::colors;
+use crate::ui::wordle_renderer::render_boards;
+use crate::{Input, SceneName, SceneResult, Settings, HEIGHT, WIDTH};
+use pixels_graphics_lib::prelude::*;
+use wordle_engine::word_list::word_count_for_size;
+use wordle_engine::{Difficulty, EngineState, MultiEngine};
+
+/// Space between the boards and the message above the keyboard
+const MESSAGE_HEIGHT: usize = 16;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum GameState {
+    Input,
+    AnimEndGame,
+    GameOver,
+}
+
+/// Several random words at once, guesses are entered once and scored on every board
+///
+/// Like practice these games don't affect progress or stats
+pub struct MultiGameScene {
+    result: Option<SceneUpdateResult<SceneResult, SceneName>>,
+    engine: MultiEngine,
+    difficulty: Difficulty,
+    keyboard: Keyboard,
+    button_bar: ButtonBar,
+    end_button_bar: ButtonBar,
+    input_timer: Timer,
+    error: Option<String>,
+    anim_timer: Timer,
+    state: GameState,
+    anim_perc: f64,
+    #[allow(unused)] //needed to play sound
+    audio_engine: Option<AudioEngine>,
+    win_sound: Option<SoundEffect>,
+    /// Latest finished game, passed on to the menu when this closes
+    last_game: Option<MultiEngine>,
+}
+
+impl MultiGameScene {
+    pub fn new(word_size: usize, boards: usize, settings: AppPrefs<Settings>) -> Box<Self> {
+        let (audio_engine, win_sound) = load_win_sound();
+        let difficulty = settings
+            .data
+            .difficulty
+            .get(&word_size)
+            .copied()
+            .unwrap_or_default();
+        Box::new(MultiGameScene {
+            result: None,
+            engine: multi_engine(word_size, boards, difficulty),
+            difficulty,
+            keyboard: Keyboard::new_multi(keyboard_pos(), boards),
+            button_bar: ButtonBar::new(
+                coord!(0, HEIGHT - BAR_HEIGHT),
+                WIDTH,
+                &[
+                    ("CURSOR", ButtonDef::Cursor),
+                    ("SELECT", ButtonDef::Select),
+                    ("CLOSE", ButtonDef::Escape),
+                ],
+            ),
+            end_button_bar: ButtonBar::new(
+                coord!(0, HEIGHT - BAR_HEIGHT),
+                WIDTH,
+                &[("AGAIN", ButtonDef::Select), ("CLOSE", ButtonDef::Escape)],
+            ),
+            input_timer: Timer::new_once(0.3),
+            error: None,
+            anim_timer: Timer::new(ANIM_UPDATE_RATE),
+            state: GameState::Input,
+            anim_perc: 0.0,
+            audio_engine,
+            win_sound,
+            last_game: None,
+        })
+    }
+}
+
+impl MultiGameScene {
+    fn press_key(&mut self, key: Key) {
+        match key {
+            Key::Letter(chr) => {
+                if let Err(err) = self.engine.add_letter(chr) {
+                    self.error = Some(error_message(err));
+                }
+            }
+            Key::Enter => self.submit(),
+            Key::Backspace => self.engine.backspace(),
+        }
+    }
+
+    fn submit(&mut self) {
+        match self.engine.submit() {
+            Ok(results) => {
+                for (board, info) in results.iter().enumerate() {
+                    if let Some(info) = info {
+                        self.keyboard.add_board_guess(board, info);
+                    }
+                }
+                if self.engine.state != EngineState::Guessing {
+                    self.last_game = Some(self.engine.clone());
+                    self.anim_perc = 0.0;
+                    self.state = GameState::AnimEndGame;
+                    if self.engine.state == EngineState::Found {
+                        if let Some(sound) = &mut self.win_sound {
+                            sound.play();
+                        }
+                    }
+                }
+            }
+            Err(err) => self.error = Some(error_message(err)),
+        }
+    }
+
+    /// Start again with new words on the same number of boards
+    fn new_game(&mut self) {
+        let boards = self.engine.boards.len();
+        self.engine = multi_engine(self.engine.word_size, boards, self.difficulty);
+        self.keyboard = Keyboard::new_multi(keyboard_pos(), boards);
+        self.state = GameState::Input;
+        self.anim_perc = 0.0;
+        self.error = None;
+    }
+
+    fn handle_input(&mut self, input: Input) {
+        if input == Input::Escape {
+            self.result = Some(SceneUpdateResult::Pop(
+                self.last_game.clone().map(SceneResult::MultiGameOver),
+            ));
+        } else if input == Input::Action && self.state == GameState::GameOver {
+            self.new_game();
+        } else if self.state == GameState::Input {
+            self.error = None;
+            if let Some(key) = self.keyboard.key_press(input) {
+                self.press_key(key);
+            }
+        }
+    }
+}
+
+impl Scene<SceneResult, SceneName> for MultiGameScene {
+    fn render(
+        &self,
+        graphics: &mut Graphics,
+        _: &MouseData,
+        _: &FxHashSet<KeyCode>,
+        controller: &GameController,
+    ) {
+        graphics.clear(colors::BACKGROUND);
+        self.keyboard.render(graphics);
+        let message_y = keyboard_pos().y as usize - MESSAGE_HEIGHT;
+        render_boards(
+            graphics,
+            &Rect::new_with_size(coord!(0, 6), WIDTH, message_y - 8),
+            &self.engine.boards,
+        );
+
+        if let Some(error) = &self.error {
+            graphics.draw_text(
+                error,
+                TextPos::px(coord!(WIDTH / 2, message_y + 4)),
+                (colors::ERROR, PixelFont::Standard6x7, Positioning::Center),
+            );
+        }
+
+        if self.engine.state == EngineState::Guessing {
+            self.button_bar
+                .render(graphics, controller.get_controller_type());
+        } else {
+            if self.state != GameState::Input {
+                let end_game = if self.engine.state == EngineState::Found {
+                    EndGame::Win(self.engine.guess_count(), self.engine.max_guess_count)
+                } else {
+                    EndGame::Lose
+                };
+                draw_end_game(graphics, self.anim_perc.clamp(0.0, 1.0), end_game);
+            }
+            self.end_button_bar
+                .render(graphics, controller.get_controller_type());
+        }
+    }
+
+    fn on_mouse_click(
+        &mut self,
+        down_at: Coord,
+        mouse: &MouseData,
+        mouse_button: MouseButton,
+        _: &FxHashSet<KeyCode>,
+    ) {
+        if mouse_button != MouseButton::Left {
+            return;
+        }
+        if self.state == GameState::GameOver {
+            self.new_game();
+        } else if self.state == GameState::Input {
+            self.error = None;
+            if let Some(key) = self.keyboard.mouse_click(down_at, mouse.xy) {
+                self.press_key(key);
+            }
+        }
+    }
+
+    fn on_key_down(&mut self, key: KeyCode, _: &MouseData, _: &FxHashSet<KeyCode>) {
+        if let Some(key) = typed_key(key) {
+            if self.state == GameState::Input {
+                self.error = None;
+                self.keyboard.flash(key);
+                self.press_key(key);
+            }
+        } else if let Some(input) = key_to_input(key) {
+            self.handle_input(input);
+        }
+    }
+
+    fn update(
+        &mut self,
+        timing: &Timing,
+        mouse: &MouseData,
+        _: &FxHashSet<KeyCode>,
+        controller: &GameController,
+        _: &Window,
+    ) -> SceneUpdateResult<SceneResult, SceneName> {
+        if let Some(sound) = &mut self.win_sound {
+            sound.update(timing);
+        }
+        self.keyboard.update(timing);
+        if let Some(input) = poll_controller(&mut self.input_timer, timing, controller) {
+            self.handle_input(input);
+        }
+        if self.anim_timer.update(timing) && self.state == GameState::AnimEndGame {
+            self.anim_perc += ANIM_ENDGAME_STEP;
+            if self.anim_perc >= 1.0 {
+                self.state = GameState::GameOver;
+            }
+        }
+        self.keyboard.mouse_move(mouse.xy);
+        self.result.clone().unwrap_or(SceneUpdateResult::Nothing)
+    }
+
+    fn resuming(&mut self, _: Option<SceneResult>) {
+        self.result = None;
+        self.input_timer.reset();
+    }
+}
+
+/// Game with a different random word on each board
+fn multi_engine(word_size: usize, boards: usize, difficulty: Difficulty) -> MultiEngine {
+    let mut idxs = fastrand::choose_multiple(0..word_count_for_size(word_size), boards);
+    fastrand::shuffle(&mut idxs);
+    MultiEngine::new(word_size, &idxs, difficulty).expect("word size is from menu")
+}
@@ -0,0 +1,421 @@
+use crate::date::{format_days_ago, LocalTime};
+use crate::history::{load_games, Record};
+use crate::scenes::game::{ANIM_GUESS_STEP, ANIM_UPDATE_RATE};
+use crate::scenes::keys_to_input;
+use crate::ui::button_bar::{ButtonBar, ButtonDef, BAR_HEIGHT};
+use crate::ui::theme::colors;
+use crate::ui::wordle_renderer::{render_field, render_guess_field};
+use crate::{Input, SceneName, SceneResult, HEIGHT, WIDTH};
+use pixels_graphics_lib::prelude::*;
+use wordle_engine::history::Outcome;
+use wordle_engine::SubmittedGuessInfo;
+
+const LIST_START: usize = 34;
+const LIST_ROW_HEIGHT: usize = 24;
+/// Games that fit above the button bar, the list scrolls to keep the cursor on screen
+const LIST_ROWS: usize = (HEIGHT - BAR_HEIGHT - LIST_START) / LIST_ROW_HEIGHT;
+const LIST_PADDING: usize = 8;
+const BOARD_Y: usize = 18;
+const STATUS_Y: usize = 242;
+const CONTROLS_Y: usize = 254;
+const CONTROL_SIZE: (usize, usize) = (56, 16);
+const CONTROL_SPACING: usize = 8;
+/// Seconds between rows when autoplaying, counted from when the last row finished revealing
+const AUTOPLAY_DELAY: f64 = 0.8;
+
+/// On screen buttons for mouse players, each one acts like an [Input]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Control {
+    Back,
+    Play,
+    Forward,
+}
+
+const CONTROLS: [Control; 3] = [Control::Back, Control::Play, Control::Forward];
+
+impl Control {
+    fn input(&self) -> Input {
+        match self {
+            Control::Back => Input::Left,
+            Control::Play => Input::Action,
+            Control::Forward => Input::Right,
+        }
+    }
+
+    fn rect(idx: usize) -> Rect {
+        let width = CONTROLS.len() * (CONTROL_SIZE.0 + CONTROL_SPACING) - CONTROL_SPACING;
+        Rect::new_with_size(
+            coord!(
+                WIDTH / 2 - width / 2 + idx * (CONTROL_SIZE.0 + CONTROL_SPACING),
+                CONTROLS_Y
+            ),
+            CONTROL_SIZE.0,
+            CONTROL_SIZE.1,
+        )
+    }
+}
+
+/// Game being replayed, the first `shown` guesses are on the board
+struct Replay {
+    record: Record,
+    shown: usize,
+    /// Reveal animation of the latest guess, 1.0 once it's finished
+    anim_perc: f64,
+    autoplay: bool,
+    autoplay_timer: Timer,
+}
+
+impl Replay {
+    fn new(record: Record) -> Self {
+        Replay {
+            record,
+            shown: 0,
+            anim_perc: 1.0,
+            autoplay: true,
+            autoplay_timer: Timer::new_once(AUTOPLAY_DELAY),
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.shown == self.record.guesses.len()
+    }
+
+    fn step_forward(&mut self) {
+        if !self.is_finished() {
+            self.shown += 1;
+            self.anim_perc = 0.0;
+            self.autoplay_timer.reset();
+        }
+    }
+
+    /// Going back stops autoplay, the row is removed straight away
+    fn step_back(&mut self) {
+        self.shown = self.shown.saturating_sub(1);
+        self.anim_perc = 1.0;
+        self.autoplay = false;
+    }
+
+    /// Starts again from the first guess if the replay has finished
+    fn toggle_autoplay(&mut self) {
+        if self.is_finished() {
+            self.shown = 0;
+            self.autoplay = true;
+        } else {
+            self.autoplay = !self.autoplay;
+        }
+        self.autoplay_timer.reset();
+    }
+
+    fn update(&mut self, timing: &Timing, anim_step: bool) {
+        if anim_step && self.anim_perc < 1.0 {
+            self.anim_perc = (self.anim_perc + ANIM_GUESS_STEP).min(1.0);
+        }
+        if self.autoplay && self.anim_perc >= 1.0 && self.autoplay_timer.update(timing) {
+            if self.is_finished() {
+                self.autoplay = false;
+            } else {
+                self.step_forward();
+            }
+        }
+    }
+}
+
+/// Lists finished games from the history and replays the chosen one guess by guess
+pub struct ReplayScene {
+    result: Option<SceneUpdateResult<SceneResult, SceneName>>,
+    /// Newest first
+    games: Vec<Record>,
+    cursor: usize,
+    /// First game shown in the list
+    scroll: usize,
+    replay: Option<Replay>,
+    list_button_bar: ButtonBar,
+    replay_button_bar: ButtonBar,
+    input_timer: Timer,
+    anim_timer: Timer,
+    today: i64,
+}
+
+impl ReplayScene {
+    pub fn new() -> Box<Self> {
+        let mut games = load_games();
+        games.reverse();
+        Box::new(ReplayScene {
+            result: None,
+            games,
+            cursor: 0,
+            scroll: 0,
+            replay: None,
+            list_button_bar: ButtonBar::new(
+                coord!(0, HEIGHT - BAR_HEIGHT),
+                WIDTH,
+                &[
+                    ("CURSOR", ButtonDef::Vert),
+                    ("REPLAY", ButtonDef::Select),
+                    ("BACK", ButtonDef::Escape),
+                ],
+            ),
+            replay_button_bar: ButtonBar::new(
+                coord!(0, HEIGHT - BAR_HEIGHT),
+                WIDTH,
+                &[
+                    ("STEP", ButtonDef::Horz),
+                    ("PLAY/PAUSE", ButtonDef::Select),
+                    ("GAMES", ButtonDef::Escape),
+                ],
+            ),
+            input_timer: Timer::new_once(0.3),
+            anim_timer: Timer::new(ANIM_UPDATE_RATE),
+            today: LocalTime::now().day,
+        })
+    }
+}
+
+impl ReplayScene {
+    fn handle_input(&mut self, input: Input) {
+        match &mut self.replay {
+            None => match input {
+                Input::Up => self.move_cursor(self.cursor.saturating_sub(1)),
+                Input::Down => self.move_cursor(self.cursor + 1),
+                Input::Left => self.move_cursor(self.cursor.saturating_sub(LIST_ROWS)),
+                Input::Right => self.move_cursor(self.cursor + LIST_ROWS),
+                Input::Action => {
+                    if let Some(record) = self.games.get(self.cursor) {
+                        self.replay = Some(Replay::new(record.clone()));
+                    }
+                }
+                Input::Escape => self.result = Some(SceneUpdateResult::Pop(None)),
+                Input::Secondary | Input::Tertiary => {}
+            },
+            Some(replay) => match input {
+                Input::Left => replay.step_back(),
+                Input::Right => replay.step_forward(),
+                Input::Action => replay.toggle_autoplay(),
+                Input::Escape => self.replay = None,
+                Input::Up | Input::Down | Input::Secondary | Input::Tertiary => {}
+            },
+        }
+    }
+
+    /// Moves the cursor to `idx`, or the last game, and scrolls the list to show it
+    fn move_cursor(&mut self, idx: usize) {
+        self.cursor = idx.min(self.games.len().saturating_sub(1));
+        if self.cursor < self.scroll {
+            self.scroll = self.cursor;
+        } else if self.cursor >= self.scroll + LIST_ROWS {
+            self.scroll = self.cursor + 1 - LIST_ROWS;
+        }
+    }
+
+    fn list_row_rect(row: usize) -> Rect {
+        Rect::new_with_size(
+            coord!(LIST_PADDING / 2, LIST_START + row * LIST_ROW_HEIGHT),
+            WIDTH - LIST_PADDING,
+            LIST_ROW_HEIGHT - 2,
+        )
+    }
+
+    fn draw_list(&self, graphics: &mut Graphics) {
+        graphics.draw_text(
+            "History",
+            TextPos::px(coord!(WIDTH / 2, 16)),
+            (
+                colors::KEYBOARD_LETTER,
+                PixelFont::Standard8x10,
+                Positioning::Center,
+            ),
+        );
+        if self.games.is_empty() {
+            graphics.draw_text(
+                "No games played yet",
+                TextPos::px(coord!(WIDTH / 2, HEIGHT / 2)),
+                (colors::HINT, PixelFont::Standard6x7, Positioning::Center),
+            );
+            return;
+        }
+        let visible = self.games.iter().enumerate().skip(self.scroll);
+        for (row, (idx, record)) in visible.take(LIST_ROWS).enumerate() {
+            let rect = ReplayScene::list_row_rect(row);
+            if idx == self.cursor {
+                graphics.draw_rect(rect.clone(), stroke(colors::MENU_SELECTED));
+            }
+            let (result, result_color) = match record.outcome {
+                Outcome::Won => (
+                    format!("Won in {}", record.guesses.len()),
+                    colors::SLOT_POS_RIGHT_BACK,
+                ),
+                Outcome::Lost => (String::from("Lost"), colors::ERROR),
+            };
+            graphics.draw_text(
+                &format!("{}, {} letters", record.mode.name(), record.word_size),
+                TextPos::px(rect.top_left() + (4, 4)),
+                (colors::KEYBOARD_LETTER, PixelFont::Standard6x7),
+            );
+            graphics.draw_text(
+                &result,
+                TextPos::px(coord!(rect.right() - 4, rect.top() + 4)),
+                (result_color, PixelFont::Standard6x7, Positioning::RightTop),
+            );
+            graphics.draw_text(
+                &game_details(record, self.today),
+                TextPos::px(rect.top_left() + (4, 14)),
+                (colors::HINT, PixelFont::Standard4x5),
+            );
+        }
+    }
+
+    fn draw_replay(&self, graphics: &mut Graphics, replay: &Replay) {
+        let record = &replay.record;
+        graphics.draw_text(
+            &format!("{}, {} letters", record.mode.name(), record.word_size),
+            TextPos::px(coord!(WIDTH / 2, 10)),
+            (
+                colors::KEYBOARD_LETTER,
+                PixelFont::Standard6x7,
+                Positioning::Center,
+            ),
+        );
+        let engine = record.board(replay.shown);
+        if replay.anim_perc < 1.0 && replay.shown > 0 {
+            let info = SubmittedGuessInfo::from_row(&record.guesses[replay.shown - 1]);
+            render_guess_field(
+                graphics,
+                coord!(0, BOARD_Y),
+                &engine,
+                &info,
+                replay.anim_perc,
+            );
+        } else {
+            render_field(graphics, coord!(0, BOARD_Y), &engine);
+        }
+        //the answer is only given away once the last guess has been revealed
+        let status = if replay.is_finished() && replay.anim_perc >= 1.0 {
+            match record.outcome {
+                Outcome::Won => format!("Solved in {}", record.guesses.len()),
+                Outcome::Lost => format!("The word was {}", record.answer),
+            }
+        } else {
+            format!("Guess {} of {}", replay.shown, record.guesses.len())
+        };
+        graphics.draw_text(
+            &status,
+            TextPos::px(coord!(WIDTH / 2, STATUS_Y)),
+            (colors::HINT, PixelFont::Standard6x7, Positioning::Center),
+        );
+        for (i, control) in CONTROLS.iter().enumerate() {
+            let label = match control {
+                Control::Back => "<",
+                Control::Play if replay.autoplay => "PAUSE",
+                Control::Play if replay.is_finished() => "AGAIN",
+                Control::Play => "PLAY",
+                Control::Forward => ">",
+            };
+            let rect = Control::rect(i);
+            graphics.draw_rect(rect.clone(), stroke(colors::MENU_DEFAULT));
+            graphics.draw_text(
+                label,
+                TextPos::px(rect.center() + (1, 1)),
+                (
+                    colors::MENU_SELECTED,
+                    PixelFont::Standard6x7,
+                    Positioning::Center,
+                ),
+            );
+        }
+    }
+}
+
+/// When the game was played, how long it took and the hints used
+fn game_details(record: &Record, today: i64) -> String {
+    let seconds = record.duration as u64;
+    let mut details = format!(
+        "{}, {}:{:02}",
+        format_days_ago(LocalTime::at(record.finished).day, today),
+        seconds / 60,
+        seconds % 60
+    );
+    match record.hints {
+        0 => {}
+        1 => details.push_str(", 1 hint"),
+        hints => details.push_str(&format!(", {hints} hints")),
+    }
+    details
+}
+
+impl Scene<SceneResult, SceneName> for ReplayScene {
+    fn render(
+        &self,
+        graphics: &mut Graphics,
+        _: &MouseData,
+        _: &FxHashSet<KeyCode>,
+        controller: &GameController,
+    ) {
+        graphics.clear(colors::BACKGROUND);
+        match &self.replay {
+            None => {
+                self.draw_list(graphics);
+                self.list_button_bar
+                    .render(graphics, controller.get_controller_type());
+            }
+            Some(replay) => {
+                self.draw_replay(graphics, replay);
+                self.replay_button_bar
+                    .render(graphics, controller.get_controller_type());
+            }
+        }
+    }
+
+    fn on_mouse_click(
+        &mut self,
+        down_at: Coord,
+        mouse: &MouseData,
+        mouse_button: MouseButton,
+        _: &FxHashSet<KeyCode>,
+    ) {
+        if mouse_button != MouseButton::Left {
+            return;
+        }
+        if self.replay.is_some() {
+            for (i, control) in CONTROLS.iter().enumerate() {
+                let rect = Control::rect(i);
+                if rect.contains(down_at) && rect.contains(mouse.xy) {
+                    self.handle_input(control.input());
+                }
+            }
+        } else {
+            for row in 0..LIST_ROWS.min(self.games.len() - self.scroll) {
+                let rect = ReplayScene::list_row_rect(row);
+                if rect.contains(down_at) && rect.contains(mouse.xy) {
+                    self.cursor = self.scroll + row;
+                    self.handle_input(Input::Action);
+                }
+            }
+        }
+    }
+
+    fn update(
+        &mut self,
+        timing: &Timing,
+        _: &MouseData,
+        held_keys: &FxHashSet<KeyCode>,
+        controller: &GameController,
+        _: &Window,
+    ) -> SceneUpdateResult<SceneResult, SceneName> {
+        if self.input_timer.update(timing) {
+            if let Some(input) = keys_to_input(held_keys, controller) {
+                self.input_timer.reset();
+                self.handle_input(input);
+            }
+        }
+        let anim_step = self.anim_timer.update(timing);
+        if let Some(replay) = &mut self.replay {
+            replay.update(timing, anim_step);
+        }
+        self.result.clone().unwrap_or(SceneUpdateResult::Nothing)
+    }
+
+    fn resuming(&mut self, _: Option<SceneResult>) {
+        self.result = None;
+        self.input_timer.reset();
+    }
+}
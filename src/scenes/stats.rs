@@ -0,0 +1,350 @@
+use crate::scenes::keys_to_input;
+use crate::ui::button_bar::{ButtonBar, ButtonDef, BAR_HEIGHT};
+use crate::ui::theme::colors;
+use crate::{GameMode, Input, SceneName, SceneResult, Settings, HEIGHT, WIDTH};
+use pixels_graphics_lib::prelude::*;
+use wordle_engine::stats::Stats;
+use wordle_engine::word_list::WORD_SIZES;
+use wordle_engine::{EngineState, WordleEngine};
+
+const MODES: [GameMode; 9] = [
+    GameMode::Classic,
+    GameMode::Retry,
+    GameMode::Daily,
+    GameMode::Practice,
+    GameMode::Absurdle,
+    GameMode::Endless,
+    GameMode::TimeAttack,
+    GameMode::TwoPlayer,
+    GameMode::Custom,
+];
+
+const SELECTOR_Y: usize = 42;
+const ARROW_SIZE: usize = 16;
+const NUMBERS_Y: usize = 64;
+const DISTRIBUTION_Y: usize = 100;
+const BAR_START: Coord = Coord::new(30, 114);
+const BAR_HEIGHT_PX: usize = 10;
+const BAR_SPACING: usize = 14;
+const BAR_MAX_WIDTH: usize = WIDTH - 50;
+/// Wide enough to fit the count
+const BAR_MIN_WIDTH: usize = 12;
+/// Most bars that fit above the button bar, games with more guesses are added to the last bar
+const MAX_BARS: usize = (HEIGHT - BAR_HEIGHT - 8 - 114) / BAR_SPACING;
+
+/// Results for each word size and mode, with a bar chart of the number of guesses
+pub struct StatsScene {
+    result: Option<SceneUpdateResult<SceneResult, SceneName>>,
+    word_size: usize,
+    mode_idx: usize,
+    /// Game that was just finished, its bar is highlighted
+    last_game: Option<(WordleEngine, GameMode)>,
+    button_bar: ButtonBar,
+    input_timer: Timer,
+    settings: AppPrefs<Settings>,
+}
+
+impl StatsScene {
+    pub fn new(
+        word_size: usize,
+        mode: GameMode,
+        last_game: Option<WordleEngine>,
+        settings: AppPrefs<Settings>,
+    ) -> Box<Self> {
+        Box::new(StatsScene {
+            result: None,
+            word_size,
+            mode_idx: MODES.iter().position(|m| *m == mode).unwrap_or_default(),
+            last_game: last_game.map(|engine| (engine, mode)),
+            button_bar: ButtonBar::new(
+                coord!(0, HEIGHT - BAR_HEIGHT),
+                WIDTH,
+                &[
+                    ("MODE", ButtonDef::Horz),
+                    ("WORD SIZE", ButtonDef::Vert),
+                    ("HISTORY", ButtonDef::Secondary),
+                    ("BACK", ButtonDef::Escape),
+                ],
+            ),
+            input_timer: Timer::new_once(0.3),
+            settings,
+        })
+    }
+}
+
+impl StatsScene {
+    fn stats(&self) -> Stats {
+        self.settings
+            .data
+            .stats
+            .get(&self.word_size)
+            .and_then(|modes| modes.get(&MODES[self.mode_idx]))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn change_mode(&mut self, forward: bool) {
+        self.mode_idx = if forward {
+            (self.mode_idx + 1) % MODES.len()
+        } else {
+            (self.mode_idx + MODES.len() - 1) % MODES.len()
+        };
+    }
+
+    fn change_size(&mut self, bigger: bool) {
+        self.word_size = if bigger && self.word_size == *WORD_SIZES.end() {
+            *WORD_SIZES.start()
+        } else if bigger {
+            self.word_size + 1
+        } else if self.word_size == *WORD_SIZES.start() {
+            *WORD_SIZES.end()
+        } else {
+            self.word_size - 1
+        };
+    }
+
+    fn arrow_rect(forward: bool) -> Rect {
+        let x = if forward { WIDTH - 20 - ARROW_SIZE } else { 20 };
+        Rect::new_with_size(
+            coord!(x, SELECTOR_Y - ARROW_SIZE / 2),
+            ARROW_SIZE,
+            ARROW_SIZE,
+        )
+    }
+
+    fn draw_selector(&self, graphics: &mut Graphics) {
+        graphics.draw_text(
+            &format!(
+                "{}, {} letters",
+                MODES[self.mode_idx].name(),
+                self.word_size
+            ),
+            TextPos::px(coord!(WIDTH / 2, SELECTOR_Y)),
+            (
+                colors::MENU_SELECTED,
+                PixelFont::Standard6x7,
+                Positioning::Center,
+            ),
+        );
+        for (forward, arrow) in [(false, "<"), (true, ">")] {
+            let rect = StatsScene::arrow_rect(forward);
+            graphics.draw_text(
+                arrow,
+                TextPos::px(rect.center() + (1, 1)),
+                (
+                    colors::MENU_SELECTED,
+                    PixelFont::Standard6x7,
+                    Positioning::Center,
+                ),
+            );
+            graphics.draw_rect(rect, stroke(colors::MENU_DEFAULT));
+        }
+    }
+
+    fn draw_numbers(&self, graphics: &mut Graphics, stats: &Stats) {
+        let numbers = [
+            (stats.played, "PLAYED"),
+            (stats.win_percent(), "WIN %"),
+            (stats.current_streak, "STREAK"),
+            (stats.max_streak, "MAX STREAK"),
+        ];
+        for (i, (number, label)) in numbers.iter().enumerate() {
+            let x = WIDTH * (i * 2 + 1) / 8;
+            graphics.draw_text(
+                &number.to_string(),
+                TextPos::px(coord!(x, NUMBERS_Y)),
+                (
+                    colors::KEYBOARD_LETTER,
+                    PixelFont::Standard8x10,
+                    Positioning::Center,
+                ),
+            );
+            graphics.draw_text(
+                label,
+                TextPos::px(coord!(x, NUMBERS_Y + 14)),
+                (colors::HINT, PixelFont::Standard4x5, Positioning::Center),
+            );
+        }
+    }
+
+    /// The game that was just finished, if it's for the size and mode being shown
+    fn shown_last_game(&self) -> Option<&WordleEngine> {
+        self.last_game
+            .as_ref()
+            .filter(|(engine, mode)| {
+                engine.word_size == self.word_size && *mode == MODES[self.mode_idx]
+            })
+            .map(|(engine, _)| engine)
+    }
+
+    /// A bar for each number of guesses up to the guess limit, the bar for the game that was
+    /// just finished is highlighted
+    fn draw_distribution(&self, graphics: &mut Graphics, stats: &Stats) {
+        graphics.draw_text(
+            "GUESS DISTRIBUTION",
+            TextPos::px(coord!(WIDTH / 2, DISTRIBUTION_Y)),
+            (
+                colors::KEYBOARD_LETTER,
+                PixelFont::Standard6x7,
+                Positioning::Center,
+            ),
+        );
+        if stats.played == 0 {
+            graphics.draw_text(
+                "No games played yet",
+                TextPos::px(coord!(WIDTH / 2, BAR_START.y as usize + BAR_SPACING)),
+                (colors::HINT, PixelFont::Standard6x7, Positioning::Center),
+            );
+            return;
+        }
+        let bars = distribution_bars(stats, self.word_size + 1);
+        let max = bars
+            .iter()
+            .map(|(_, count)| *count)
+            .max()
+            .unwrap_or_default();
+        let last_game = self.shown_last_game();
+        let last = last_game
+            .filter(|engine| engine.state == EngineState::Found)
+            .map(|engine| engine.guesses.len().min(bars.len()));
+        for (i, (label, count)) in bars.iter().enumerate() {
+            let pos = BAR_START + (0, i * BAR_SPACING);
+            let width = (count * BAR_MAX_WIDTH)
+                .checked_div(max)
+                .unwrap_or_default()
+                .max(BAR_MIN_WIDTH);
+            let color = if last == Some(i + 1) {
+                colors::SLOT_POS_RIGHT_BACK
+            } else {
+                colors::SLOT_NO_MATCH_BACK
+            };
+            graphics.draw_text(
+                label,
+                TextPos::px(coord!(pos.x - 6, pos.y + 2)),
+                (
+                    colors::KEYBOARD_LETTER,
+                    PixelFont::Standard6x7,
+                    Positioning::RightTop,
+                ),
+            );
+            let rect = Rect::new_with_size(pos, width, BAR_HEIGHT_PX);
+            graphics.draw_rect(rect.clone(), fill(color));
+            graphics.draw_text(
+                &count.to_string(),
+                TextPos::px(coord!(rect.right() - 2, rect.top() + 3)),
+                (
+                    colors::SLOT_NO_MATCH_FORE,
+                    PixelFont::Standard4x5,
+                    Positioning::RightTop,
+                ),
+            );
+        }
+        if let Some(engine) = last_game {
+            let text = if engine.state == EngineState::Found {
+                format!("Latest game solved in {}", engine.guesses.len())
+            } else {
+                String::from("Latest game not solved")
+            };
+            graphics.draw_text(
+                &text,
+                TextPos::px(coord!(
+                    WIDTH / 2,
+                    BAR_START.y as usize + bars.len() * BAR_SPACING + 4
+                )),
+                (colors::HINT, PixelFont::Standard4x5, Positioning::Center),
+            );
+        }
+    }
+}
+
+/// Label and number of wins for each bar, there's a bar for every guess count up to
+/// `guess_limit` (or the most guesses used to win, for modes without a limit)
+/// and at most [MAX_BARS]
+fn distribution_bars(stats: &Stats, guess_limit: usize) -> Vec<(String, usize)> {
+    let needed = guess_limit.max(stats.distribution.len());
+    let shown = needed.min(MAX_BARS);
+    let mut bars: Vec<(String, usize)> = (1..=shown)
+        .map(|guesses| (guesses.to_string(), stats.wins_in(guesses)))
+        .collect();
+    if needed > shown {
+        let rest: usize = (shown..=needed).map(|guesses| stats.wins_in(guesses)).sum();
+        bars[shown - 1] = (format!("{shown}+"), rest);
+    }
+    bars
+}
+
+impl Scene<SceneResult, SceneName> for StatsScene {
+    fn render(
+        &self,
+        graphics: &mut Graphics,
+        _: &MouseData,
+        _: &FxHashSet<KeyCode>,
+        controller: &GameController,
+    ) {
+        graphics.clear(colors::BACKGROUND);
+        graphics.draw_text(
+            "Statistics",
+            TextPos::px(coord!(WIDTH / 2, 20)),
+            (
+                colors::KEYBOARD_LETTER,
+                PixelFont::Standard8x10,
+                Positioning::Center,
+            ),
+        );
+        let stats = self.stats();
+        self.draw_selector(graphics);
+        self.draw_numbers(graphics, &stats);
+        self.draw_distribution(graphics, &stats);
+        self.button_bar
+            .render(graphics, controller.get_controller_type());
+    }
+
+    fn on_mouse_click(
+        &mut self,
+        down_at: Coord,
+        mouse: &MouseData,
+        mouse_button: MouseButton,
+        _: &FxHashSet<KeyCode>,
+    ) {
+        if mouse_button == MouseButton::Left {
+            for forward in [false, true] {
+                let rect = StatsScene::arrow_rect(forward);
+                if rect.contains(down_at) && rect.contains(mouse.xy) {
+                    self.change_mode(forward);
+                }
+            }
+        }
+    }
+
+    fn update(
+        &mut self,
+        timing: &Timing,
+        _: &MouseData,
+        held_keys: &FxHashSet<KeyCode>,
+        controller: &GameController,
+        _: &Window,
+    ) -> SceneUpdateResult<SceneResult, SceneName> {
+        if self.input_timer.update(timing) {
+            if let Some(input) = keys_to_input(held_keys, controller) {
+                self.input_timer.reset();
+                match input {
+                    Input::Left => self.change_mode(false),
+                    Input::Right => self.change_mode(true),
+                    Input::Up => self.change_size(true),
+                    Input::Down => self.change_size(false),
+                    Input::Escape => return SceneUpdateResult::Pop(None),
+                    Input::Secondary => {
+                        self.result = Some(SceneUpdateResult::Push(false, SceneName::History))
+                    }
+                    Input::Action | Input::Tertiary => {}
+                }
+            }
+        }
+        self.result.clone().unwrap_or(SceneUpdateResult::Nothing)
+    }
+
+    fn resuming(&mut self, _: Option<SceneResult>) {
+        self.result = None;
+        self.input_timer.reset();
+    }
+}
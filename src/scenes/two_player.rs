@@ -0,0 +1,243 @@
+use crate::scenes::game::keyboard_pos;
+use crate::scenes::{key_to_input, poll_controller, typed_key};
+use crate::ui::button_bar::{ButtonBar, ButtonDef, BAR_HEIGHT};
+use crate::ui::keyboard::{Key, Keyboard};
+use crate::ui::text_entry::TextEntry;
+use crate::ui::theme::colors;
+use crate::ui::wordle_renderer::render_masked_row;
+use crate::{GameMode, Input, SceneName, SceneResult, Settings, HEIGHT, WIDTH};
+use pixels_graphics_lib::prelude::*;
+use wordle_engine::versus::Versus;
+use wordle_engine::{Difficulty, EngineError, EngineState, WordleEngine};
+
+const WORD_Y: usize = 104;
+
+/// Players take turns to pick a word for the other to guess on the same machine
+///
+/// The word is hidden as it's typed, then the other player guesses it in a normal game
+pub struct TwoPlayerScene {
+    result: Option<SceneUpdateResult<SceneResult, SceneName>>,
+    word_size: usize,
+    difficulty: Difficulty,
+    versus: Versus,
+    entry: TextEntry,
+    /// How the last round went
+    last_round: Option<String>,
+    keyboard: Keyboard,
+    button_bar: ButtonBar,
+    input_timer: Timer,
+}
+
+impl TwoPlayerScene {
+    pub fn new(word_size: usize, settings: AppPrefs<Settings>) -> Box<Self> {
+        let difficulty = settings
+            .data
+            .difficulty
+            .get(&word_size)
+            .copied()
+            .unwrap_or_default();
+        Box::new(TwoPlayerScene {
+            result: None,
+            word_size,
+            difficulty,
+            versus: Versus::default(),
+            entry: TextEntry::new(word_size),
+            last_round: None,
+            keyboard: Keyboard::new(keyboard_pos()),
+            button_bar: ButtonBar::new(
+                coord!(0, HEIGHT - BAR_HEIGHT),
+                WIDTH,
+                &[
+                    ("CURSOR", ButtonDef::Cursor),
+                    ("SELECT", ButtonDef::Select),
+                    ("CLOSE", ButtonDef::Escape),
+                ],
+            ),
+            input_timer: Timer::new_once(0.3),
+        })
+    }
+}
+
+impl TwoPlayerScene {
+    fn press_key(&mut self, key: Key) {
+        if self.entry.press_key(key) {
+            self.submit();
+        }
+    }
+
+    /// Start the round if the word is in the dictionary
+    fn submit(&mut self) {
+        if self.entry.letters.len() < self.word_size {
+            self.entry.error = Some(String::from("Not enough letters"));
+            return;
+        }
+        let word = self.entry.text();
+        match WordleEngine::with_word(&word, self.difficulty) {
+            Ok(engine) => {
+                self.entry.clear();
+                self.result = Some(SceneUpdateResult::Push(
+                    false,
+                    SceneName::GameWith(engine, GameMode::TwoPlayer),
+                ));
+            }
+            Err(EngineError::NotAWord) => self.entry.error = Some(String::from("Unknown word")),
+            Err(err) => self.entry.error = Some(err.to_string()),
+        }
+    }
+
+    fn handle_input(&mut self, input: Input) {
+        if input == Input::Escape {
+            self.result = Some(SceneUpdateResult::Pop(None));
+        } else if let Some(key) = self.keyboard.key_press(input) {
+            self.press_key(key);
+        }
+    }
+
+    /// Scores the game that was just played, closing it early means the round is picked again
+    fn end_round(&mut self, engine: Option<WordleEngine>) {
+        let guesser = self.versus.guesser() + 1;
+        let Some(engine) = engine else {
+            self.last_round = Some(String::from("Round abandoned"));
+            return;
+        };
+        self.last_round = Some(match self.versus.add_round(&engine) {
+            None => String::from("Round abandoned"),
+            Some(score) if engine.state == EngineState::Found => format!(
+                "Player {guesser} found {} in {score}/{}",
+                engine.word, engine.max_guess_count
+            ),
+            Some(score) => format!("Player {guesser} missed {}, scores {score}", engine.word),
+        });
+    }
+
+    fn draw_scores(&self, graphics: &mut Graphics) {
+        let scores = format!(
+            "Player 1: {}    Player 2: {}",
+            self.versus.scores[0], self.versus.scores[1]
+        );
+        graphics.draw_text(
+            &scores,
+            TextPos::px(coord!(WIDTH / 2, 44)),
+            (
+                colors::KEYBOARD_LETTER,
+                PixelFont::Standard6x7,
+                Positioning::Center,
+            ),
+        );
+        let status = match self.versus.leader() {
+            Some(player) => format!(
+                "Round {}, player {} leads (lowest wins)",
+                self.versus.rounds + 1,
+                player + 1
+            ),
+            None => format!("Round {} (lowest score wins)", self.versus.rounds + 1),
+        };
+        graphics.draw_text(
+            &status,
+            TextPos::px(coord!(WIDTH / 2, 56)),
+            (colors::HINT, PixelFont::Standard4x5, Positioning::Center),
+        );
+    }
+}
+
+impl Scene<SceneResult, SceneName> for TwoPlayerScene {
+    fn render(
+        &self,
+        graphics: &mut Graphics,
+        _: &MouseData,
+        _: &FxHashSet<KeyCode>,
+        controller: &GameController,
+    ) {
+        graphics.clear(colors::BACKGROUND);
+        graphics.draw_text(
+            "Two player",
+            TextPos::px(coord!(WIDTH / 2, 20)),
+            (
+                colors::KEYBOARD_LETTER,
+                PixelFont::Standard8x10,
+                Positioning::Center,
+            ),
+        );
+        self.draw_scores(graphics);
+        graphics.draw_text(
+            &format!(
+                "Player {}, pick a {} letter word",
+                self.versus.setter() + 1,
+                self.word_size
+            ),
+            TextPos::px(coord!(WIDTH / 2, 76)),
+            (
+                colors::KEYBOARD_LETTER,
+                PixelFont::Standard6x7,
+                Positioning::Center,
+            ),
+        );
+        graphics.draw_text(
+            &format!("Player {}, look away!", self.versus.guesser() + 1),
+            TextPos::px(coord!(WIDTH / 2, 88)),
+            (colors::HINT, PixelFont::Standard6x7, Positioning::Center),
+        );
+        render_masked_row(graphics, WORD_Y, self.word_size, self.entry.letters.len());
+        if let Some(last_round) = &self.last_round {
+            graphics.draw_text(
+                last_round,
+                TextPos::px(coord!(WIDTH / 2, WORD_Y + 36)),
+                (colors::HINT, PixelFont::Standard6x7, Positioning::Center),
+            );
+        }
+        self.entry.render_error(graphics);
+        self.keyboard.render(graphics);
+        self.button_bar
+            .render(graphics, controller.get_controller_type());
+    }
+
+    fn on_mouse_click(
+        &mut self,
+        down_at: Coord,
+        mouse: &MouseData,
+        mouse_button: MouseButton,
+        _: &FxHashSet<KeyCode>,
+    ) {
+        if mouse_button == MouseButton::Left {
+            if let Some(key) = self.keyboard.mouse_click(down_at, mouse.xy) {
+                self.press_key(key);
+            }
+        }
+    }
+
+    fn on_key_down(&mut self, key: KeyCode, _: &MouseData, _: &FxHashSet<KeyCode>) {
+        //typed keys aren't flashed on the keyboard, that would give the word away
+        if let Some(key) = typed_key(key) {
+            self.press_key(key);
+        } else if let Some(input) = key_to_input(key) {
+            self.handle_input(input);
+        }
+    }
+
+    fn update(
+        &mut self,
+        timing: &Timing,
+        mouse: &MouseData,
+        _: &FxHashSet<KeyCode>,
+        controller: &GameController,
+        _: &Window,
+    ) -> SceneUpdateResult<SceneResult, SceneName> {
+        self.keyboard.update(timing);
+        if let Some(input) = poll_controller(&mut self.input_timer, timing, controller) {
+            self.handle_input(input);
+        }
+        self.keyboard.mouse_move(mouse.xy);
+        self.result.clone().unwrap_or(SceneUpdateResult::Nothing)
+    }
+
+    fn resuming(&mut self, result: Option<SceneResult>) {
+        //returning from a round
+        self.result = None;
+        self.input_timer.reset();
+        self.entry.error = None;
+        self.end_round(match result {
+            Some(SceneResult::GameOver(engine, _)) => Some(engine),
+            _ => None,
+        });
+    }
+}
@@ -0,0 +1,158 @@
+//! Copying results to and pasting codes from the clipboard, using whichever clipboard tool
+//! the system has
+
+use log::{info, warn};
+use pixels_graphics_lib::prelude::get_pref_dir;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+pub const SHARE_FILE: &str = "share.txt";
+
+/// Where shared text ended up
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Shared {
+    Clipboard,
+    /// No clipboard tool worked so the text was written here
+    File(PathBuf),
+}
+
+/// Copies `text` to the clipboard, or writes it to a file in the prefs directory
+/// if there's no clipboard
+///
+/// Returns `None` if neither worked, this waits for the clipboard tool so shouldn't
+/// be called on the UI thread
+pub fn share(text: &str) -> Option<Shared> {
+    if TOOLS
+        .iter()
+        .any(|(program, args)| copy_with(program, args, text))
+    {
+        return Some(Shared::Clipboard);
+    }
+    let mut path = match get_pref_dir("app", "emmabritton", "wordle") {
+        Ok(path) => path,
+        Err(err) => {
+            warn!("Unable to find prefs dir to share: {err:?}");
+            return None;
+        }
+    };
+    path.push(SHARE_FILE);
+    match fs::write(&path, text) {
+        Ok(()) => {
+            info!("No clipboard, result saved to {path:?}");
+            Some(Shared::File(path))
+        }
+        Err(err) => {
+            warn!("Unable to write {path:?}: {err:?}");
+            None
+        }
+    }
+}
+
+/// Runs `program` with `text` as its input, returns true if it succeeded
+fn copy_with(program: &str, args: &[&str], text: &str) -> bool {
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    hide_window(&mut command);
+    let Ok(mut child) = command.spawn() else {
+        return false;
+    };
+    let written = child
+        .stdin
+        .take()
+        .map(|mut stdin| stdin.write_all(text.as_bytes()).is_ok())
+        .unwrap_or_default();
+    //stdin has been dropped so the tool sees the end of the text
+    let succeeded = child
+        .wait()
+        .map(|status| status.success())
+        .unwrap_or_default();
+    written && succeeded
+}
+
+/// Text on the clipboard, `None` if no clipboard tool worked
+///
+/// This waits for the clipboard tool so shouldn't be called on the UI thread
+pub fn paste() -> Option<String> {
+    PASTE_TOOLS
+        .iter()
+        .find_map(|(program, args)| paste_with(program, args))
+}
+
+/// Runs `program` and returns its output, `None` if it failed
+fn paste_with(program: &str, args: &[&str]) -> Option<String> {
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null());
+    hide_window(&mut command);
+    let output = command.output().ok()?;
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        None
+    }
+}
+
+/// Program and arguments
+type Tool = (&'static str, &'static [&'static str]);
+
+/// Clipboard tools to try, in order
+#[cfg(target_os = "macos")]
+const TOOLS: &[Tool] = &[("pbcopy", &[])];
+
+/// Clipboard tools to read from, in order
+#[cfg(target_os = "macos")]
+const PASTE_TOOLS: &[Tool] = &[("pbpaste", &[])];
+
+/// Clipboard tools to try, in order
+#[cfg(windows)]
+const TOOLS: &[Tool] = &[
+    //clip doesn't read UTF-8 so emoji are garbled, powershell is tried first
+    (
+        "powershell",
+        &[
+            "-NoProfile",
+            "-Command",
+            "[Console]::InputEncoding = [Text.Encoding]::UTF8; Set-Clipboard -Value ([Console]::In.ReadToEnd())",
+        ],
+    ),
+    ("clip", &[]),
+];
+
+/// Clipboard tools to read from, in order
+#[cfg(windows)]
+const PASTE_TOOLS: &[Tool] = &[("powershell", &["-NoProfile", "-Command", "Get-Clipboard"])];
+
+/// Clipboard tools to try, in order, wl-copy fails straight away outside of Wayland
+#[cfg(not(any(target_os = "macos", windows)))]
+const TOOLS: &[Tool] = &[
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+];
+
+/// Clipboard tools to read from, in order
+#[cfg(not(any(target_os = "macos", windows)))]
+const PASTE_TOOLS: &[Tool] = &[
+    ("wl-paste", &["--no-newline"]),
+    ("xclip", &["-selection", "clipboard", "-o"]),
+    ("xsel", &["--clipboard", "--output"]),
+];
+
+/// Stops a console window flashing up while the tool runs
+#[cfg(windows)]
+fn hide_window(command: &mut Command) {
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+    command.creation_flags(CREATE_NO_WINDOW);
+}
+
+#[cfg(not(windows))]
+fn hide_window(_: &mut Command) {}
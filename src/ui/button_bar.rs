@@ -201,6 +201,80 @@ fn get_icon(def: ButtonDef, controller: Option<Controller>) -> &'static IndexedI
                     ),
                 ]),
             ),
+            (
+                ButtonDef::Secondary,
+                HashMap::from([
+                    (
+                        Some(Controller::Playstation),
+                        IndexedImage::from_file_contents(include_bytes!(
+                            "../../assets/icons/cntr_triangle.ici"
+                        ))
+                        .unwrap()
+                        .0,
+                    ),
+                    (
+                        Some(Controller::Xbox),
+                        IndexedImage::from_file_contents(include_bytes!(
+                            "../../assets/icons/cntr_y.ici"
+                        ))
+                        .unwrap()
+                        .0,
+                    ),
+                    (
+                        Some(Controller::Switch),
+                        IndexedImage::from_file_contents(include_bytes!(
+                            "../../assets/icons/cntr_x.ici"
+                        ))
+                        .unwrap()
+                        .0,
+                    ),
+                    (
+                        None,
+                        IndexedImage::from_file_contents(include_bytes!(
+                            "../../assets/icons/key_tab.ici"
+                        ))
+                        .unwrap()
+                        .0,
+                    ),
+                ]),
+            ),
+            (
+                ButtonDef::Tertiary,
+                HashMap::from([
+                    (
+                        Some(Controller::Playstation),
+                        IndexedImage::from_file_contents(include_bytes!(
+                            "../../assets/icons/cntr_square.ici"
+                        ))
+                        .unwrap()
+                        .0,
+                    ),
+                    (
+                        Some(Controller::Xbox),
+                        IndexedImage::from_file_contents(include_bytes!(
+                            "../../assets/icons/cntr_x.ici"
+                        ))
+                        .unwrap()
+                        .0,
+                    ),
+                    (
+                        Some(Controller::Switch),
+                        IndexedImage::from_file_contents(include_bytes!(
+                            "../../assets/icons/cntr_y.ici"
+                        ))
+                        .unwrap()
+                        .0,
+                    ),
+                    (
+                        None,
+                        IndexedImage::from_file_contents(include_bytes!(
+                            "../../assets/icons/key_f1.ici"
+                        ))
+                        .unwrap()
+                        .0,
+                    ),
+                ]),
+            ),
         ])
     })[&def][&controller]
 }
@@ -270,4 +344,6 @@ pub enum ButtonDef {
     Horz,
     Vert,
     Cursor,
+    Secondary,
+    Tertiary,
 }
@@ -4,6 +4,9 @@ use crate::ui::keyboard::setup::*;
 use crate::ui::theme::colors;
 use crate::Input;
 use pixels_graphics_lib::prelude::*;
+use wordle_engine::SubmittedGuessInfo;
+
+const FLASH_DURATION: f64 = 0.15;
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum Key {
@@ -26,9 +29,10 @@ pub struct Keyboard {
     cursor: Key,
     //mouse cursor
     last_pos: Coord,
-    matched: Vec<char>,
-    mismatched: Vec<char>,
-    no_matches: Vec<char>,
+    //one per board, the first is used when there's a single board
+    boards: Vec<KnownLetters>,
+    //key pressed via physical keyboard
+    flash: Option<(Key, Timer)>,
 }
 
 impl Keyboard {
@@ -42,13 +46,17 @@ impl Keyboard {
 
 impl Keyboard {
     pub fn new(pos: Coord) -> Self {
+        Keyboard::new_multi(pos, 1)
+    }
+
+    /// Keyboard for several boards at once, each key is split into a segment per board
+    pub fn new_multi(pos: Coord, boards: usize) -> Self {
         Keyboard {
             pos,
             cursor: Key::Letter('G'),
             last_pos: coord!(-1, -1),
-            matched: vec![],
-            mismatched: vec![],
-            no_matches: vec![],
+            boards: vec![KnownLetters::default(); boards.max(1)],
+            flash: None,
         }
     }
 }
@@ -61,20 +69,28 @@ enum KeyState {
     NoMatch,
 }
 
-impl Keyboard {
+/// Letters found by the guesses on one board
+#[derive(Clone, Debug, Default)]
+struct KnownLetters {
+    matched: Vec<char>,
+    mismatched: Vec<char>,
+    no_matches: Vec<char>,
+}
+
+impl KnownLetters {
     fn is_known(&self, chr: char) -> bool {
         self.matched.contains(&chr)
             || self.no_matches.contains(&chr)
             || self.mismatched.contains(&chr)
     }
 
-    pub fn add_mismatch(&mut self, chr: char) {
+    fn add_mismatch(&mut self, chr: char) {
         if !self.is_known(chr) {
             self.mismatched.push(chr);
         }
     }
 
-    pub fn add_match(&mut self, chr: char) {
+    fn add_match(&mut self, chr: char) {
         if !(self.matched.contains(&chr) || self.no_matches.contains(&chr)) {
             self.matched.push(chr);
             if let Some(idx) = self.mismatched.iter().position(|c| c == &chr) {
@@ -83,12 +99,81 @@ impl Keyboard {
         }
     }
 
-    pub fn add_no_match(&mut self, chr: char) {
+    fn add_no_match(&mut self, chr: char) {
         if !self.is_known(chr) {
             self.no_matches.push(chr);
         }
     }
 
+    fn add_guess(&mut self, info: &SubmittedGuessInfo) {
+        for c in &info.matches {
+            self.add_match(*c);
+        }
+        for c in &info.mismatches {
+            self.add_mismatch(*c);
+        }
+        for c in &info.no_matches {
+            self.add_no_match(*c);
+        }
+    }
+
+    fn state_for(&self, key: Key) -> KeyState {
+        match key {
+            Key::Letter(c) => {
+                if self.matched.contains(&c) {
+                    KeyState::Match
+                } else if self.mismatched.contains(&c) {
+                    KeyState::Mismatch
+                } else if self.no_matches.contains(&c) {
+                    KeyState::NoMatch
+                } else {
+                    KeyState::Default
+                }
+            }
+            Key::Enter => KeyState::Default,
+            Key::Backspace => KeyState::Default,
+        }
+    }
+}
+
+impl Keyboard {
+    /// Whether `chr` has been marked as a match, mismatch or no match
+    pub fn is_known(&self, chr: char) -> bool {
+        self.boards[0].is_known(chr)
+    }
+
+    pub fn add_mismatch(&mut self, chr: char) {
+        self.boards[0].add_mismatch(chr);
+    }
+
+    pub fn add_no_match(&mut self, chr: char) {
+        self.boards[0].add_no_match(chr);
+    }
+
+    pub fn add_guess(&mut self, info: &SubmittedGuessInfo) {
+        self.add_board_guess(0, info);
+    }
+
+    /// Update the segment for `board` with the result of a guess
+    pub fn add_board_guess(&mut self, board: usize, info: &SubmittedGuessInfo) {
+        if let Some(letters) = self.boards.get_mut(board) {
+            letters.add_guess(info);
+        }
+    }
+
+    /// Briefly show `key` as pressed, used when typing on a physical keyboard
+    pub fn flash(&mut self, key: Key) {
+        self.flash = Some((key, Timer::new_once(FLASH_DURATION)));
+    }
+
+    pub fn update(&mut self, timing: &Timing) {
+        if let Some((_, timer)) = &mut self.flash {
+            if timer.update(timing) {
+                self.flash = None;
+            }
+        }
+    }
+
     pub fn render(&self, graphics: &mut Graphics) {
         let start = self.pos;
         let key_pos = key_pos();
@@ -101,7 +186,8 @@ impl Keyboard {
                     *key,
                     pos,
                     self.cursor == *key,
-                    self.state_for(*key),
+                    self.is_flashing(*key),
+                    &self.states_for(*key),
                 );
             }
         }
@@ -147,35 +233,41 @@ impl Keyboard {
         None
     }
 
-    fn state_for(&self, key: Key) -> KeyState {
-        match key {
-            Key::Letter(c) => {
-                if self.matched.contains(&c) {
-                    KeyState::Match
-                } else if self.mismatched.contains(&c) {
-                    KeyState::Mismatch
-                } else if self.no_matches.contains(&c) {
-                    KeyState::NoMatch
-                } else {
-                    KeyState::Default
-                }
-            }
-            Key::Enter => KeyState::Default,
-            Key::Backspace => KeyState::Default,
-        }
+    fn is_flashing(&self, key: Key) -> bool {
+        self.flash
+            .as_ref()
+            .map(|(flashed, _)| *flashed == key)
+            .unwrap_or_default()
+    }
+
+    fn states_for(&self, key: Key) -> Vec<KeyState> {
+        self.boards
+            .iter()
+            .map(|letters| letters.state_for(key))
+            .collect()
     }
 }
 
-fn draw_key(graphics: &mut Graphics, key: Key, pos: Coord, highlighted: bool, state: KeyState) {
+fn draw_key(
+    graphics: &mut Graphics,
+    key: Key,
+    pos: Coord,
+    highlighted: bool,
+    flashing: bool,
+    states: &[KeyState],
+) {
     let size = key.size();
     let rect = Rect::new_with_size(pos, size.0, size.1);
-    let (back_clr, fore_clr) = match state {
-        KeyState::Default => (colors::KEYBOARD_BACK, colors::KEYBOARD_LETTER),
-        KeyState::Mismatch => (colors::SLOT_POS_WRONG_BACK, colors::SLOT_POS_WRONG_FORE),
-        KeyState::Match => (colors::SLOT_POS_RIGHT_BACK, colors::SLOT_POS_RIGHT_FORE),
-        KeyState::NoMatch => (colors::SLOT_NO_MATCH_BACK, colors::SLOT_NO_MATCH_FORE),
+    let fore_clr = if flashing {
+        graphics.draw_rect(rect.clone(), fill(colors::KEYBOARD_FLASH_BACK));
+        colors::KEYBOARD_FLASH_FORE
+    } else {
+        draw_segments(graphics, &rect, states);
+        match states {
+            [state] => state_colors(*state).1,
+            _ => colors::KEYBOARD_LETTER,
+        }
     };
-    graphics.draw_rect(rect.clone(), fill(back_clr));
     match key {
         Key::Letter(chr) => graphics.draw_text(
             &chr.to_string(),
@@ -189,3 +281,31 @@ fn draw_key(graphics: &mut Graphics, key: Key, pos: Coord, highlighted: bool, st
         graphics.draw_rect(rect, stroke(colors::KEYBOARD_HIGHLIGHT))
     }
 }
+
+/// Fills `rect` with the color for each board, split into two columns when there's more than one
+fn draw_segments(graphics: &mut Graphics, rect: &Rect, states: &[KeyState]) {
+    let cols = states.len().clamp(1, 2);
+    let rows = states.len().div_ceil(cols).max(1);
+    let (width, height) = (rect.width(), rect.height());
+    for (i, state) in states.iter().enumerate() {
+        let (col, row) = (i % cols, i / cols);
+        let left = width * col / cols;
+        let top = height * row / rows;
+        let segment = Rect::new_with_size(
+            rect.top_left() + (left, top),
+            width * (col + 1) / cols - left,
+            height * (row + 1) / rows - top,
+        );
+        graphics.draw_rect(segment, fill(state_colors(*state).0));
+    }
+}
+
+/// Background and foreground colors for a key
+fn state_colors(state: KeyState) -> (Color, Color) {
+    match state {
+        KeyState::Default => (colors::KEYBOARD_BACK, colors::KEYBOARD_LETTER),
+        KeyState::Mismatch => (colors::SLOT_POS_WRONG_BACK, colors::SLOT_POS_WRONG_FORE),
+        KeyState::Match => (colors::SLOT_POS_RIGHT_BACK, colors::SLOT_POS_RIGHT_FORE),
+        KeyState::NoMatch => (colors::SLOT_NO_MATCH_BACK, colors::SLOT_NO_MATCH_FORE),
+    }
+}
@@ -114,7 +114,10 @@ pub fn move_cursor(input: Input, cursor: Key) -> Option<Key> {
             _ => panic!("invalid input: {input:?}"),
         }
     }
-    if input == Input::Action || input == Input::Escape {
+    if matches!(
+        input,
+        Input::Action | Input::Escape | Input::Secondary | Input::Tertiary
+    ) {
         return None;
     }
     let new_key = match cursor {
@@ -1,4 +1,5 @@
 pub mod button_bar;
 pub mod keyboard;
+pub mod text_entry;
 pub mod theme;
 pub mod wordle_renderer;
@@ -0,0 +1,65 @@
+use crate::ui::keyboard::{Key, Keyboard};
+use crate::ui::theme::colors;
+use crate::{HEIGHT, WIDTH};
+use pixels_graphics_lib::prelude::*;
+
+/// Letters typed on the on screen keyboard outside of a game, e.g. a word or a puzzle code
+#[derive(Debug, Clone)]
+pub struct TextEntry {
+    pub letters: Vec<char>,
+    pub error: Option<String>,
+    pub max_len: usize,
+}
+
+impl TextEntry {
+    pub fn new(max_len: usize) -> Self {
+        TextEntry {
+            letters: vec![],
+            error: None,
+            max_len,
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.letters.iter().collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.letters.clear();
+        self.error = None;
+    }
+
+    /// Adds or removes a letter, returns true if `key` was enter so the text should be submitted
+    pub fn press_key(&mut self, key: Key) -> bool {
+        self.error = None;
+        match key {
+            Key::Letter(chr) => {
+                if self.letters.len() < self.max_len {
+                    self.letters.push(chr);
+                } else {
+                    self.error = Some(String::from("Too many letters"));
+                }
+            }
+            Key::Backspace => {
+                self.letters.pop();
+            }
+            Key::Enter => return true,
+        }
+        false
+    }
+
+    pub fn render_error(&self, graphics: &mut Graphics) {
+        if let Some(error) = &self.error {
+            draw_message(graphics, error, colors::ERROR);
+        }
+    }
+}
+
+/// Draws a line of text just above the keyboard, for errors and hints
+pub fn draw_message(graphics: &mut Graphics, text: &str, color: Color) {
+    graphics.draw_text(
+        text,
+        TextPos::px(coord!(WIDTH / 2, HEIGHT - Keyboard::size().1 - 26)),
+        (color, PixelFont::Standard6x7, Positioning::Center),
+    );
+}
@@ -11,9 +11,12 @@ pub mod colors {
 
     pub const BACKGROUND: Color = WHITE;
     pub const ERROR: Color = RED;
+    pub const HINT: Color = DARK_GREY;
     pub const KEYBOARD_BACK: Color = GREY;
     pub const KEYBOARD_HIGHLIGHT: Color = DARK_GREY;
     pub const KEYBOARD_LETTER: Color = BLACK;
+    pub const KEYBOARD_FLASH_BACK: Color = BLACK;
+    pub const KEYBOARD_FLASH_FORE: Color = WHITE;
     pub const SLOT_EMPTY_BORDER: Color = GREY;
     pub const SLOT_POS_WRONG_BACK: Color = YELLOW;
     pub const SLOT_POS_WRONG_FORE: Color = WHITE;
@@ -1,13 +1,15 @@
-use crate::engine::{SlotState, SubmittedGuessInfo, WordleEngine};
 use crate::ui::theme::colors;
 use crate::WIDTH;
 use pixels_graphics_lib::prelude::*;
+use wordle_engine::{EngineState, LetterSlot, SlotState, SubmittedGuessInfo, WordleEngine};
 
 const SPACING: usize = 6;
 const FONT: PixelFont = PixelFont::Standard8x10;
 const SQUARE_SIZE: (usize, usize) = (FONT.size().0 * 2, FONT.size().1 * 2);
 const PADDED_SIZE: (usize, usize) = (FONT.size().0 * 2 + SPACING, FONT.size().1 * 2 + SPACING);
 const LETTER_OFFSET: (usize, usize) = (1, 2);
+/// Most rows that fit above the keyboard, later rows scroll the board up
+const MAX_ROWS: usize = 8;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum Slot {
@@ -18,6 +20,11 @@ enum Slot {
     Match(char),
 }
 
+/// Width of the board for words of `word_size`
+pub fn field_width(word_size: usize) -> usize {
+    PADDED_SIZE.0 * word_size
+}
+
 pub fn render_guess_field(
     graphics: &mut Graphics,
     offset: Coord,
@@ -26,10 +33,10 @@ pub fn render_guess_field(
     perc: f64,
 ) {
     render_field(graphics, offset, engine);
-    let width = (PADDED_SIZE.0) * engine.word_size;
+    let width = field_width(engine.word_size);
     let offset = offset + ((WIDTH / 2) - (width / 2), SPACING);
 
-    let anim_row = engine.guesses.len() - 1;
+    let anim_row = engine.guesses.len() - 1 - first_row(engine, row_count(engine));
     let slot = (perc / 0.2).trunc() as usize;
     let slot_perc1 = inv_flerp(0.0, 0.1, (perc - (slot as f64 * 0.2)) as f32);
     let slot_perc2 = inv_flerp(0.1, 0.2, (perc - (slot as f64 * 0.2)) as f32);
@@ -67,15 +74,11 @@ pub fn render_guess_field(
 }
 
 pub fn render_field(graphics: &mut Graphics, offset: Coord, engine: &WordleEngine) {
-    let width = (PADDED_SIZE.0) * engine.word_size;
+    let width = field_width(engine.word_size);
 
     let offset = offset + ((WIDTH / 2) - (width / 2), SPACING);
 
-    let mut field = vec![];
-
-    convert_guesses(&mut field, engine);
-    convert_current_guess(&mut field, engine);
-    add_empties(&mut field, engine);
+    let field = build_field(engine, row_count(engine));
 
     graphics.with_translate(offset, |g| {
         for (y, row) in field.iter().enumerate() {
@@ -93,6 +96,152 @@ pub fn render_field(graphics: &mut Graphics, offset: Coord, engine: &WordleEngin
     });
 }
 
+/// Draws `word_size` empty squares across the screen at `y`, the first `letters` are shown
+/// with a `*` so the word being typed stays hidden
+pub fn render_masked_row(graphics: &mut Graphics, y: usize, word_size: usize, letters: usize) {
+    let x = (WIDTH / 2) - (field_width(word_size) / 2);
+    for i in 0..word_size {
+        let pos = coord!(x + i * PADDED_SIZE.0, y);
+        if i < letters {
+            draw_guess(graphics, pos, '*');
+        } else {
+            draw_empty(graphics, pos);
+        }
+    }
+}
+
+const SMALL_FONT: PixelFont = PixelFont::Standard4x5;
+pub const SMALL_SQUARE_SIZE: usize = 9;
+const SMALL_SPACING: usize = 1;
+
+/// Draws a scored row with small squares, for lists and summaries
+pub fn render_small_row(graphics: &mut Graphics, pos: Coord, row: &[LetterSlot]) {
+    for (i, slot) in row.iter().enumerate() {
+        let (back_color, fore_color) = match slot.state {
+            SlotState::NoMatch => (colors::SLOT_NO_MATCH_BACK, colors::SLOT_NO_MATCH_FORE),
+            SlotState::WrongPos => (colors::SLOT_POS_WRONG_BACK, colors::SLOT_POS_WRONG_FORE),
+            SlotState::Match => (colors::SLOT_POS_RIGHT_BACK, colors::SLOT_POS_RIGHT_FORE),
+        };
+        let rect = Rect::new_with_size(
+            pos + (i * (SMALL_SQUARE_SIZE + SMALL_SPACING), 0),
+            SMALL_SQUARE_SIZE,
+            SMALL_SQUARE_SIZE,
+        );
+        graphics.draw_rect(rect.clone(), fill(back_color));
+        graphics.draw_text(
+            &slot.chr.to_string(),
+            TextPos::px(rect.center() + (1, 1)),
+            (fore_color, SMALL_FONT, Positioning::Center),
+        );
+    }
+}
+
+/// Width of a row drawn by [render_small_row]
+pub fn small_row_width(word_size: usize) -> usize {
+    word_size * (SMALL_SQUARE_SIZE + SMALL_SPACING) - SMALL_SPACING
+}
+
+const BOARD_GAP: usize = 6;
+const COMPACT_SPACING: usize = 1;
+const MAX_COMPACT_SQUARE: usize = 18;
+/// Smallest square that letters are drawn in
+const MIN_LETTER_SQUARE: usize = 6;
+
+/// Draws several boards in a grid that fits inside `area`, the squares are shrunk as needed
+///
+/// All boards must have the same word size and guess limit
+pub fn render_boards(graphics: &mut Graphics, area: &Rect, boards: &[WordleEngine]) {
+    let Some(first) = boards.first() else {
+        return;
+    };
+    let (cols, square) = board_layout(area, boards.len(), first.word_size, first.max_guess_count);
+    let step = square + COMPACT_SPACING;
+    let board_size = (
+        step * first.word_size - COMPACT_SPACING,
+        step * first.max_guess_count - COMPACT_SPACING,
+    );
+    let grid_width = cols * (board_size.0 + BOARD_GAP) - BOARD_GAP;
+    let start = area.top_left() + ((area.width().saturating_sub(grid_width)) / 2, 0);
+    let font = if square >= 14 {
+        PixelFont::Standard6x7
+    } else {
+        PixelFont::Standard4x5
+    };
+
+    for (i, engine) in boards.iter().enumerate() {
+        let board_pos = start
+            + (
+                (i % cols) * (board_size.0 + BOARD_GAP),
+                (i / cols) * (board_size.1 + BOARD_GAP),
+            );
+        let field = build_field(engine, engine.max_guess_count);
+        for (y, row) in field.iter().enumerate() {
+            for (x, slot) in row.iter().enumerate() {
+                let rect = Rect::new_with_size(board_pos + (x * step, y * step), square, square);
+                let (back, fore, chr) = match slot {
+                    Slot::Empty => (None, colors::SLOT_EMPTY_BORDER, None),
+                    Slot::Guess(chr) => (None, colors::SLOT_GUESS_LETTER, Some(chr)),
+                    Slot::NoMatch(chr) => (
+                        Some(colors::SLOT_NO_MATCH_BACK),
+                        colors::SLOT_NO_MATCH_FORE,
+                        Some(chr),
+                    ),
+                    Slot::Mismatch(chr) => (
+                        Some(colors::SLOT_POS_WRONG_BACK),
+                        colors::SLOT_POS_WRONG_FORE,
+                        Some(chr),
+                    ),
+                    Slot::Match(chr) => (
+                        Some(colors::SLOT_POS_RIGHT_BACK),
+                        colors::SLOT_POS_RIGHT_FORE,
+                        Some(chr),
+                    ),
+                };
+                match (back, slot) {
+                    (Some(back), _) => graphics.draw_rect(rect.clone(), fill(back)),
+                    (None, Slot::Empty) => {
+                        graphics.draw_rect(rect.clone(), stroke(colors::SLOT_EMPTY_BORDER))
+                    }
+                    (None, _) => {
+                        graphics.draw_rect(rect.clone(), stroke(colors::SLOT_GUESS_BORDER))
+                    }
+                }
+                if let Some(chr) = chr {
+                    if square >= MIN_LETTER_SQUARE {
+                        graphics.draw_text(
+                            &chr.to_string(),
+                            TextPos::px(rect.center() + (1, 1)),
+                            (fore, font, Positioning::Center),
+                        );
+                    }
+                }
+            }
+        }
+        if engine.state == EngineState::Found {
+            let outline =
+                Rect::new_with_size(board_pos - (2, 2), board_size.0 + 4, board_size.1 + 4);
+            graphics.draw_rect(outline, stroke(colors::WIN_BANNER));
+        }
+    }
+}
+
+/// Number of columns and the square size that gives the largest squares for `count` boards
+fn board_layout(area: &Rect, count: usize, word_size: usize, rows: usize) -> (usize, usize) {
+    (1..=count.max(1))
+        .map(|cols| {
+            let grid_rows = count.div_ceil(cols);
+            let width = (area.width() / cols).saturating_sub(BOARD_GAP) / word_size;
+            let height = (area.height() / grid_rows).saturating_sub(BOARD_GAP) / rows;
+            let square = width.min(height).saturating_sub(COMPACT_SPACING);
+            (cols, square.min(MAX_COMPACT_SQUARE))
+        })
+        //earliest wins ties, so boards are stacked rather than spread out
+        .fold(
+            (1, 0),
+            |best, layout| if layout.1 > best.1 { layout } else { best },
+        )
+}
+
 fn draw_answer(graphics: &mut Graphics, pos: Coord, slot: Slot) {
     let (back_color, fore_color, chr) = match slot {
         Slot::Guess(_) | Slot::Empty => panic!("Invalid slot {slot:?} passed"),
@@ -132,25 +281,47 @@ fn draw_empty(graphics: &mut Graphics, pos: Coord) {
     graphics.draw_rect(rect, stroke(colors::SLOT_EMPTY_BORDER));
 }
 
-fn convert_guesses(field: &mut Vec<Vec<Slot>>, engine: &WordleEngine) {
-    for row_idx in 0..engine.max_guess_count {
-        if let Some(row) = engine.guesses.get(row_idx) {
-            let mut render_row = vec![];
-            for slot in row {
-                let slot_state = match slot.state {
-                    SlotState::Match => Slot::Match(slot.chr),
-                    SlotState::WrongPos => Slot::Mismatch(slot.chr),
-                    SlotState::NoMatch => Slot::NoMatch(slot.chr),
-                };
-                render_row.push(slot_state);
-            }
-            field.push(render_row);
+/// Number of rows to draw, games without a guess limit show [MAX_ROWS]
+fn row_count(engine: &WordleEngine) -> usize {
+    engine.max_guess_count.min(MAX_ROWS)
+}
+
+/// First guess to draw, the earliest guesses are hidden once there are more than `rows`
+fn first_row(engine: &WordleEngine, rows: usize) -> usize {
+    let used = if engine.state == EngineState::Guessing {
+        engine.guesses.len() + 1
+    } else {
+        engine.guesses.len()
+    };
+    used.saturating_sub(rows)
+}
+
+/// Slots for `rows` rows, ending with the current guess
+fn build_field(engine: &WordleEngine, rows: usize) -> Vec<Vec<Slot>> {
+    let mut field = vec![];
+    convert_guesses(&mut field, engine, first_row(engine, rows));
+    convert_current_guess(&mut field, engine, rows);
+    add_empties(&mut field, engine, rows);
+    field
+}
+
+fn convert_guesses(field: &mut Vec<Vec<Slot>>, engine: &WordleEngine, first: usize) {
+    for row in engine.guesses.iter().skip(first) {
+        let mut render_row = vec![];
+        for slot in row {
+            let slot_state = match slot.state {
+                SlotState::Match => Slot::Match(slot.chr),
+                SlotState::WrongPos => Slot::Mismatch(slot.chr),
+                SlotState::NoMatch => Slot::NoMatch(slot.chr),
+            };
+            render_row.push(slot_state);
         }
+        field.push(render_row);
     }
 }
 
-fn convert_current_guess(field: &mut Vec<Vec<Slot>>, engine: &WordleEngine) {
-    if field.len() < engine.max_guess_count {
+fn convert_current_guess(field: &mut Vec<Vec<Slot>>, engine: &WordleEngine, rows: usize) {
+    if field.len() < rows {
         let mut row = vec![];
         for i in 0..engine.word_size {
             row.push(
@@ -165,8 +336,8 @@ fn convert_current_guess(field: &mut Vec<Vec<Slot>>, engine: &WordleEngine) {
     }
 }
 
-fn add_empties(field: &mut Vec<Vec<Slot>>, engine: &WordleEngine) {
-    for _ in field.len()..engine.max_guess_count {
+fn add_empties(field: &mut Vec<Vec<Slot>>, engine: &WordleEngine, rows: usize) {
+    for _ in field.len()..rows {
         field.push(vec![Slot::Empty; engine.word_size]);
     }
 }